@@ -0,0 +1,45 @@
+use aoc_core::{Answer, Error, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+}
+
+fn part_one(data: &str) -> i32 {
+    let res = data
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|chr| if chr == '(' { 1i32 } else { -1i32 })
+                .sum::<i32>()
+        })
+        .sum::<i32>();
+
+    res
+}
+
+fn part_two(data: &str) -> u32 {
+    let mapped = data
+        .lines()
+        .flat_map(|line| line.chars().map(|chr| if chr == '(' { 1 } else { -1 }));
+
+    let mut total = 0i32;
+    let mut iter = 0u32;
+    for token in mapped {
+        if total < 0 {
+            break;
+        }
+
+        total += token;
+        iter += 1;
+    }
+
+    iter
+}
@@ -0,0 +1,46 @@
+use aoc_core::{Answer, Error, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+}
+
+fn parse_dimensions(line: &str) -> Result<(i64, i64, i64), Error> {
+    let mut tokens = line.split('x');
+    let mut next = || -> Result<i64, Error> {
+        Ok(tokens.next().ok_or("Missing dimension")?.parse::<i64>()?)
+    };
+
+    Ok((next()?, next()?, next()?))
+}
+
+fn part_one(data: &str) -> Result<i64, Error> {
+    data.lines().map(|line| {
+	let (l, w, h) = parse_dimensions(line)?;
+
+	let s1 = l*w;
+	let s2 = w*h;
+	let s3 = h*l;
+
+	let smallest = i64::min(s1, i64::min(s2, s3));
+
+	Ok(2i64 * (s1 + s2 + s3) + smallest)
+    }).sum()
+}
+
+fn part_two(data: &str) -> Result<i64, Error> {
+    data.lines().map(|line| {
+	let (l, w, h) = parse_dimensions(line)?;
+
+	let biggest = i64::max(l, i64::max(w, h));
+
+	Ok(2i64 * (l + w + h - biggest) + l * w * h)
+    }).sum()
+}
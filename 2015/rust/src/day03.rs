@@ -1,15 +1,23 @@
-use std::{collections::HashSet, fs::read_to_string};
+use std::collections::HashSet;
+
+use aoc_core::{Answer, Error, Solution};
 
 type Vector = (isize, isize);
 type Enumerated<T> = (usize, T);
 
-fn main() {
-    part_one();
-    part_two();
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
 }
 
-fn part_one() {
-    let data = read_to_string("data/03.txt").expect("Could not read datafile");
+fn part_one(data: &str) -> usize {
     let mut coords = HashSet::new();
     let instructions = data
         .lines()
@@ -33,11 +41,10 @@ fn part_one() {
         coords.insert(current_coord);
     }
 
-    println!("{}", coords.len());
+    coords.len()
 }
 
-fn part_two() {
-    let data = read_to_string("data/03.txt").expect("Could not read datafile");
+fn part_two(data: &str) -> usize {
     let mut coords = HashSet::new();
     let instructions: (Vec<Enumerated<Vector>>, Vec<Enumerated<Vector>>) = data
         .lines()
@@ -67,5 +74,5 @@ fn part_two() {
         coords.insert(robo_coord);
     }
 
-    println!("{}", coords.len());
+    coords.len()
 }
@@ -0,0 +1,173 @@
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+};
+
+use aoc_core::{Answer, Error, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn variants(&self) -> &'static [&'static str] {
+        &["part_one_naive", "part_two_naive"]
+    }
+
+    fn variant(&self, name: &str, input: &str) -> Option<Result<Answer, Error>> {
+        let zeros = match name {
+            "part_one_naive" => 5,
+            "part_two_naive" => 6,
+            _ => return None,
+        };
+        Some(Ok(mine_naive(input.trim(), zeros).into()))
+    }
+}
+
+/// Nonces every thread checks before taking the next ones
+const CHUNK: u64 = 4096;
+
+/// Hexadecimal digits in an MD5 digest
+const DIGITS: usize = 32;
+
+/// Whether the hexadecimal digest starts with this many zeros, at most [`DIGITS`]
+fn has_zeros(digest: &md5::Digest, zeros: usize) -> bool {
+    let bytes = zeros / 2;
+    digest.0[..bytes].iter().all(|&byte| byte == 0)
+        && (zeros.is_multiple_of(2) || digest.0[bytes] >> 4 == 0)
+}
+
+/// Writes the decimal digits of the nonce to the end of the buffer, returns where they start
+fn write_nonce(buffer: &mut [u8; 20], mut nonce: u64) -> usize {
+    let mut start = buffer.len();
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (nonce % 10) as u8;
+        nonce /= 10;
+        if nonce == 0 {
+            return start;
+        }
+    }
+}
+
+/// Checks the nonces from `start` on in order, until one gives a digest with this many zeros
+/// after the key or they are not smaller than `best` anymore
+fn mine_chunk(key: &md5::Context, zeros: usize, start: u64, best: &AtomicU64) {
+    let mut buffer = [0; 20];
+    for nonce in start..start + CHUNK {
+        if nonce >= best.load(Ordering::Relaxed) {
+            return;
+        }
+        let mut context = key.clone();
+        let digits = write_nonce(&mut buffer, nonce);
+        context.consume(&buffer[digits..]);
+        if has_zeros(&context.compute(), zeros) {
+            best.fetch_min(nonce, Ordering::Relaxed);
+            return;
+        }
+    }
+}
+
+/// Finds the smallest nonce that gives a hexadecimal MD5 digest, of the key followed by the
+/// nonce, that starts with this many zeros. It keeps on looking until it finds one, and an error
+/// when there are more zeros than the digest has digits.
+///
+/// The threads take the next chunk of nonces until they get one that starts after the smallest
+/// nonce found so far, so all the chunks before it have been checked by the time they stop.
+fn mine(key: &str, zeros: usize, threads: NonZeroUsize) -> Result<u64, Error> {
+    if zeros > DIGITS {
+        return Err(
+            format!("A digest has {DIGITS} digits, it cannot start with {zeros} zeros").into(),
+        );
+    }
+    let mut context = md5::Context::new();
+    context.consume(key.as_bytes());
+
+    let next = AtomicU64::new(0);
+    let best = AtomicU64::new(u64::MAX);
+    thread::scope(|scope| {
+        for _ in 0..threads.get() {
+            scope.spawn(|| loop {
+                let start = next.fetch_add(CHUNK, Ordering::Relaxed);
+                if start >= best.load(Ordering::Relaxed) {
+                    break;
+                }
+                mine_chunk(&context, zeros, start, &best);
+            });
+        }
+    });
+    Ok(best.into_inner())
+}
+
+/// How the days used to be solved, on one thread with a new string for every nonce, to compare
+/// [`mine`] with
+fn mine_naive(key: &str, zeros: usize) -> u64 {
+    (0..)
+        .find(|i| {
+            let hash = md5::compute(format!("{key}{i}"));
+            format!("{hash:x}").chars().take(zeros).all(|x| x == '0')
+        })
+        .expect("The nonces never run out")
+}
+
+fn threads() -> NonZeroUsize {
+    thread::available_parallelism().unwrap_or(NonZeroUsize::MIN)
+}
+
+fn part_one(data: &str) -> Result<u64, Error> {
+    mine(data.trim(), 5, threads())
+}
+
+fn part_two(data: &str) -> Result<u64, Error> {
+    mine(data.trim(), 6, threads())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::{has_zeros, mine, mine_naive, threads, write_nonce};
+
+    #[test]
+    fn zeros() {
+        let digest = md5::Digest([0, 0, 0x0f, 0xff, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(has_zeros(&digest, 0));
+        assert!(has_zeros(&digest, 4));
+        assert!(has_zeros(&digest, 5));
+        assert!(!has_zeros(&digest, 6));
+        assert!(has_zeros(&md5::Digest([0; 16]), 32));
+    }
+
+    #[test]
+    fn nonce_digits() {
+        for nonce in [0, 7, 10, 609043, u64::MAX] {
+            let mut buffer = [0; 20];
+            let start = write_nonce(&mut buffer, nonce);
+            assert_eq!(&buffer[start..], nonce.to_string().as_bytes());
+        }
+    }
+
+    #[test]
+    fn smallest_nonce_on_any_number_of_threads() {
+        assert_eq!(mine("abcdef", 5, threads()).unwrap(), 609043);
+        for key in ["abcdef", "pqrstuv", "bgvyzdsv"] {
+            let expected = mine_naive(key, 3);
+            for threads in [1, 2, 3, 8] {
+                let threads = NonZeroUsize::new(threads).unwrap();
+                assert_eq!(mine(key, 3, threads).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn more_zeros_than_digits() {
+        assert!(mine("abcdef", 33, threads()).is_err());
+    }
+}
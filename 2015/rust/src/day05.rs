@@ -1,13 +1,20 @@
-use std::{collections::HashSet, fs::read_to_string};
+use std::collections::HashSet;
 
-fn main() {
-    part_one();
-    part_two();
-}
+use aoc_core::{Answer, Error, Solution};
+
+pub struct Day;
 
-fn part_one() {
-    let data = read_to_string("data/05.txt").expect("Could not read datafile");
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
 
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+}
+
+fn part_one(data: &str) -> usize {
     let bad_segments = ["ab", "cd", "pq", "xy"];
 
     let vowels = ['a', 'e', 'i', 'o', 'u'];
@@ -30,12 +37,10 @@ fn part_one() {
         true
     });
 
-    println!("Answer {}", nice.count())
+    nice.count()
 }
 
-fn part_two() {
-    let data = read_to_string("data/05.txt").expect("Could not read datafile");
-
+fn part_two(data: &str) -> usize {
     let nice = data.lines().filter(|line| {
         let l2: Vec<_> = line.chars().collect();
 
@@ -45,12 +50,10 @@ fn part_two() {
 
         let quintuplets = l2
             .windows(4)
-            .into_iter()
             .filter(|x| x[0] == x[1] && x[0] == x[2] && x[0] == x[3])
             .count();
         let triplets = l2
             .windows(3)
-            .into_iter()
             .filter(|x| x[0] == x[1] && x[0] == x[2])
             .count();
         let combos = l2.windows(2).collect::<HashSet<_>>().len();
@@ -62,5 +65,5 @@ fn part_two() {
         true
     });
 
-    println!("Answer {}", nice.count())
+    nice.count()
 }
@@ -0,0 +1,375 @@
+use aoc_core::{parsed, Answer, Error, Solution};
+use aoc_util::image::{Image, FORMATS};
+use aoc_util::words::{parse_lines, ParseError, Words};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_instructions(input).map(parsed).map_err(Error::from))
+    }
+
+    fn render(
+        &self,
+        input: &str,
+        part: Option<u8>,
+        format: &str,
+    ) -> Option<Result<Vec<u8>, Error>> {
+        FORMATS.contains(&format).then(|| {
+            let image = match part {
+                None => return Err("The lights can only be rendered after a part".into()),
+                Some(1) => render::<bool>(input)?,
+                Some(_) => render::<u64>(input)?,
+            };
+            Ok(image
+                .encode(format)
+                .ok_or_else(|| format!("Could not encode the lights as {format}"))?)
+        })
+    }
+}
+
+/// Most lights in a rendered image
+const MAX_PIXELS: u64 = 100_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    On,
+    Off,
+    Toggle,
+}
+
+type Coord = (u64, u64);
+
+/// An action on every light from one corner up to and including the other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Instruction {
+    action: Action,
+    from: Coord,
+    to: Coord,
+}
+
+fn parse_instructions(data: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(data, parse_instruction)
+}
+
+fn parse_instruction(line_number: usize, line: &str) -> Result<Instruction, ParseError> {
+    let mut words = Words::new(line_number, line).with_separators(&[',']);
+
+    let action = match words.peek() {
+        Some("toggle") => Action::Toggle,
+        Some("turn") => {
+            words.advance();
+            match words.peek() {
+                Some("on") => Action::On,
+                Some("off") => Action::Off,
+                _ => return Err(words.unexpected("`on` or `off`")),
+            }
+        }
+        _ => return Err(words.unexpected("`turn` or `toggle`")),
+    };
+    words.advance();
+
+    let from = corner(&mut words, (0, 0), "a number")?;
+    words.expect("through", "`through`")?;
+    let to = corner(
+        &mut words,
+        from,
+        "a number no smaller than in the first corner",
+    )?;
+    if words.peek().is_some() {
+        return Err(words.unexpected("the end of the line"));
+    }
+
+    Ok(Instruction { action, from, to })
+}
+
+/// A corner that is not above or to the left of the given one
+fn corner(words: &mut Words, min: Coord, expected: &'static str) -> Result<Coord, ParseError> {
+    let x = number(words, min.0, expected)?;
+    words.expect(",", "`,`")?;
+    let y = number(words, min.1, expected)?;
+    Ok((x, y))
+}
+
+/// The largest number is left out, the lights up to a corner are counted one past it
+fn number(words: &mut Words, min: u64, expected: &'static str) -> Result<u64, ParseError> {
+    match words.peek().map(str::parse::<u64>) {
+        Some(Ok(number)) if (min..u64::MAX).contains(&number) => {
+            words.advance();
+            Ok(number)
+        }
+        _ => Err(words.unexpected(expected)),
+    }
+}
+
+/// What the actions do to a light
+trait Light: Copy + Default {
+    fn apply(self, action: Action) -> Self;
+
+    /// How much the light counts towards the answer
+    fn weight(self) -> u64;
+}
+
+/// A light that is either on or off
+impl Light for bool {
+    fn apply(self, action: Action) -> Self {
+        match action {
+            Action::On => true,
+            Action::Off => false,
+            Action::Toggle => !self,
+        }
+    }
+
+    fn weight(self) -> u64 {
+        self as u64
+    }
+}
+
+/// The brightness of a light
+impl Light for u64 {
+    fn apply(self, action: Action) -> Self {
+        match action {
+            Action::On => self + 1,
+            Action::Off => self.saturating_sub(1),
+            Action::Toggle => self + 2,
+        }
+    }
+
+    fn weight(self) -> u64 {
+        self
+    }
+}
+
+/// The lights, with the grid compressed to the regions between the edges of the instructions,
+/// since all the lights in such a region always end up the same
+struct Lights<L> {
+    /// Every column where a region starts, and the one after the last region
+    xs: Vec<u64>,
+    /// Every row where a region starts, and the one after the last region
+    ys: Vec<u64>,
+    /// The regions, row after row
+    regions: Vec<L>,
+}
+
+impl<L: Light> Lights<L> {
+    fn new(instructions: &[Instruction]) -> Self {
+        let edges = |coord: fn(&Coord) -> u64| {
+            let mut edges = instructions
+                .iter()
+                .flat_map(|instruction| [coord(&instruction.from), coord(&instruction.to) + 1])
+                .collect::<Vec<_>>();
+            edges.sort_unstable();
+            edges.dedup();
+            edges
+        };
+        let xs = edges(|coord| coord.0);
+        let ys = edges(|coord| coord.1);
+        let regions = vec![L::default(); xs.len().saturating_sub(1) * ys.len().saturating_sub(1)];
+        Self { xs, ys, regions }
+    }
+
+    /// Applies an instruction, its edges must have been given to [`Lights::new`]
+    fn apply(&mut self, instruction: &Instruction) {
+        let region = |edges: &[u64], coord| edges.binary_search(&coord).unwrap();
+        let columns = region(&self.xs, instruction.from.0)..region(&self.xs, instruction.to.0 + 1);
+        let rows = region(&self.ys, instruction.from.1)..region(&self.ys, instruction.to.1 + 1);
+
+        let width = self.xs.len() - 1;
+        for row in rows {
+            for light in &mut self.regions[row * width..][columns.clone()] {
+                *light = light.apply(instruction.action);
+            }
+        }
+    }
+
+    /// The weight of all lights, an error when it does not fit
+    fn total(&self) -> Result<u64, Error> {
+        let width = self.xs.len().saturating_sub(1);
+        let mut total = 0u64;
+        for (row, ys) in self.ys.windows(2).enumerate() {
+            let height = ys[1] - ys[0];
+            for (xs, light) in self.xs.windows(2).zip(&self.regions[row * width..]) {
+                total = light
+                    .weight()
+                    .checked_mul(xs[1] - xs[0])
+                    .and_then(|weight| weight.checked_mul(height))
+                    .and_then(|weight| total.checked_add(weight))
+                    .ok_or("The lights add up to more than fits in 64 bits")?;
+            }
+        }
+        Ok(total)
+    }
+
+    /// The lights from `0,0` on, with the weight of every light scaled so the highest is white
+    fn image(&self) -> Result<Image, Error> {
+        let (width, height) = (
+            self.xs.last().copied().unwrap_or_default(),
+            self.ys.last().copied().unwrap_or_default(),
+        );
+        if width.saturating_mul(height) > MAX_PIXELS {
+            return Err(format!("The lights are too large to render, {width}x{height}").into());
+        }
+        let (width, height) = (width as usize, height as usize);
+
+        let mut levels = vec![0; width * height];
+        let columns = self.xs.len().saturating_sub(1);
+        for (row, ys) in self.ys.windows(2).enumerate() {
+            for (xs, light) in self.xs.windows(2).zip(&self.regions[row * columns..]) {
+                for y in ys[0] as usize..ys[1] as usize {
+                    levels[y * width + xs[0] as usize..y * width + xs[1] as usize]
+                        .fill(light.weight());
+                }
+            }
+        }
+        Ok(Image::from_levels(width, height, &levels))
+    }
+}
+
+fn apply_all<L: Light>(data: &str) -> Result<Lights<L>, Error> {
+    let instructions = parse_instructions(data)?;
+    let mut lights = Lights::<L>::new(&instructions);
+    for instruction in &instructions {
+        lights.apply(instruction);
+    }
+    Ok(lights)
+}
+
+fn run<L: Light>(data: &str) -> Result<u64, Error> {
+    apply_all::<L>(data)?.total()
+}
+
+fn render<L: Light>(data: &str) -> Result<Image, Error> {
+    apply_all::<L>(data)?.image()
+}
+
+fn part_one(data: &str) -> Result<u64, Error> {
+    run::<bool>(data)
+}
+
+fn part_two(data: &str) -> Result<u64, Error> {
+    run::<u64>(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{parse_instructions, part_one, part_two, render, run, Action, Instruction, Light};
+
+    /// Applies the instructions to every light of a small grid one by one
+    fn run_naive<L: Light>(instructions: &[Instruction]) -> u64 {
+        let mut lights = [[L::default(); 16]; 16];
+        for instruction in instructions {
+            for row in &mut lights[instruction.from.1 as usize..=instruction.to.1 as usize] {
+                for light in &mut row[instruction.from.0 as usize..=instruction.to.0 as usize] {
+                    *light = light.apply(instruction.action);
+                }
+            }
+        }
+        lights.iter().flatten().map(|light| light.weight()).sum()
+    }
+
+    fn disassemble(instructions: &[Instruction]) -> String {
+        instructions
+            .iter()
+            .map(|instruction| {
+                let action = match instruction.action {
+                    Action::On => "turn on",
+                    Action::Off => "turn off",
+                    Action::Toggle => "toggle",
+                };
+                let (from, to) = (instruction.from, instruction.to);
+                format!("{action} {},{} through {},{}\n", from.0, from.1, to.0, to.1)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn examples() {
+        assert_eq!(part_one("turn on 0,0 through 999,999").unwrap(), 1_000_000);
+        assert_eq!(
+            part_one("turn on 0,0 through 999,999\ntoggle 0,0 through 999,0\nturn off 499,499 through 500,500").unwrap(),
+            998_996
+        );
+        assert_eq!(part_two("turn on 0,0 through 0,0").unwrap(), 1);
+        assert_eq!(part_two("toggle 0,0 through 999,999").unwrap(), 2_000_000);
+    }
+
+    #[test]
+    fn large_coordinates() {
+        let data =
+            "turn on 0,0 through 999999999,999999999\nturn off 1,1 through 999999998,999999998";
+        assert_eq!(part_one(data).unwrap(), 4 * 999_999_999);
+        assert_eq!(
+            run::<u64>("toggle 4000000000,7 through 4000000001,8").unwrap(),
+            8
+        );
+        assert!(part_one("turn on 0,0 through 4294967296,4294967296").is_err());
+    }
+
+    #[test]
+    fn render_lights() {
+        let data = "turn on 0,0 through 2,1\ntoggle 1,1 through 3,1";
+        let image = render::<bool>(data).unwrap();
+        assert_eq!((image.width(), image.height()), (4, 2));
+        assert_eq!(image.pixels(), &[255, 255, 255, 0, 255, 0, 0, 255]);
+        let image = render::<u64>(data).unwrap();
+        assert_eq!(image.pixels(), &[85, 85, 85, 0, 85, 255, 255, 170]);
+        assert!(render::<bool>("turn on 0,0 through 99999,99999").is_err());
+    }
+
+    #[test]
+    fn parse_errors() {
+        for data in [
+            "turn up 0,0 through 1,1",
+            "flip 0,0 through 1,1",
+            "toggle 0,0 to 1,1",
+            "toggle 0,0 through 1",
+            "toggle 2,0 through 1,1",
+            "toggle 0,0 through 1,1 twice",
+        ] {
+            assert!(parse_instructions(data).is_err(), "{data}");
+        }
+        assert_eq!(
+            parse_instructions("toggle 0,0 through 1,1\n\nturn off 1,1")
+                .unwrap_err()
+                .to_string(),
+            "3:13: Expected `through`, found the end of the line"
+        );
+        assert_eq!(
+            parse_instructions("toggle 2,0 through 1,1")
+                .unwrap_err()
+                .to_string(),
+            "1:20: Expected a number no smaller than in the first corner, found `1`"
+        );
+    }
+
+    fn any_instruction() -> impl Strategy<Value = Instruction> {
+        let action = prop_oneof![Just(Action::On), Just(Action::Off), Just(Action::Toggle)];
+        (action, 0..16u64, 0..16u64, 0..16u64, 0..16u64).prop_map(|(action, x1, y1, x2, y2)| {
+            Instruction {
+                action,
+                from: (x1.min(x2), y1.min(y2)),
+                to: (x1.max(x2), y1.max(y2)),
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn compressed_matches_naive(instructions in prop::collection::vec(any_instruction(), 0..24)) {
+            let data = disassemble(&instructions);
+            prop_assert_eq!(parse_instructions(&data).unwrap(), instructions.clone());
+            prop_assert_eq!(run::<bool>(&data).unwrap(), run_naive::<bool>(&instructions));
+            prop_assert_eq!(run::<u64>(&data).unwrap(), run_naive::<u64>(&instructions));
+        }
+    }
+}
@@ -0,0 +1,639 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{Display, Write},
+};
+
+use aoc_core::{parsed, Answer, Error, Solution};
+use aoc_util::words::{parse_lines, ParseError, Words};
+
+type Var = String;
+type Num = u16;
+
+#[derive(Clone, PartialEq, Debug)]
+enum Value {
+    Var(Var),
+    Num(Num),
+}
+
+#[derive(Clone, Debug)]
+enum Instruction {
+    And(Value, Value),
+    Or(Value, Value),
+    Not(Value),
+    Rshift(Value, Num),
+    Lshift(Value, Num),
+    Set(Value),
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_instructions(input).map(parsed).map_err(Error::from))
+    }
+
+    fn render(
+        &self,
+        input: &str,
+        part: Option<u8>,
+        format: &str,
+    ) -> Option<Result<Vec<u8>, Error>> {
+        (format == "dot").then(|| Ok(render_dot(input, part)?.into_bytes()))
+    }
+}
+
+/// The circuit as a graph, with the signals after the part on its wires when there is a part
+fn render_dot(data: &str, part: Option<u8>) -> Result<String, Error> {
+    let instructions = parse_instructions(data)?;
+    let circuit = match part {
+        None => None,
+        Some(1) => Some(Circuit::new(instructions.clone())?),
+        Some(_) => {
+            let mut circuit = Circuit::new(instructions.clone())?;
+            let a = circuit.signal("a").ok_or("Could not get var a")?;
+            circuit.override_wire("b", a)?;
+            Some(circuit)
+        }
+    };
+
+    Ok(to_dot(&instructions, circuit.as_ref()))
+}
+
+/// Instructions are like `x AND y -> z`, one per line
+fn parse_instructions(data: &str) -> Result<Vec<(Instruction, Var)>, ParseError> {
+    parse_lines(data, parse_instruction)
+}
+
+fn parse_instruction(line_number: usize, line: &str) -> Result<(Instruction, Var), ParseError> {
+    let mut words = Words::new(line_number, line);
+
+    let instruction = if words.peek() == Some("NOT") {
+        words.advance();
+        Instruction::Not(value(&mut words)?)
+    } else {
+        let left = value(&mut words)?;
+        match words.peek() {
+            Some("AND") => {
+                words.advance();
+                Instruction::And(left, value(&mut words)?)
+            }
+            Some("OR") => {
+                words.advance();
+                Instruction::Or(left, value(&mut words)?)
+            }
+            Some("LSHIFT") => {
+                words.advance();
+                Instruction::Lshift(left, shift_len(&mut words)?)
+            }
+            Some("RSHIFT") => {
+                words.advance();
+                Instruction::Rshift(left, shift_len(&mut words)?)
+            }
+            Some("->") => Instruction::Set(left),
+            _ => return Err(words.unexpected("an operator or `->`")),
+        }
+    };
+
+    words.expect("->", "`->`")?;
+    let wire = wire(&mut words)?;
+    if words.peek().is_some() {
+        return Err(words.unexpected("the end of the line"));
+    }
+
+    Ok((instruction, wire))
+}
+
+fn value(words: &mut Words) -> Result<Value, ParseError> {
+    let value = match words.peek() {
+        Some(word) if is_wire(word) => Value::Var(word.to_owned()),
+        Some(word) => match word.parse() {
+            Ok(num) => Value::Num(num),
+            Err(_) => return Err(words.unexpected("a wire or a signal")),
+        },
+        None => return Err(words.unexpected("a wire or a signal")),
+    };
+    words.advance();
+    Ok(value)
+}
+
+fn wire(words: &mut Words) -> Result<Var, ParseError> {
+    match words.peek() {
+        Some(word) if is_wire(word) => {
+            words.advance();
+            Ok(word.to_owned())
+        }
+        _ => Err(words.unexpected("a wire")),
+    }
+}
+
+/// Shifting a signal by its full width or more would overflow
+fn shift_len(words: &mut Words) -> Result<Num, ParseError> {
+    match words.peek().map(str::parse::<Num>) {
+        Some(Ok(shift_len)) if shift_len < Num::BITS as Num => {
+            words.advance();
+            Ok(shift_len)
+        }
+        _ => Err(words.unexpected("a shift below 16")),
+    }
+}
+
+fn is_wire(word: &str) -> bool {
+    word.chars().all(|chr| chr.is_ascii_lowercase())
+}
+
+impl Value {
+    fn wire(&self) -> Option<&Var> {
+        match self {
+            Value::Var(var) => Some(var),
+            Value::Num(_) => None,
+        }
+    }
+
+    fn signal(&self, signals: &HashMap<Var, Num>) -> Num {
+        match self {
+            Value::Var(var) => signals[var],
+            Value::Num(num) => *num,
+        }
+    }
+}
+
+impl Instruction {
+    fn inputs(&self) -> impl Iterator<Item = &Var> {
+        let (first, second) = match self {
+            Instruction::And(value1, value2) | Instruction::Or(value1, value2) => {
+                (value1, Some(value2))
+            }
+            Instruction::Not(value)
+            | Instruction::Rshift(value, _)
+            | Instruction::Lshift(value, _)
+            | Instruction::Set(value) => (value, None),
+        };
+        first.wire().into_iter().chain(second.and_then(Value::wire))
+    }
+
+    /// The signal the gate puts on its wire, its inputs need to have a signal already
+    fn evaluate(&self, signals: &HashMap<Var, Num>) -> Num {
+        match self {
+            Instruction::And(value1, value2) => value1.signal(signals) & value2.signal(signals),
+            Instruction::Or(value1, value2) => value1.signal(signals) | value2.signal(signals),
+            Instruction::Not(value) => !value.signal(signals),
+            Instruction::Rshift(value, shift_len) => value.signal(signals) >> shift_len,
+            Instruction::Lshift(value, shift_len) => value.signal(signals) << shift_len,
+            Instruction::Set(value) => value.signal(signals),
+        }
+    }
+}
+
+/// Returned when the instructions do not make up a circuit that can be evaluated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CircuitError {
+    /// A gate reads from a wire that no gate drives
+    UndefinedWire { wire: Var, read_by: Var },
+    /// Two gates drive the same wire
+    DuplicateWire(Var),
+    /// The wire is not part of the circuit
+    UnknownWire(Var),
+    /// The wires feed into each other, every wire is driven by the one before it
+    Cycle(Vec<Var>),
+}
+
+impl Display for CircuitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircuitError::UndefinedWire { wire, read_by } => {
+                write!(f, "Wire {wire} is read by {read_by}, but has no signal")
+            }
+            CircuitError::DuplicateWire(wire) => write!(f, "Wire {wire} is driven more than once"),
+            CircuitError::UnknownWire(wire) => write!(f, "Wire {wire} is not part of the circuit"),
+            CircuitError::Cycle(wires) => write!(f, "Wires form a cycle: {}", wires.join(" -> ")),
+        }
+    }
+}
+
+impl std::error::Error for CircuitError {}
+
+/// The wires of a circuit with the gates driving them, evaluated in topological order.
+///
+/// Overriding a wire only re-evaluates the wires that depend on it.
+#[derive(Debug)]
+struct Circuit {
+    gates: HashMap<Var, Instruction>,
+    /// The wires reading from every wire
+    dependents: HashMap<Var, Vec<Var>>,
+    /// Every wire comes after the wires it reads from
+    order: Vec<Var>,
+    signals: HashMap<Var, Num>,
+}
+
+impl Circuit {
+    fn new(instructions: Vec<(Instruction, Var)>) -> Result<Self, CircuitError> {
+        let mut gates = HashMap::new();
+        for (instruction, wire) in instructions {
+            if gates.insert(wire.clone(), instruction).is_some() {
+                return Err(CircuitError::DuplicateWire(wire));
+            }
+        }
+
+        let mut dependents: HashMap<Var, Vec<Var>> = HashMap::new();
+        for (wire, instruction) in &gates {
+            for input in instruction.inputs() {
+                if !gates.contains_key(input) {
+                    return Err(CircuitError::UndefinedWire {
+                        wire: input.clone(),
+                        read_by: wire.clone(),
+                    });
+                }
+                dependents
+                    .entry(input.clone())
+                    .or_default()
+                    .push(wire.clone());
+            }
+        }
+
+        let order = topological_order(&gates, &dependents)?;
+        let mut circuit = Self {
+            gates,
+            dependents,
+            order,
+            signals: HashMap::new(),
+        };
+        circuit.evaluate(|_| true);
+        Ok(circuit)
+    }
+
+    fn signal(&self, wire: &str) -> Option<Num> {
+        self.signals.get(wire).copied()
+    }
+
+    /// Drives the wire with a fixed signal instead of its gate, like connecting it to a constant.
+    fn override_wire(&mut self, wire: &str, signal: Num) -> Result<(), CircuitError> {
+        let gate = self
+            .gates
+            .get_mut(wire)
+            .ok_or_else(|| CircuitError::UnknownWire(wire.to_owned()))?;
+        let old_gate = std::mem::replace(gate, Instruction::Set(Value::Num(signal)));
+
+        for input in old_gate.inputs() {
+            if let Some(dependents) = self.dependents.get_mut(input) {
+                dependents.retain(|dependent| dependent != wire);
+            }
+        }
+
+        let cone = self.cone(wire);
+        self.evaluate(|wire| cone.contains(wire));
+        Ok(())
+    }
+
+    /// The wire with all wires that depend on it, directly or through other wires
+    fn cone(&self, wire: &str) -> HashSet<Var> {
+        let mut cone = HashSet::from([wire.to_owned()]);
+        let mut queue = VecDeque::from([wire.to_owned()]);
+        while let Some(wire) = queue.pop_front() {
+            for dependent in self.dependents.get(&wire).into_iter().flatten() {
+                if cone.insert(dependent.clone()) {
+                    queue.push_back(dependent.clone());
+                }
+            }
+        }
+        cone
+    }
+
+    fn evaluate(&mut self, mut affected: impl FnMut(&Var) -> bool) {
+        for wire in &self.order {
+            if affected(wire) {
+                let signal = self.gates[wire].evaluate(&self.signals);
+                self.signals.insert(wire.clone(), signal);
+            }
+        }
+    }
+}
+
+/// Orders the wires after their inputs, fails on the first cycle it finds
+fn topological_order(
+    gates: &HashMap<Var, Instruction>,
+    dependents: &HashMap<Var, Vec<Var>>,
+) -> Result<Vec<Var>, CircuitError> {
+    let mut unresolved: HashMap<&Var, usize> = gates
+        .iter()
+        .map(|(wire, instruction)| (wire, instruction.inputs().count()))
+        .collect();
+    let mut queue: VecDeque<&Var> = unresolved
+        .iter()
+        .filter(|(_, &count)| count == 0)
+        .map(|(&wire, _)| wire)
+        .collect();
+
+    let mut order = Vec::with_capacity(gates.len());
+    while let Some(wire) = queue.pop_front() {
+        order.push(wire.clone());
+        for dependent in dependents.get(wire).into_iter().flatten() {
+            let count = unresolved
+                .get_mut(dependent)
+                .expect("Dependent has no gate");
+            *count -= 1;
+            if *count == 0 {
+                queue.push_back(dependent);
+            }
+        }
+    }
+
+    if order.len() < gates.len() {
+        return Err(CircuitError::Cycle(find_cycle(gates, &unresolved)));
+    }
+    Ok(order)
+}
+
+/// Every wire that could not be ordered reads from another one that could not, so following those
+/// inputs has to come back to a wire that was already seen.
+fn find_cycle(gates: &HashMap<Var, Instruction>, unresolved: &HashMap<&Var, usize>) -> Vec<Var> {
+    let is_unresolved = |wire: &Var| unresolved.get(wire).is_some_and(|&count| count > 0);
+
+    let mut wire = unresolved
+        .iter()
+        .filter(|(_, &count)| count > 0)
+        .map(|(&wire, _)| wire)
+        .min()
+        .expect("No unresolved wires");
+    let mut path = vec![wire];
+    loop {
+        wire = gates[wire]
+            .inputs()
+            .find(|input| is_unresolved(input))
+            .expect("Unresolved wire has no unresolved inputs");
+        if let Some(start) = path.iter().position(|&seen| seen == wire) {
+            // The path reads backwards, from each wire to the one driving it
+            let mut cycle = path[start..]
+                .iter()
+                .rev()
+                .map(|&wire| wire.clone())
+                .collect::<Vec<_>>();
+            let first = (0..cycle.len())
+                .min_by_key(|&i| &cycle[i])
+                .expect("Cycle is empty");
+            cycle.rotate_left(first);
+            cycle.push(cycle[0].clone());
+            return cycle;
+        }
+        path.push(wire);
+    }
+}
+
+/// Every gate is a node driving its wire, the wires are the edges to the gates reading them.
+///
+/// Wires that no gate reads end in a node with their name, wires that no gate drives start at a
+/// dashed node. With a circuit, the wires are labelled with their signal.
+fn to_dot(instructions: &[(Instruction, Var)], circuit: Option<&Circuit>) -> String {
+    let driven = instructions
+        .iter()
+        .map(|(_, wire)| wire)
+        .collect::<HashSet<_>>();
+    let read = instructions
+        .iter()
+        .flat_map(|(instruction, _)| instruction.inputs())
+        .collect::<HashSet<_>>();
+    let wire_label = |wire: &str| match circuit.and_then(|circuit| circuit.signal(wire)) {
+        Some(signal) => format!("{wire} = {signal}"),
+        None => wire.to_owned(),
+    };
+
+    let mut dot = String::from("digraph circuit {\n    rankdir=LR;\n");
+    for (instruction, wire) in instructions {
+        let (label, values) = match instruction {
+            Instruction::And(value1, value2) => (String::from("AND"), vec![value1, value2]),
+            Instruction::Or(value1, value2) => (String::from("OR"), vec![value1, value2]),
+            Instruction::Not(value) => (String::from("NOT"), vec![value]),
+            Instruction::Rshift(value, shift_len) => (format!("RSHIFT {shift_len}"), vec![value]),
+            Instruction::Lshift(value, shift_len) => (format!("LSHIFT {shift_len}"), vec![value]),
+            // A literal drives the wire directly
+            Instruction::Set(Value::Num(num)) => (num.to_string(), vec![]),
+            Instruction::Set(value) => (String::from("SET"), vec![value]),
+        };
+        let shape = match instruction {
+            Instruction::Set(Value::Num(_)) => "box",
+            _ => "ellipse",
+        };
+        writeln!(
+            dot,
+            "    \"gate_{wire}\" [label=\"{label}\", shape={shape}];"
+        )
+        .expect("Writing to a string failed");
+
+        for (index, value) in values.into_iter().enumerate() {
+            match value {
+                Value::Var(input) => writeln!(
+                    dot,
+                    "    \"gate_{input}\" -> \"gate_{wire}\" [label=\"{}\"];",
+                    wire_label(input)
+                ),
+                Value::Num(num) => writeln!(
+                    dot,
+                    "    \"const_{wire}_{index}\" [label=\"{num}\", shape=box];\n    \
+                     \"const_{wire}_{index}\" -> \"gate_{wire}\";"
+                ),
+            }
+            .expect("Writing to a string failed");
+        }
+
+        if !read.contains(wire) {
+            writeln!(
+                dot,
+                "    \"wire_{wire}\" [label=\"{wire}\", shape=plaintext];\n    \
+                 \"gate_{wire}\" -> \"wire_{wire}\" [label=\"{}\"];",
+                wire_label(wire)
+            )
+            .expect("Writing to a string failed");
+        }
+    }
+
+    let mut undriven = read.difference(&driven).collect::<Vec<_>>();
+    undriven.sort();
+    for wire in undriven {
+        writeln!(
+            dot,
+            "    \"gate_{wire}\" [label=\"{wire}?\", shape=box, style=dashed];"
+        )
+        .expect("Writing to a string failed");
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+fn part_one(data: &str) -> Result<Num, Error> {
+    let circuit = Circuit::new(parse_instructions(data)?)?;
+    Ok(circuit.signal("a").ok_or("Could not get var a")?)
+}
+
+fn part_two(data: &str) -> Result<Num, Error> {
+    let mut circuit = Circuit::new(parse_instructions(data)?)?;
+    let a = circuit.signal("a").ok_or("Could not get var a")?;
+
+    circuit.override_wire("b", a)?;
+    Ok(circuit.signal("a").ok_or("Could not get var a")?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i";
+
+    fn parse_error(line: usize, column: usize, expected: &'static str, found: &str) -> ParseError {
+        ParseError {
+            line,
+            column,
+            expected,
+            found: (!found.is_empty()).then(|| found.to_owned()),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let instructions = parse_instructions(EXAMPLE).unwrap();
+
+        assert_eq!(instructions.len(), 8);
+        assert!(matches!(
+            &instructions[2],
+            (Instruction::And(Value::Var(x), Value::Var(y)), d) if x == "x" && y == "y" && d == "d"
+        ));
+        assert!(matches!(
+            &instructions[4],
+            (Instruction::Lshift(Value::Var(_), 2), _)
+        ));
+        assert!(matches!(
+            &instructions[6],
+            (Instruction::Not(Value::Var(_)), _)
+        ));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            (
+                "123 -> x\nx XOR y -> d",
+                parse_error(2, 3, "an operator or `->`", "XOR"),
+            ),
+            ("x AND -> d", parse_error(1, 7, "a wire or a signal", "->")),
+            (
+                "x LSHIFT y -> d",
+                parse_error(1, 10, "a shift below 16", "y"),
+            ),
+            (
+                "x LSHIFT 16 -> d",
+                parse_error(1, 10, "a shift below 16", "16"),
+            ),
+            ("NOT x ->", parse_error(1, 9, "a wire", "")),
+            (
+                "NOT x -> y z",
+                parse_error(1, 12, "the end of the line", "z"),
+            ),
+            ("123 x", parse_error(1, 5, "an operator or `->`", "x")),
+            ("x AND y d", parse_error(1, 9, "`->`", "d")),
+        ];
+
+        for (data, error) in cases {
+            assert_eq!(parse_instructions(data).unwrap_err(), error, "{data}");
+        }
+    }
+
+    #[test]
+    fn test_parse_error_message() {
+        let error = parse_instructions("x AND Y -> d").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "1:7: Expected a wire or a signal, found `Y`"
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let circuit = Circuit::new(parse_instructions(EXAMPLE).unwrap()).unwrap();
+
+        let signals = ["d", "e", "f", "g", "h", "i", "x", "y"].map(|wire| circuit.signal(wire));
+        let expected = [72, 507, 492, 114, 65412, 65079, 123, 456].map(Some);
+        assert_eq!(signals, expected);
+    }
+
+    #[test]
+    fn test_order_independent() {
+        let reversed = EXAMPLE.lines().rev().collect::<Vec<_>>().join("\n");
+        let circuit = Circuit::new(parse_instructions(&reversed).unwrap()).unwrap();
+
+        assert_eq!(circuit.signal("d"), Some(72));
+    }
+
+    #[test]
+    fn test_undefined_wire() {
+        let error = Circuit::new(parse_instructions("x AND y -> d\n1 -> x").unwrap()).unwrap_err();
+
+        assert_eq!(
+            error,
+            CircuitError::UndefinedWire {
+                wire: String::from("y"),
+                read_by: String::from("d"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_cycle() {
+        let error = Circuit::new(parse_instructions("a -> b\nb -> c\nc -> a\n1 -> d").unwrap())
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            CircuitError::Cycle(["a", "b", "c", "a"].map(String::from).to_vec())
+        );
+    }
+
+    #[test]
+    fn test_override_wire() {
+        let mut circuit = Circuit::new(parse_instructions(EXAMPLE).unwrap()).unwrap();
+        circuit.override_wire("x", 1).unwrap();
+
+        assert_eq!(circuit.signal("d"), Some(1 & 456));
+        assert_eq!(circuit.signal("h"), Some(!1));
+        assert_eq!(circuit.signal("i"), Some(65079));
+        assert_eq!(circuit.cone("x").len(), 5);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let instructions = parse_instructions("123 -> x\nx AND y -> d\n1 OR d -> e").unwrap();
+        let dot = to_dot(&instructions, None);
+
+        assert!(dot.starts_with("digraph circuit {\n"));
+        assert!(dot.contains("\"gate_x\" [label=\"123\", shape=box];"));
+        assert!(dot.contains("\"gate_x\" -> \"gate_d\" [label=\"x\"];"));
+        assert!(dot.contains("\"const_e_0\" -> \"gate_e\";"));
+        assert!(!dot.contains("\"gate_d\" -> \"wire_d\""));
+        assert!(dot.contains("\"gate_e\" -> \"wire_e\" [label=\"e\"];"));
+        assert!(dot.contains("\"gate_y\" [label=\"y?\", shape=box, style=dashed];"));
+    }
+
+    #[test]
+    fn test_to_dot_signals() {
+        let instructions = parse_instructions(EXAMPLE).unwrap();
+        let circuit = Circuit::new(parse_instructions(EXAMPLE).unwrap()).unwrap();
+        let dot = to_dot(&instructions, Some(&circuit));
+
+        assert!(dot.contains("\"gate_x\" -> \"gate_d\" [label=\"x = 123\"];"));
+        assert!(dot.contains("\"gate_d\" -> \"wire_d\" [label=\"d = 72\"];"));
+    }
+}
@@ -1,25 +1,32 @@
-use std::fs::read_to_string;
+use aoc_core::{Answer, Error, Solution};
 
-fn main() {
-    // let file = File::open("./data/08.txt").expect("Could not open datafile");
-    // let string_tuples: Vec<(String, String)> = BufReader::new(file)
-        // .lines().filter_map(|line| {
-    let data = read_to_string("./data/08.txt").expect("Could not open datafile");
+pub struct Day;
 
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+}
+
+fn part_one(data: &str) -> usize {
     let answer: usize = data.lines().map(|line| {
 	let mem_string = parse_string(line);
 	(line, mem_string)
     }).map(|(code_string, mem_string)| code_string.chars().count() - mem_string.chars().count()).sum::<_>();
 
-    println!("Answer part one: {answer}");
-
+    answer
+}
 
+fn part_two(data: &str) -> usize {
     let answer: usize = data.lines().map(|line|{
 	encode_string(line).chars().count() - line.chars().count()
     }).sum();
 
-    println!("Answer part two: {answer}");
-
+    answer
 }
 
 pub fn parse_string(inp: &str) -> String {
@@ -84,7 +91,7 @@ pub fn encode_string(inp: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse_string, encode_string};
+    use super::{parse_string, encode_string};
 
     #[test]
     fn decode_empty() {
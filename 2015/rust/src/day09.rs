@@ -1,10 +1,28 @@
 use itertools::Itertools;
 use std::collections::{BTreeMap, BTreeSet};
-use std::fs::read_to_string;
 
-fn main() {
-    let data = read_to_string("./data/09.txt").expect("Could not read datafile");
+use aoc_core::{parsed, Answer, Error, Solution};
 
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        parsed(parse_distances(input));
+        Some(Ok(()))
+    }
+}
+
+type Distances<'a> = BTreeMap<(&'a str, &'a str), u32>;
+
+fn parse_distances(data: &str) -> (BTreeSet<&str>, Distances<'_>) {
     let info = data
         .lines()
         .filter_map(|line| {
@@ -34,6 +52,12 @@ fn main() {
         distances.insert((loc2, loc1), cost);
     }
 
+    (locations, distances)
+}
+
+fn part_one(data: &str) -> u32 {
+    let (locations, distances) = parse_distances(data);
+
     let shortest = locations
         .iter()
         .permutations(locations.len())
@@ -51,6 +75,12 @@ fn main() {
         .min()
         .expect("Could not find a min path");
 
+    shortest
+}
+
+fn part_two(data: &str) -> u32 {
+    let (locations, distances) = parse_distances(data);
+
     let longest = locations
         .iter()
         .permutations(locations.len())
@@ -68,6 +98,5 @@ fn main() {
         .max()
         .expect("Could not find a min path");
 
-    eprintln!("Answer part one: {shortest}");
-    eprintln!("Answer part two: {longest}");
+    longest
 }
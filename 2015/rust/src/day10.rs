@@ -1,18 +1,33 @@
-fn main() {
-    let input = String::from("1113222113");
+use aoc_core::{Answer, Error, Solution};
 
-    let mut current = input;
+pub struct Day;
 
-    for _i in 1..=40 {
-	current = look_say(&current);
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
     }
-    println!("Answer part one: {}", current.len());
+}
+
+fn part_one(data: &str) -> usize {
+    look_say_length(data.trim(), 40)
+}
+
+fn part_two(data: &str) -> usize {
+    look_say_length(data.trim(), 50)
+}
+
+fn look_say_length(input: &str, iterations: usize) -> usize {
+    let mut current = input.to_owned();
 
-    for _i in 41..=50 {
+    for _i in 1..=iterations {
 	current = look_say(&current);
     }
 
-    println!("Answer part two: {}", current.len());
+    current.len()
 }
 
 fn look_say(inp: &str) -> String {
@@ -41,7 +56,7 @@ fn look_say(inp: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use crate::look_say;
+    use super::look_say;
 
     #[test]
     pub fn test_1() {
@@ -3,14 +3,26 @@ const START_CHAR: char = 'a';
 
 const ILLEGAL_CHARS: [char; 3] = ['o', 'i', 'l'];
 
-fn main() {
-    let current_pass = "hepxcrrq";
+use aoc_core::{Answer, Error, Solution};
 
-    let next_password = next_pass(current_pass);
-    println!("{:?}", next_password);
+pub struct Day;
 
-    let next_password = next_pass(&next_password);
-    println!("{:?}", next_password);
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+}
+
+fn part_one(data: &str) -> String {
+    next_pass(data.trim())
+}
+
+fn part_two(data: &str) -> String {
+    next_pass(&part_one(data))
 }
 
 fn next_pass(current_pass: &str) -> String {
@@ -81,7 +93,7 @@ fn pass_to_number(pass: &str) -> u64 {
 
 #[cfg(test)]
 mod tests {
-    use crate::{number_to_pass, pass_to_number};
+    use super::{number_to_pass, pass_to_number};
 
     #[test]
     fn number_to_pass_empty() {
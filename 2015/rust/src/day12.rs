@@ -0,0 +1,1427 @@
+use std::{
+    fmt::Display,
+    io::{self, BufRead, BufReader, Read},
+};
+
+use aoc_core::{Answer, Error, QueryOption, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one_with_tokenizer(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn query(&self, input: &str, options: &[QueryOption]) -> Option<Result<Answer, Error>> {
+        Some(query(input, options))
+    }
+}
+
+type Key = String;
+type Pairs = (Key, Token);
+
+/// A JSON value, numbers without a fraction or exponent that fit are integers
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Array(Vec<Token>),
+    Object(Vec<Pairs>),
+    String(String),
+    Integer(isize),
+    Float(f64),
+    Bool(bool),
+    Null,
+}
+
+/// How [`Token::print`] lays out arrays and objects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Layout {
+    /// Everything on a single line, without any whitespace
+    Compact,
+    /// Every element on its own line, indented by this many spaces per level
+    Indented(usize),
+}
+
+impl Token {
+    /// Writes the token back as JSON, which tokenizes to the same token. Floats that are not
+    /// finite cannot be written, they become `null`.
+    fn print(&self, layout: Layout) -> String {
+        let mut output = String::new();
+        self.write(&mut output, layout, 0)
+            .expect("Writing to a string failed");
+        output
+    }
+
+    fn write(
+        &self,
+        f: &mut impl std::fmt::Write,
+        layout: Layout,
+        depth: usize,
+    ) -> std::fmt::Result {
+        let (open, close, len) = match self {
+            Token::Array(elements) => ('[', ']', elements.len()),
+            Token::Object(pairs) => ('{', '}', pairs.len()),
+            Token::String(value) => return write_string(f, value),
+            Token::Integer(value) => return write!(f, "{value}"),
+            // The debug representation always has a fraction or exponent, so it stays a float
+            Token::Float(value) if value.is_finite() => return write!(f, "{value:?}"),
+            Token::Float(_) | Token::Null => return write!(f, "null"),
+            Token::Bool(value) => return write!(f, "{value}"),
+        };
+
+        let newline = |f: &mut dyn std::fmt::Write, depth: usize| match layout {
+            Layout::Compact => Ok(()),
+            Layout::Indented(indent) => write!(f, "\n{:width$}", "", width = indent * depth),
+        };
+
+        write!(f, "{open}")?;
+        for index in 0..len {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            newline(f, depth + 1)?;
+            match self {
+                Token::Array(elements) => elements[index].write(f, layout, depth + 1)?,
+                Token::Object(pairs) => {
+                    let (key, value) = &pairs[index];
+                    write_string(f, key)?;
+                    match layout {
+                        Layout::Compact => write!(f, ":")?,
+                        Layout::Indented(_) => write!(f, ": ")?,
+                    }
+                    value.write(f, layout, depth + 1)?;
+                }
+                _ => unreachable!("Only arrays and objects have elements"),
+            }
+        }
+        if len > 0 {
+            newline(f, depth)?;
+        }
+        write!(f, "{close}")
+    }
+}
+
+/// Compact JSON, or indented by two spaces with `{:#}`
+impl Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let layout = match f.alternate() {
+            true => Layout::Indented(2),
+            false => Layout::Compact,
+        };
+        write!(f, "{}", self.print(layout))
+    }
+}
+
+/// Quotes the string, escaping what JSON does not allow in a string
+fn write_string(f: &mut impl std::fmt::Write, value: &str) -> std::fmt::Result {
+    write!(f, "\"")?;
+    for chr in value.chars() {
+        match chr {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\u{8}' => write!(f, "\\b")?,
+            '\u{c}' => write!(f, "\\f")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            _ if chr < '\u{20}' => write!(f, "\\u{:04x}", chr as u32)?,
+            _ => write!(f, "{chr}")?,
+        }
+    }
+    write!(f, "\"")
+}
+
+/// What is read from a document, in the order it appears. Arrays and objects are read as a
+/// start and an end event around their elements, every value in an object follows its key.
+#[derive(Debug, PartialEq)]
+enum Event {
+    StartArray,
+    EndArray,
+    StartObject,
+    Key(Key),
+    EndObject,
+    /// A string, number, boolean or null
+    Value(Token),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ParserEnvironment {
+    Global,
+    /// The value of the document has been read, only whitespace may follow
+    End,
+    Array(ArrayEnvironment),
+    Object(ObjectEnvironment),
+}
+
+/// A container is at its `Start` right after it was opened, and `Before` an element after a `,`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ArrayEnvironment {
+    Start,
+    Before,
+    After,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ObjectEnvironment {
+    Start,
+    Before,
+    Intermediate,
+    After,
+}
+
+/// Returned when the document is not valid JSON, with the byte offset where it went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TokenizeError {
+    offset: usize,
+    expected: &'static str,
+    /// The unexpected character, `None` at the end of the document
+    found: Option<char>,
+}
+
+impl Display for TokenizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let found = match self.found {
+            Some(chr) => format!("{chr:?}"),
+            None => String::from("the end of the document"),
+        };
+        write!(
+            f,
+            "Expected {}, found {found} at byte {}",
+            self.expected, self.offset
+        )
+    }
+}
+
+impl std::error::Error for TokenizeError {}
+
+/// Returned while reading events, when the reader fails or the document is not valid JSON
+#[derive(Debug)]
+enum EventError {
+    Io(io::Error),
+    Tokenize(TokenizeError),
+}
+
+impl Display for EventError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventError::Io(error) => write!(f, "Could not read the document: {error}"),
+            EventError::Tokenize(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for EventError {}
+
+impl From<io::Error> for EventError {
+    fn from(error: io::Error) -> Self {
+        EventError::Io(error)
+    }
+}
+
+impl From<TokenizeError> for EventError {
+    fn from(error: TokenizeError) -> Self {
+        EventError::Tokenize(error)
+    }
+}
+
+/// Pull parser over a document from any reader. Only the environments of the open arrays and
+/// objects are kept, besides the string or number that is being read.
+struct Events<R> {
+    reader: BufReader<R>,
+    offset: usize,
+    parser_environment: ParserEnvironment,
+    env_stack: Vec<ParserEnvironment>,
+    /// Reading stops at the first error
+    failed: bool,
+}
+
+impl<R: Read> Iterator for Events<R> {
+    type Item = Result<Event, EventError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let event = self.next_event().transpose();
+        self.failed = matches!(event, Some(Err(_)));
+        event
+    }
+}
+
+impl<R: Read> Events<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            offset: 0,
+            parser_environment: ParserEnvironment::Global,
+            env_stack: Vec::new(),
+            failed: false,
+        }
+    }
+
+    fn next_event(&mut self) -> Result<Option<Event>, EventError> {
+        loop {
+            self.skip_whitespace()?;
+            match self.parser_environment {
+                ParserEnvironment::Global => {
+                    self.parser_environment = ParserEnvironment::End;
+                    return self.value().map(Some);
+                }
+
+                ParserEnvironment::End => {
+                    return match self.peek()? {
+                        None => Ok(None),
+                        Some(_) => Err(self.error("the end of the document")),
+                    };
+                }
+
+                ParserEnvironment::Array(
+                    environment @ (ArrayEnvironment::Start | ArrayEnvironment::Before),
+                ) => {
+                    if environment == ArrayEnvironment::Start && self.skip(b']')? {
+                        return Ok(Some(self.close(Event::EndArray)));
+                    }
+                    self.parser_environment = ParserEnvironment::Array(ArrayEnvironment::After);
+                    return self.value().map(Some);
+                }
+
+                ParserEnvironment::Array(ArrayEnvironment::After) => match self.peek()? {
+                    Some(b',') => {
+                        self.next_byte()?;
+                        self.parser_environment =
+                            ParserEnvironment::Array(ArrayEnvironment::Before);
+                    }
+                    Some(b']') => {
+                        self.next_byte()?;
+                        return Ok(Some(self.close(Event::EndArray)));
+                    }
+                    _ => return Err(self.error("`,` or `]`")),
+                },
+
+                ParserEnvironment::Object(
+                    environment @ (ObjectEnvironment::Start | ObjectEnvironment::Before),
+                ) => {
+                    let is_start = environment == ObjectEnvironment::Start;
+                    if is_start && self.skip(b'}')? {
+                        return Ok(Some(self.close(Event::EndObject)));
+                    }
+                    if !self.skip(b'"')? {
+                        return Err(self.error(if is_start { "a key or `}`" } else { "a key" }));
+                    }
+                    let key = self.string()?;
+                    self.parser_environment =
+                        ParserEnvironment::Object(ObjectEnvironment::Intermediate);
+                    return Ok(Some(Event::Key(key)));
+                }
+
+                ParserEnvironment::Object(ObjectEnvironment::Intermediate) => {
+                    if !self.skip(b':')? {
+                        return Err(self.error("`:`"));
+                    }
+                    self.parser_environment = ParserEnvironment::Object(ObjectEnvironment::After);
+                    return self.value().map(Some);
+                }
+
+                ParserEnvironment::Object(ObjectEnvironment::After) => match self.peek()? {
+                    Some(b',') => {
+                        self.next_byte()?;
+                        self.parser_environment =
+                            ParserEnvironment::Object(ObjectEnvironment::Before);
+                    }
+                    Some(b'}') => {
+                        self.next_byte()?;
+                        return Ok(Some(self.close(Event::EndObject)));
+                    }
+                    _ => return Err(self.error("`,` or `}`")),
+                },
+            }
+        }
+    }
+
+    /// Reads the next value in full, or only the start of it for arrays and objects
+    fn value(&mut self) -> Result<Event, EventError> {
+        self.skip_whitespace()?;
+        match self.peek()? {
+            Some(b'"') => {
+                self.next_byte()?;
+                Ok(Event::Value(Token::String(self.string()?)))
+            }
+            Some(b'-' | b'0'..=b'9') => Ok(Event::Value(self.number()?)),
+            Some(b't' | b'f' | b'n') => Ok(Event::Value(self.literal()?)),
+            Some(b'[') => {
+                self.next_byte()?;
+                self.open(ParserEnvironment::Array(ArrayEnvironment::Start));
+                Ok(Event::StartArray)
+            }
+            Some(b'{') => {
+                self.next_byte()?;
+                self.open(ParserEnvironment::Object(ObjectEnvironment::Start));
+                Ok(Event::StartObject)
+            }
+            _ => Err(self.error("a value")),
+        }
+    }
+
+    fn open(&mut self, environment: ParserEnvironment) {
+        let parent = std::mem::replace(&mut self.parser_environment, environment);
+        self.env_stack.push(parent);
+    }
+
+    fn close(&mut self, event: Event) -> Event {
+        self.parser_environment = self.env_stack.pop().expect("Ran out of env stack");
+        event
+    }
+
+    /// Reads the rest of a string, up to and including the closing quote
+    fn string(&mut self) -> Result<String, EventError> {
+        let mut value = String::new();
+        loop {
+            match self.peek()? {
+                Some(b'"') => {
+                    self.next_byte()?;
+                    break Ok(value);
+                }
+                Some(b'\\') => {
+                    self.next_byte()?;
+                    value.push(self.escape()?);
+                }
+                Some(byte) if byte < 0x20 => break Err(self.error("an escaped control character")),
+                Some(byte) if byte.is_ascii() => {
+                    self.next_byte()?;
+                    value.push(byte as char);
+                }
+                Some(_) => {
+                    let offset = self.offset;
+                    let chr = self.read_char()?.ok_or(TokenizeError {
+                        offset,
+                        expected: "valid UTF-8",
+                        found: Some(char::REPLACEMENT_CHARACTER),
+                    })?;
+                    value.push(chr);
+                }
+                None => break Err(self.error("a closing `\"`")),
+            }
+        }
+    }
+
+    /// The character escaped after a backslash, characters outside of the basic plane are escaped
+    /// as a pair of UTF-16 surrogates
+    fn escape(&mut self) -> Result<char, EventError> {
+        let escaped = match self.peek()? {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                let escape_offset = self.offset - 1;
+                self.next_byte()?;
+                let unit = self.hex()?;
+                return match unit {
+                    0xD800..=0xDBFF => {
+                        let low_offset = self.offset;
+                        let missing_low = TokenizeError {
+                            offset: low_offset,
+                            expected: "a low surrogate",
+                            found: Some('\\'),
+                        };
+                        if !self.skip(b'\\')? {
+                            return Err(self.error("a low surrogate"));
+                        }
+                        if !self.skip(b'u')? {
+                            return Err(missing_low.into());
+                        }
+                        let low = self.hex()?;
+                        if !(0xDC00..=0xDFFF).contains(&low) {
+                            return Err(missing_low.into());
+                        }
+                        let code = 0x10000 + ((unit as u32 - 0xD800) << 10) + (low as u32 - 0xDC00);
+                        Ok(char::from_u32(code).expect("Surrogate pair is not a character"))
+                    }
+                    0xDC00..=0xDFFF => Err(TokenizeError {
+                        offset: escape_offset,
+                        expected: "a high surrogate first",
+                        found: Some('\\'),
+                    }
+                    .into()),
+                    _ => Ok(char::from_u32(unit as u32).expect("Unit is not a character")),
+                };
+            }
+            _ => return Err(self.error("an escape character")),
+        };
+        self.next_byte()?;
+        Ok(escaped)
+    }
+
+    fn hex(&mut self) -> Result<u16, EventError> {
+        let mut unit = 0;
+        for _ in 0..4 {
+            let Some(digit) = self.peek()?.and_then(|byte| (byte as char).to_digit(16)) else {
+                return Err(self.error("a hexadecimal digit"));
+            };
+            self.next_byte()?;
+            unit = unit * 16 + digit as u16;
+        }
+        Ok(unit)
+    }
+
+    /// Numbers are `-? (0 | [1-9][0-9]*) (.[0-9]+)? ([eE][+-]?[0-9]+)?`
+    fn number(&mut self) -> Result<Token, EventError> {
+        let mut value = String::new();
+        if self.skip(b'-')? {
+            value.push('-');
+        }
+        // Leading zeroes are not allowed
+        if self.skip(b'0')? {
+            value.push('0');
+        } else {
+            self.digits(&mut value)?;
+        }
+
+        let mut is_integer = true;
+        if self.skip(b'.')? {
+            value.push('.');
+            self.digits(&mut value)?;
+            is_integer = false;
+        }
+        if let Some(exponent @ (b'e' | b'E')) = self.peek()? {
+            self.next_byte()?;
+            value.push(exponent as char);
+            if let Some(sign @ (b'+' | b'-')) = self.peek()? {
+                self.next_byte()?;
+                value.push(sign as char);
+            }
+            self.digits(&mut value)?;
+            is_integer = false;
+        }
+
+        match value.parse() {
+            // Integers too large for an `isize` are still numbers
+            Ok(integer) if is_integer => Ok(Token::Integer(integer)),
+            _ => Ok(Token::Float(value.parse().expect("Number was not valid"))),
+        }
+    }
+
+    fn digits(&mut self, value: &mut String) -> Result<(), EventError> {
+        if !matches!(self.peek()?, Some(b'0'..=b'9')) {
+            return Err(self.error("a digit"));
+        }
+        while let Some(digit @ b'0'..=b'9') = self.peek()? {
+            self.next_byte()?;
+            value.push(digit as char);
+        }
+        Ok(())
+    }
+
+    fn literal(&mut self) -> Result<Token, EventError> {
+        let offset = self.offset;
+        let (literal, token) = match self.peek()? {
+            Some(b't') => ("true", Token::Bool(true)),
+            Some(b'f') => ("false", Token::Bool(false)),
+            _ => ("null", Token::Null),
+        };
+        for byte in literal.bytes() {
+            if !self.skip(byte)? {
+                return Err(TokenizeError {
+                    offset,
+                    expected: "`true`, `false` or `null`",
+                    found: literal.chars().next(),
+                }
+                .into());
+            }
+        }
+        Ok(token)
+    }
+
+    fn peek(&mut self) -> io::Result<Option<u8>> {
+        Ok(self.reader.fill_buf()?.first().copied())
+    }
+
+    fn next_byte(&mut self) -> io::Result<Option<u8>> {
+        let byte = self.peek()?;
+        if byte.is_some() {
+            self.reader.consume(1);
+            self.offset += 1;
+        }
+        Ok(byte)
+    }
+
+    /// Skips the next byte if it is the expected one
+    fn skip(&mut self, expected: u8) -> io::Result<bool> {
+        let is_expected = self.peek()? == Some(expected);
+        if is_expected {
+            self.next_byte()?;
+        }
+        Ok(is_expected)
+    }
+
+    /// Only spaces, tabs and line breaks are whitespace in JSON
+    fn skip_whitespace(&mut self) -> io::Result<()> {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek()? {
+            self.next_byte()?;
+        }
+        Ok(())
+    }
+
+    /// Reads a character of up to four bytes, `None` if it is not valid UTF-8
+    fn read_char(&mut self) -> io::Result<Option<char>> {
+        let Some(lead) = self.next_byte()? else {
+            return Ok(None);
+        };
+        let len = match lead.leading_ones() {
+            0 => 1,
+            len @ 2..=4 => len as usize,
+            _ => return Ok(None),
+        };
+        let mut bytes = vec![lead];
+        while bytes.len() < len {
+            match self.peek()? {
+                Some(byte) if byte & 0xC0 == 0x80 => {
+                    self.next_byte()?;
+                    bytes.push(byte);
+                }
+                _ => return Ok(None),
+            }
+        }
+        Ok(std::str::from_utf8(&bytes)
+            .ok()
+            .and_then(|chr| chr.chars().next()))
+    }
+
+    /// Error for the character that is up next
+    fn error(&mut self, expected: &'static str) -> EventError {
+        let offset = self.offset;
+        let found = match self.peek() {
+            Ok(None) => Ok(None),
+            Ok(Some(_)) => self
+                .read_char()
+                .map(|chr| Some(chr.unwrap_or(char::REPLACEMENT_CHARACTER))),
+            Err(error) => Err(error),
+        };
+        match found {
+            Ok(found) => TokenizeError {
+                offset,
+                expected,
+                found,
+            }
+            .into(),
+            Err(error) => error.into(),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Token, TokenizeError> {
+    build_tree(Events::new(input.as_bytes())).map_err(|error| match error {
+        EventError::Tokenize(error) => error,
+        EventError::Io(error) => unreachable!("Could not read from a string: {error}"),
+    })
+}
+
+/// Builds the whole document from its events
+fn build_tree<R: Read>(events: Events<R>) -> Result<Token, EventError> {
+    // Arrays and objects that are still open, and the keys of the object values that are being read
+    let mut containers: Vec<Token> = Vec::new();
+    let mut keys: Vec<Key> = Vec::new();
+    let mut document = None;
+
+    for event in events {
+        let token = match event? {
+            Event::StartArray => {
+                containers.push(Token::Array(Vec::new()));
+                continue;
+            }
+            Event::StartObject => {
+                containers.push(Token::Object(Vec::new()));
+                continue;
+            }
+            Event::Key(key) => {
+                keys.push(key);
+                continue;
+            }
+            Event::EndArray | Event::EndObject => containers
+                .pop()
+                .expect("Closed a container that was not opened"),
+            Event::Value(token) => token,
+        };
+
+        match containers.last_mut() {
+            Some(Token::Array(elements)) => elements.push(token),
+            Some(Token::Object(pairs)) => {
+                pairs.push((keys.pop().expect("Object value without a key"), token))
+            }
+            Some(_) => unreachable!("Only arrays and objects are opened"),
+            None => document = Some(token),
+        }
+    }
+
+    Ok(document.expect("Events ended without a value"))
+}
+
+/// Running sum of an array or object that is still open
+#[derive(Debug, Default)]
+struct OpenContainer {
+    sum: isize,
+    is_object: bool,
+    /// One of the values of the object is the excluded string
+    is_excluded: bool,
+}
+
+/// Returned when the integers of a document add up to more than an `isize` holds
+const OVERFLOW: &str = "The integers add up to more than fits in 64 bits";
+
+/// Sums the integers while the document is read, leaving out the objects that have the excluded
+/// string as one of their values
+fn sum_integers<R: Read>(reader: R, excluded: Option<&str>) -> Result<isize, Error> {
+    // The document itself is the bottom container
+    let mut containers = vec![OpenContainer::default()];
+
+    for event in Events::new(reader) {
+        let container = containers.last_mut().expect("Closed the document");
+        match event? {
+            Event::StartArray => containers.push(OpenContainer::default()),
+            Event::StartObject => containers.push(OpenContainer {
+                is_object: true,
+                ..Default::default()
+            }),
+            Event::EndArray | Event::EndObject => {
+                let closed = containers.pop().expect("Closed the document");
+                if !closed.is_excluded {
+                    let container = containers.last_mut().expect("Closed the document");
+                    container.sum = container.sum.checked_add(closed.sum).ok_or(OVERFLOW)?;
+                }
+            }
+            Event::Key(_) => {}
+            Event::Value(Token::Integer(value)) => {
+                container.sum = container.sum.checked_add(value).ok_or(OVERFLOW)?
+            }
+            Event::Value(Token::String(value)) if Some(value.as_str()) == excluded => {
+                container.is_excluded |= container.is_object
+            }
+            Event::Value(_) => {}
+        }
+    }
+
+    Ok(containers.pop().expect("Closed the document").sum)
+}
+
+/// Predicate on arrays and objects, the ones that match are left out with everything in them
+#[derive(Debug, Clone, PartialEq)]
+enum Prune {
+    /// Objects that have this string as one of their values
+    ObjectWithValue(String),
+    Arrays,
+    Objects,
+}
+
+impl Prune {
+    fn matches(&self, token: &Token) -> bool {
+        match (self, token) {
+            (Prune::ObjectWithValue(excluded), Token::Object(pairs)) => pairs
+                .iter()
+                .any(|(_, value)| matches!(value, Token::String(value) if value == excluded)),
+            (Prune::Arrays, Token::Array(_)) | (Prune::Objects, Token::Object(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Step of a key path, written as `key`, `*` or `**` between dots
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+    /// The value of this key in an object
+    Key(Key),
+    /// Every element of an array or value of an object
+    Any,
+    /// Any number of levels deeper, including none
+    Descendants,
+}
+
+/// Selects parts of a document and folds the numbers in them
+#[derive(Debug, Default)]
+struct Query {
+    /// Arrays and objects that match any of these are left out
+    prune: Vec<Prune>,
+    /// Only numbers under the values at the end of the path are folded, all of them when the path
+    /// is empty
+    path: Vec<Step>,
+    /// Print the pruned document instead of its sum
+    print: Option<Layout>,
+}
+
+impl Query {
+    fn from_options(options: &[QueryOption]) -> Result<Self, Error> {
+        let mut query = Query::default();
+        for option in options {
+            match (option.name.as_str(), &option.value) {
+                ("exclude-value", Some(value)) => {
+                    query.prune.push(Prune::ObjectWithValue(value.clone()))
+                }
+                ("only-arrays", None) => query.prune.push(Prune::Objects),
+                ("only-objects", None) => query.prune.push(Prune::Arrays),
+                ("path", Some(path)) => query.path = parse_path(path),
+                ("print", None) => query.print = Some(Layout::Indented(2)),
+                ("print", Some(layout)) => {
+                    query.print = Some(match layout.as_str() {
+                        "compact" => Layout::Compact,
+                        indent => Layout::Indented(indent.parse().map_err(|_| {
+                            format!("--print takes `compact` or an indentation, not `{indent}`")
+                        })?),
+                    })
+                }
+                ("exclude-value" | "path", None) => {
+                    return Err(format!("--{} needs a value", option.name).into())
+                }
+                ("only-arrays" | "only-objects", Some(_)) => {
+                    return Err(format!("--{} takes no value", option.name).into())
+                }
+                _ => return Err(format!("Unknown option --{}", option.name).into()),
+            }
+        }
+        if query.print.is_some() && !query.path.is_empty() {
+            return Err("--print cannot be combined with --path".into());
+        }
+        Ok(query)
+    }
+
+    /// The document without the pruned arrays and objects, `None` when the document itself is
+    /// pruned
+    fn prune(&self, token: &Token) -> Option<Token> {
+        if self.prune.iter().any(|prune| prune.matches(token)) {
+            return None;
+        }
+        Some(match token {
+            Token::Array(elements) => Token::Array(
+                elements
+                    .iter()
+                    .filter_map(|element| self.prune(element))
+                    .collect(),
+            ),
+            Token::Object(pairs) => Token::Object(
+                pairs
+                    .iter()
+                    .filter_map(|(key, value)| Some((key.clone(), self.prune(value)?)))
+                    .collect(),
+            ),
+            token => token.clone(),
+        })
+    }
+
+    /// Folds the numbers, integers and floats, in the selected parts of the document
+    fn fold<T>(&self, document: &Token, init: T, mut f: impl FnMut(T, &Token) -> T) -> T {
+        let mut acc = init;
+        // Every value comes with the positions in the path that it was reached at, or with none
+        // once the path was followed to its end
+        let mut token_stack: Vec<(&Token, Option<Vec<usize>>)> =
+            vec![(document, self.selected(self.closure(vec![0])))];
+
+        while let Some((token, positions)) = token_stack.pop() {
+            if self.prune.iter().any(|prune| prune.matches(token)) {
+                continue;
+            }
+            match token {
+                Token::Integer(_) | Token::Float(_) if positions.is_none() => acc = f(acc, token),
+                Token::Array(elements) => {
+                    for element in elements {
+                        let positions = positions
+                            .as_ref()
+                            .and_then(|positions| self.selected(self.advance(positions, None)));
+                        token_stack.push((element, positions));
+                    }
+                }
+                Token::Object(pairs) => {
+                    for (key, value) in pairs {
+                        let positions = positions.as_ref().and_then(|positions| {
+                            self.selected(self.advance(positions, Some(key)))
+                        });
+                        token_stack.push((value, positions));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        acc
+    }
+
+    /// Sums the integers in the selected parts of the document
+    fn sum(&self, document: &Token) -> Result<isize, Error> {
+        let sum = self.fold(document, Some(0isize), |sum, token| match token {
+            Token::Integer(value) => sum?.checked_add(*value),
+            _ => sum,
+        });
+        Ok(sum.ok_or(OVERFLOW)?)
+    }
+
+    /// `None` when the path was followed to its end
+    fn selected(&self, positions: Vec<usize>) -> Option<Vec<usize>> {
+        (!positions.contains(&self.path.len())).then_some(positions)
+    }
+
+    /// The positions after going into an element of an array, or the value of a key of an object
+    fn advance(&self, positions: &[usize], key: Option<&str>) -> Vec<usize> {
+        let next = positions
+            .iter()
+            .filter_map(|&position| match &self.path.get(position)? {
+                Step::Key(step) if Some(step.as_str()) == key => Some(position + 1),
+                Step::Key(_) => None,
+                Step::Any => Some(position + 1),
+                // Stays, the step after it was already added by the closure
+                Step::Descendants => Some(position),
+            })
+            .collect();
+        self.closure(next)
+    }
+
+    /// Adds the positions that can be skipped to, because `**` also matches no levels
+    fn closure(&self, mut positions: Vec<usize>) -> Vec<usize> {
+        let mut index = 0;
+        while let Some(&position) = positions.get(index) {
+            if self.path.get(position) == Some(&Step::Descendants)
+                && !positions.contains(&(position + 1))
+            {
+                positions.push(position + 1);
+            }
+            index += 1;
+        }
+        positions
+    }
+}
+
+fn parse_path(path: &str) -> Vec<Step> {
+    path.split('.')
+        .filter(|step| !step.is_empty())
+        .map(|step| match step {
+            "*" => Step::Any,
+            "**" => Step::Descendants,
+            key => Step::Key(key.to_owned()),
+        })
+        .collect()
+}
+
+/// Sums the integers of the document, like the parts but with the pruning and path given as
+/// options, or prints what is left of the document after pruning
+fn query(data: &str, options: &[QueryOption]) -> Result<Answer, Error> {
+    let query = Query::from_options(options)?;
+    let document = tokenize(data)?;
+    Ok(match query.print {
+        Some(layout) => query
+            .prune(&document)
+            .unwrap_or(Token::Null)
+            .print(layout)
+            .into(),
+        None => query.sum(&document)?.into(),
+    })
+}
+
+/// Only integers are counted, the documents do not contain fractions
+fn part_one_with_tokenizer(data: &str) -> Result<isize, Error> {
+    Query::default().sum(&tokenize(data)?)
+}
+
+/// Reads the document as a stream, so it does not have to be kept as a whole
+fn part_two(data: &str) -> Result<isize, Error> {
+    sum_integers(data.as_bytes(), Some("red"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read};
+
+    use aoc_core::{Answer, QueryOption};
+    use proptest::prelude::*;
+
+    use super::{
+        parse_path, part_one_with_tokenizer, part_two, query, sum_integers, tokenize, Event,
+        EventError, Events, Layout, Prune, Query, Step, Token, TokenizeError,
+    };
+
+    #[test]
+    fn tokenize_string() {
+        assert_eq!(tokenize("\"hi\""), Ok(Token::String("hi".into())))
+    }
+
+    #[test]
+    fn tokenize_integer() {
+        assert_eq!(tokenize("123"), Ok(Token::Integer(123)))
+    }
+
+    #[test]
+    fn tokenize_negative_integer() {
+        assert_eq!(tokenize("-123"), Ok(Token::Integer(-123)))
+    }
+
+    #[test]
+    fn tokenize_empty_array() {
+        assert_eq!(tokenize("[]"), Ok(Token::Array(Vec::new())))
+    }
+
+    #[test]
+    fn tokenize_array_numeric_single() {
+        assert_eq!(tokenize("[1]"), Ok(Token::Array(vec![Token::Integer(1)])))
+    }
+
+    #[test]
+    fn tokenize_array_numeric_multiple() {
+        assert_eq!(
+            tokenize("[1,2]"),
+            Ok(Token::Array(vec![Token::Integer(1), Token::Integer(2)]))
+        )
+    }
+
+    #[test]
+    fn tokenize_array_string_single() {
+        assert_eq!(
+            tokenize("[\"hoi\"]"),
+            Ok(Token::Array(vec![Token::String("hoi".to_string())]))
+        )
+    }
+
+    #[test]
+    fn tokenize_array_string_multiple() {
+        assert_eq!(
+            tokenize("[\"hoi\",\"doei\"]"),
+            Ok(Token::Array(vec![
+                Token::String("hoi".into()),
+                Token::String("doei".into())
+            ]))
+        )
+    }
+
+    #[test]
+    fn tokenize_nested_empty_array() {
+        assert_eq!(
+            tokenize("[[]]"),
+            Ok(Token::Array(vec![Token::Array(Vec::new())]))
+        );
+        assert_eq!(
+            tokenize("[[[]]]"),
+            Ok(Token::Array(vec![Token::Array(vec![Token::Array(vec![])])]))
+        );
+    }
+
+    #[test]
+    fn tokenize_nested_array() {
+        assert_eq!(
+            tokenize("[[1], [2]]"),
+            Ok(Token::Array(vec![
+                Token::Array(vec![Token::Integer(1)]),
+                Token::Array(vec![Token::Integer(2)])
+            ]))
+        );
+        assert_eq!(
+            tokenize("[[1], 2]"),
+            Ok(Token::Array(vec![
+                Token::Array(vec![Token::Integer(1)]),
+                Token::Integer(2)
+            ]))
+        );
+    }
+
+    #[test]
+    fn tokenize_whitespace() {
+        let empty_array = Ok(Token::Array(Vec::new()));
+        assert_eq!(tokenize(" []"), empty_array);
+        assert_eq!(tokenize("[] "), empty_array);
+        assert_eq!(tokenize("[ ]"), empty_array);
+
+        let array_one_two = Ok(Token::Array(vec![Token::Integer(1), Token::Integer(2)]));
+        assert_eq!(tokenize("[1 ,2]"), array_one_two);
+        assert_eq!(tokenize("[1, 2]"), array_one_two);
+        assert_eq!(tokenize("[ 1,2]"), array_one_two);
+        assert_eq!(tokenize("[1,2 ]"), array_one_two);
+    }
+
+    #[test]
+    fn tokenize_empty_object() {
+        assert_eq!(tokenize("{}"), Ok(Token::Object(vec![])));
+    }
+
+    #[test]
+    fn tokenize_object() {
+        assert_eq!(
+            tokenize("{\"a\": 12}"),
+            Ok(Token::Object(vec![("a".into(), Token::Integer(12))]))
+        );
+    }
+
+    #[test]
+    fn tokenize_object_in_array() {
+        assert_eq!(
+            tokenize("[{}]"),
+            Ok(Token::Array(vec![Token::Object(vec![])]))
+        );
+    }
+
+    #[test]
+    fn tokenize_array_in_object() {
+        assert_eq!(
+            tokenize("{ \"array\": [] }"),
+            Ok(Token::Object(vec![("array".into(), Token::Array(vec![]))]))
+        );
+    }
+
+    #[test]
+    fn tokenize_literals_and_floats() {
+        assert_eq!(
+            tokenize("[true, false, null, -1.5e1, 99999999999999999999]"),
+            Ok(Token::Array(vec![
+                Token::Bool(true),
+                Token::Bool(false),
+                Token::Null,
+                Token::Float(-15.0),
+                Token::Float(1e20),
+            ]))
+        );
+    }
+
+    #[test]
+    fn tokenize_string_with_whitespace() {
+        assert_eq!(tokenize("\" hi \""), Ok(Token::String(" hi ".into())));
+    }
+
+    #[test]
+    fn tokenize_escapes() {
+        assert_eq!(
+            tokenize(r#""\"\\\/\b\f\n\r\t\u00e9\ud83c\udf84""#),
+            Ok(Token::String("\"\\/\u{8}\u{c}\n\r\t\u{e9}\u{1f384}".into()))
+        );
+    }
+
+    #[test]
+    fn tokenize_error_offsets() {
+        assert_eq!(
+            tokenize("[1,]"),
+            Err(TokenizeError {
+                offset: 3,
+                expected: "a value",
+                found: Some(']'),
+            })
+        );
+        assert_eq!(
+            tokenize("{\"a\" 1}"),
+            Err(TokenizeError {
+                offset: 5,
+                expected: "`:`",
+                found: Some('1'),
+            })
+        );
+        assert_eq!(
+            tokenize("[\"\\udc00\"]").map_err(|error| error.offset),
+            Err(2)
+        );
+        assert_eq!(
+            tokenize("").unwrap_err().to_string(),
+            "Expected a value, found the end of the document at byte 0"
+        );
+    }
+
+    /// Documents starting with `y_` must be accepted and those with `n_` rejected, those with
+    /// `i_` may be either but must not panic
+    #[test]
+    fn tokenize_conformance_corpus() {
+        let corpus = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/json");
+        let mut documents = 0;
+        for entry in std::fs::read_dir(corpus).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            let result = tokenize(&std::fs::read_to_string(&path).unwrap());
+            match &name[..2] {
+                "y_" => assert!(result.is_ok(), "{name} was rejected: {result:?}"),
+                "n_" => assert!(result.is_err(), "{name} was accepted: {result:?}"),
+                "i_" => {}
+                _ => panic!("{name} does not say whether it should be accepted"),
+            }
+            documents += 1;
+        }
+        assert!(documents > 0);
+    }
+
+    #[test]
+    fn events_in_document_order() {
+        let events: Result<Vec<_>, _> =
+            Events::new(&b"{\"a\": [1, \"b\"], \"c\": {}}"[..]).collect();
+        assert_eq!(
+            events.unwrap(),
+            vec![
+                Event::StartObject,
+                Event::Key("a".into()),
+                Event::StartArray,
+                Event::Value(Token::Integer(1)),
+                Event::Value(Token::String("b".into())),
+                Event::EndArray,
+                Event::Key("c".into()),
+                Event::StartObject,
+                Event::EndObject,
+                Event::EndObject,
+            ]
+        );
+    }
+
+    #[test]
+    fn events_stop_after_error() {
+        let mut events = Events::new(&b"[1 2]"[..]);
+        assert_eq!(events.next().unwrap().unwrap(), Event::StartArray);
+        assert_eq!(
+            events.next().unwrap().unwrap(),
+            Event::Value(Token::Integer(1))
+        );
+        assert!(matches!(
+            events.next(),
+            Some(Err(EventError::Tokenize(TokenizeError { offset: 3, .. })))
+        ));
+        assert!(events.next().is_none());
+    }
+
+    #[test]
+    fn events_report_read_errors() {
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+
+        let error = sum_integers(b"[1, ".chain(Failing), None).unwrap_err();
+        assert!(matches!(error.downcast_ref(), Some(EventError::Io(_))));
+    }
+
+    /// Quick and dirty version of part one, kept around as a cross-check for the tokenizer.
+    fn part_one_by_splitting(data: &str) -> i32 {
+        let segments = data.split(&[',', ':', '(', ')', '[', ']', '"', '{', '}'][..]);
+
+        let numbers: i32 = segments
+            .into_iter()
+            .filter_map(|x| x.parse::<i32>().ok())
+            .sum();
+
+        numbers
+    }
+
+    #[test]
+    fn part_one_matches_splitting() {
+        for data in [
+            "[1,2,3]",
+            "{\"a\":2,\"b\":4}",
+            "[[[3]]]",
+            "{\"a\":{\"b\":4},\"c\":-1}",
+            "{\"a\":[-1,1]}",
+            "[-1,{\"a\":1}]",
+            "[]",
+            "{}",
+            "[1,{\"c\":\"red\",\"b\":2},3]",
+        ] {
+            assert_eq!(
+                part_one_with_tokenizer(data).unwrap(),
+                part_one_by_splitting(data) as isize,
+                "{data}"
+            );
+        }
+    }
+
+    #[test]
+    fn part_two_skips_red_objects() {
+        assert_eq!(part_two("[1,2,3]").unwrap(), 6);
+        assert_eq!(part_two("[1,{\"c\":\"red\",\"b\":2},3]").unwrap(), 4);
+        assert_eq!(
+            part_two("{\"d\":\"red\",\"e\":[1,2,3,4],\"f\":5}").unwrap(),
+            0
+        );
+        assert_eq!(part_two("[1,\"red\",5]").unwrap(), 6);
+    }
+
+    #[test]
+    fn sums_that_overflow() {
+        let data = "[9223372036854775807,1]";
+        assert!(part_one_with_tokenizer(data).is_err());
+        assert!(part_two(data).is_err());
+        assert!(query(data, &[]).is_err());
+        // Only the sum of a whole container is checked, inside it the integers are added in order
+        assert!(part_two("[[9223372036854775807],[1]]").is_err());
+        assert_eq!(
+            part_two("[9223372036854775807,-1,{\"a\":\"red\",\"b\":1}]").unwrap(),
+            9223372036854775806
+        );
+    }
+
+    #[test]
+    fn sum_integers_streams_large_documents() {
+        // Ten megabytes of whitespace is never held in memory at once
+        let document = b"[1, "
+            .chain(io::repeat(b' ').take(10_000_000))
+            .chain(&b"{\"a\": \"red\", \"b\": 7}, 2]"[..]);
+        assert_eq!(sum_integers(document, Some("red")).unwrap(), 3);
+    }
+
+    fn option(name: &str, value: Option<&str>) -> QueryOption {
+        QueryOption {
+            name: name.into(),
+            value: value.map(String::from),
+        }
+    }
+
+    #[test]
+    fn query_excludes_any_value() {
+        let data = r#"[1, {"c": "blue", "b": 2}, {"c": "red", "b": 4}, 8]"#;
+        assert_eq!(query(data, &[]).unwrap(), 15.into());
+        assert_eq!(
+            query(data, &[option("exclude-value", Some("red"))]).unwrap(),
+            11.into()
+        );
+        assert_eq!(
+            query(data, &[option("exclude-value", Some("blue"))]).unwrap(),
+            13.into()
+        );
+        assert_eq!(
+            query(
+                data,
+                &[
+                    option("exclude-value", Some("red")),
+                    option("exclude-value", Some("blue"))
+                ]
+            )
+            .unwrap(),
+            9.into()
+        );
+    }
+
+    #[test]
+    fn query_only_arrays_or_objects() {
+        let data = r#"[1, {"a": 2, "b": [4]}, [8, {"c": 16}]]"#;
+        assert_eq!(
+            query(data, &[option("only-arrays", None)]).unwrap(),
+            9.into()
+        );
+        assert_eq!(
+            query(
+                r#"{"a": 2, "b": [4], "c": {"d": 8}}"#,
+                &[option("only-objects", None)]
+            )
+            .unwrap(),
+            10.into()
+        );
+    }
+
+    #[test]
+    fn query_under_key_path() {
+        let data = r#"{"x": [1, {"x": 2}], "y": {"x": 4, "z": {"x": 8}}, "z": 16}"#;
+        let under = |path: &str| query(data, &[option("path", Some(path))]).unwrap();
+        assert_eq!(under("x"), 3.into());
+        assert_eq!(under("y.x"), 4.into());
+        assert_eq!(under("*.x"), 4.into());
+        assert_eq!(under("*.*.x"), 10.into());
+        // Nested matches are only counted once
+        assert_eq!(under("**.x"), 15.into());
+        assert_eq!(under("y.**"), 12.into());
+        assert_eq!(under("nothing"), 0.into());
+    }
+
+    #[test]
+    fn query_rejects_unknown_options() {
+        assert!(query("[]", &[option("exclude-value", None)]).is_err());
+        assert!(query("[]", &[option("only-arrays", Some("yes"))]).is_err());
+        assert!(query("[]", &[option("colour", Some("red"))]).is_err());
+    }
+
+    #[test]
+    fn fold_numeric_leaves() {
+        let document = tokenize(r#"[1, 2.5, {"a": -3, "b": "4"}, [true, null]]"#).unwrap();
+        let query = Query {
+            path: parse_path("**"),
+            ..Default::default()
+        };
+        assert_eq!(query.fold(&document, 0, |count, _| count + 1), 3);
+        assert_eq!(
+            query.fold(&document, f64::MIN, |max, token| match token {
+                Token::Integer(value) => max.max(*value as f64),
+                Token::Float(value) => max.max(*value),
+                _ => max,
+            }),
+            2.5
+        );
+        assert_eq!(
+            parse_path("a.*.**"),
+            vec![Step::Key("a".into()), Step::Any, Step::Descendants]
+        );
+        assert!(Prune::Objects.matches(&Token::Object(vec![])));
+    }
+
+    #[test]
+    fn print_layouts() {
+        let document = tokenize(r#"{"a": [1, 2.5, true, null], "b": {}, "c": []}"#).unwrap();
+        assert_eq!(
+            document.print(Layout::Compact),
+            r#"{"a":[1,2.5,true,null],"b":{},"c":[]}"#
+        );
+        assert_eq!(document.to_string(), document.print(Layout::Compact));
+        assert_eq!(
+            document.print(Layout::Indented(4)),
+            "{\n    \"a\": [\n        1,\n        2.5,\n        true,\n        null\n    ],\n    \"b\": {},\n    \"c\": []\n}"
+        );
+        assert_eq!(format!("{document:#}"), document.print(Layout::Indented(2)));
+    }
+
+    #[test]
+    fn print_escapes_strings() {
+        let token = Token::String("\"\\/\u{8}\u{c}\n\r\t\u{1}é🎄".into());
+        assert_eq!(token.to_string(), r#""\"\\/\b\f\n\r\t\u0001é🎄""#);
+        assert_eq!(Token::Float(1.0).to_string(), "1.0");
+        assert_eq!(Token::Float(f64::INFINITY).to_string(), "null");
+    }
+
+    #[test]
+    fn query_prints_pruned_document() {
+        let data = r#"[1,{"c":"red","b":2},{"c":"blue"},3]"#;
+        assert_eq!(
+            query(
+                data,
+                &[
+                    option("exclude-value", Some("red")),
+                    option("print", Some("compact"))
+                ]
+            )
+            .unwrap(),
+            Answer::from(r#"[1,{"c":"blue"},3]"#)
+        );
+        assert_eq!(
+            query(
+                r#"{"a":"red"}"#,
+                &[option("exclude-value", Some("red")), option("print", None)]
+            )
+            .unwrap(),
+            Answer::from("null")
+        );
+        assert!(query(data, &[option("print", Some("wide"))]).is_err());
+    }
+
+    fn any_token() -> impl Strategy<Value = Token> {
+        let leaf = prop_oneof![
+            any::<isize>().prop_map(Token::Integer),
+            any::<f64>()
+                .prop_filter("JSON has no infinities", |value| value.is_finite())
+                .prop_map(Token::Float),
+            any::<bool>().prop_map(Token::Bool),
+            Just(Token::Null),
+            any::<String>().prop_map(Token::String),
+        ];
+        leaf.prop_recursive(4, 64, 8, |inner| {
+            prop_oneof![
+                prop::collection::vec(inner.clone(), 0..8).prop_map(Token::Array),
+                prop::collection::vec((any::<String>(), inner), 0..8).prop_map(Token::Object),
+            ]
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn print_round_trips(token in any_token(), indent in 0..5usize) {
+            prop_assert_eq!(tokenize(&token.print(Layout::Compact)), Ok(token.clone()));
+            prop_assert_eq!(tokenize(&token.print(Layout::Indented(indent))), Ok(token));
+        }
+    }
+}
@@ -1,16 +1,33 @@
-use std::{collections::HashMap, fs::File, io::BufRead, io::BufReader};
+use std::collections::HashMap;
 
+use aoc_core::{parsed, Answer, Error, Solution};
 use itertools::Itertools;
 
-fn main() {
-    let file = File::open("./data/13.txt").expect("Could not read data file");
-    let reader = BufReader::new(file);
+pub struct Day;
 
-    let data_vec = reader
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        let data_vec = load_data(input);
+        Ok(part_one(&happiness_map(&data_vec)).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        let data_vec = load_data(input);
+        Ok(part_two(&happiness_map(&data_vec)).into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        parsed(load_data(input));
+        Some(Ok(()))
+    }
+}
+
+type Happiness = (String, String, i32);
+
+fn load_data(data: &str) -> Vec<Happiness> {
+    data
         .lines()
         .filter_map(|line| {
-            let line = line.ok()?;
-
             let mut segments = line.split_whitespace();
 
             let name1 = segments.next()?;
@@ -27,18 +44,17 @@ fn main() {
 
             Some((name1.to_owned(), name2.to_owned(), score))
         })
-        .collect::<Vec<_>>();
+        .collect::<Vec<_>>()
+}
 
-    let data = data_vec
+fn happiness_map(data_vec: &[Happiness]) -> HashMap<(&str, &str), i32> {
+    data_vec
         .iter()
         .map(|(x, y, z)| ((x.as_str(), y.as_str()), *z))
-        .collect::<HashMap<(&str, &str), i32>>();
-
-    part_one(&data);
-    part_two(&data);
+        .collect::<HashMap<(&str, &str), i32>>()
 }
 
-fn part_one(data: &HashMap<(&str, &str), i32>) {
+fn part_one(data: &HashMap<(&str, &str), i32>) -> i32 {
     let names = data
         .keys()
         .map(|(name1, _name2)| *name1)
@@ -72,10 +88,10 @@ fn part_one(data: &HashMap<(&str, &str), i32>) {
         .max()
         .expect("Could not find max");
 
-    println!("Part one: {total}");
+    total
 }
 
-fn part_two(data: &HashMap<(&str, &str), i32>) {
+fn part_two(data: &HashMap<(&str, &str), i32>) -> i32 {
     let names = data
         .keys()
         .map(|(name1, _name2)| *name1)
@@ -105,5 +121,5 @@ fn part_two(data: &HashMap<(&str, &str), i32>) {
         .max()
         .expect("Could not find max");
 
-    println!("Part two: {total}");
+    total
 }
@@ -1,10 +1,27 @@
-use std::{fs::File, io::BufRead, io::BufReader};
+use aoc_core::{parsed, Answer, Error, Solution};
 
-fn main() {
-    let file = File::open("./data/14.txt").expect("Could not load datafile");
-    let data = BufReader::new(file)
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        parsed(load_reindeer(input));
+        Some(Ok(()))
+    }
+}
+
+const FINISH_TIME: usize = 2503;
+
+fn load_reindeer(data: &str) -> Vec<(usize, usize, usize)> {
+    data
         .lines()
-        .filter_map(|line| line.ok())
         .filter_map(|line| {
             let mut segments = line.split_whitespace();
             let speed = segments.nth(3)?.parse::<usize>().ok()?;
@@ -12,21 +29,23 @@ fn main() {
             let rest_time = segments.nth(6)?.parse::<usize>().ok()?;
             Some((speed.to_owned(), run_time.to_owned(), rest_time.to_owned()))
         })
-        .collect::<Vec<_>>();
+        .collect::<Vec<_>>()
+}
 
-    let finish_time = 2503;
+fn part_one(data: &str) -> usize {
+    let data = load_reindeer(data);
 
-    let max_distance = distances_after(&data, finish_time)
+    distances_after(&data, FINISH_TIME)
         .max()
-        .expect("Could not find maximum distance");
+        .expect("Could not find maximum distance")
+}
 
-    println!("Part one: {max_distance}");
+fn part_two(data: &str) -> usize {
+    let data = load_reindeer(data);
 
     let mut reindeer = vec![0; data.len()];
 
-    println!("Reindeer count: {}", data.len());
-
-    for cur_time in 1..(finish_time) {
+    for cur_time in 1..(FINISH_TIME) {
         let distances = distances_after(&data, cur_time).collect::<Vec<_>>();
         let max_distance = distances.iter().max().expect("Could not find max distance");
         let winners = distances
@@ -39,7 +58,10 @@ fn main() {
         }
     }
 
-    println!("{:#?}", reindeer);
+    reindeer
+        .into_iter()
+        .max()
+        .expect("Could not find the winning reindeer")
 }
 
 fn distances_after(
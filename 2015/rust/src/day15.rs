@@ -1,10 +1,25 @@
-use std::{fs::File, io::BufRead, io::BufReader};
+use aoc_core::{parsed, Answer, Error, Solution};
 
-fn main() {
-    let file = File::open("./data/15.txt").expect("Could not load datafile");
-    let data = BufReader::new(file)
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        parsed(load_ingredients(input));
+        Some(Ok(()))
+    }
+}
+
+fn load_ingredients(data: &str) -> Vec<Vec<isize>> {
+    data
         .lines()
-        .filter_map(|line| line.ok())
         .filter_map(|line| {
             let mut segments = line.split(": ");
             let property_line = segments.nth(1)?;
@@ -16,7 +31,11 @@ fn main() {
                 })
                 .collect::<Vec<_>>())
         })
-        .collect::<Vec<_>>();
+        .collect::<Vec<_>>()
+}
+
+fn part_one(data: &str) -> isize {
+    let data = load_ingredients(data);
 
     let total_teaspoons = 100;
     let total_ingredients = data.len();
@@ -27,7 +46,6 @@ fn main() {
         .iter()
         .map(|recipe| {
             (0..total_properties - 1)
-                .into_iter()
                 .map(|property_index| {
                     let val = (0..total_ingredients)
                         .map(|ingredient_index| {
@@ -43,8 +61,17 @@ fn main() {
         .max()
         .expect("Could not find max");
 
-    println!("Part one: {max}");
+    max
+}
 
+fn part_two(data: &str) -> isize {
+    let data = load_ingredients(data);
+
+    let total_teaspoons = 100;
+    let total_ingredients = data.len();
+    let total_properties = data[0].len();
+
+    let recipes = total_sum(total_teaspoons, total_ingredients);
     let max_with_cal_constraint: isize = recipes
         .iter()
         .filter_map(|recipe| {
@@ -60,7 +87,6 @@ fn main() {
 
             Some(
                 (0..total_properties - 1)
-                    .into_iter()
                     .map(|property_index| {
                         let val = (0..total_ingredients)
                             .map(|ingredient_index| {
@@ -77,7 +103,7 @@ fn main() {
         .max()
         .expect("Could not find max");
 
-    println!("Part two: {max_with_cal_constraint}");
+    max_with_cal_constraint
 }
 
 fn total_sum(total: usize, len: usize) -> Vec<Vec<usize>> {
@@ -1,8 +1,6 @@
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use std::collections::HashMap;
+
+use aoc_core::{parsed, Answer, Error, Solution};
 
 enum CorrectionType {
     Fewer,
@@ -10,12 +8,29 @@ enum CorrectionType {
     More,
 }
 
-fn main() {
-    let file = File::open("./data/16.txt").expect("Could not read datafile");
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        parsed(load_aunts(input));
+        Some(Ok(()))
+    }
+}
+
+type Aunt = (String, Vec<(String, usize)>);
+
+fn load_aunts(data: &str) -> Vec<Aunt> {
     // Part one: Allocation might be uncessary, we can only collect the once that match the conditions
-    let data = BufReader::new(file)
+    data
         .lines()
-        .filter_map(|res| res.ok())
         .filter_map(|line| {
             let (name_segment, attributes_segment) = line.split_once(": ")?;
             Some((
@@ -32,8 +47,10 @@ fn main() {
                     .collect::<Vec<_>>(),
             ))
         })
-        .collect::<Vec<_>>();
+        .collect::<Vec<_>>()
+}
 
+fn known_values() -> HashMap<&'static str, (CorrectionType, usize)> {
     // Considering the size of the dataset a linear search with O(n m) complexity could be faster considering the size of the known values list.
     let mut known_values: HashMap<&str, (CorrectionType, usize)> = HashMap::new();
     known_values.insert("children", (CorrectionType::Equal, 3));
@@ -47,7 +64,20 @@ fn main() {
     known_values.insert("cars", (CorrectionType::Equal, 2));
     known_values.insert("perfumes", (CorrectionType::Equal, 1));
 
-    println!("Part one");
+    known_values
+}
+
+/// Names are formatted as `Sue <number>`, the number is what we are looking for
+fn sue_number(name: &str) -> usize {
+    name.trim_start_matches("Sue ")
+        .parse()
+        .expect("Could not parse the number of Sue")
+}
+
+fn part_one(data: &str) -> usize {
+    let data = load_aunts(data);
+    let known_values = known_values();
+
     data.iter()
         .filter(|(_name, attributes)| {
             attributes.iter().all(|(attr_name, attr_val)| {
@@ -57,11 +87,15 @@ fn main() {
                 known_value.1 == *attr_val
             })
         })
-        .for_each(|(name, _)| {
-            println!("{name}");
-        });
+        .map(|(name, _)| sue_number(name))
+        .next()
+        .expect("Could not find a matching Sue")
+}
+
+fn part_two(data: &str) -> usize {
+    let data = load_aunts(data);
+    let known_values = known_values();
 
-    println!("Part two");
     data.iter()
         .filter(|(_, attributes)| {
             attributes.iter().all(|(attr_name, attr_val)| {
@@ -75,7 +109,7 @@ fn main() {
                 }
             })
         })
-        .for_each(|(name, _)| {
-            println!("{name}");
-        });
+        .map(|(name, _)| sue_number(name))
+        .next()
+        .expect("Could not find a matching Sue")
 }
@@ -1,21 +1,22 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
+use aoc_core::{Answer, Error, Solution};
 use itertools::Itertools;
 
-fn main() {
-    // Lets try brute force here as it should have about 1e6 values.
-    part_one();
-    part_two();
+// Lets try brute force here as it should have about 1e6 values.
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
 }
 
-fn part_one() {
-    let file = File::open("./data/17.txt").expect("Could not open file");
-    let containers = BufReader::new(file)
+fn part_one(data: &str) -> usize {
+    let containers = data
         .lines()
-        .filter_map(|res| res.ok())
         .filter_map(|line| line.parse::<usize>().ok())
         .collect::<Vec<_>>();
 
@@ -27,20 +28,18 @@ fn part_one() {
         .filter(|&container_volume| container_volume == eggnog_volume)
         .count();
 
-    println!("Part one: {container_count}");
+    container_count
 }
 
-fn part_two() {
-    let file = File::open("./data/17.txt").expect("Could not open file");
-    let containers = BufReader::new(file)
+fn part_two(data: &str) -> usize {
+    let containers = data
         .lines()
-        .filter_map(|res| res.ok())
         .filter_map(|line| line.parse::<usize>().ok())
         .collect::<Vec<_>>();
 
     let eggnog_volume = 150;
 
-    let container_count = (1..=containers.len())
+    let (_smallest_container_size, container_count) = (1..=containers.len())
         .flat_map(|container_count| containers.iter().combinations(container_count))
         .map(|containers| (containers.len(), containers.into_iter().sum::<usize>()))
         .filter(|(_, container_volume)| *container_volume == eggnog_volume)
@@ -55,5 +54,5 @@ fn part_two() {
             }
         });
 
-    println!("Part two: {container_count:?}");
+    container_count
 }
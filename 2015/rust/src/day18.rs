@@ -0,0 +1,772 @@
+use std::{
+    fmt::{self, Display},
+    mem,
+    str::FromStr,
+};
+
+use aoc_core::{Answer, Error, QueryOption, Solution};
+use aoc_util::{
+    grid::Grid,
+    image::{Image, FORMATS},
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn query(&self, input: &str, options: &[QueryOption]) -> Option<Result<Answer, Error>> {
+        Some(query(input, options).map(Answer::from))
+    }
+
+    fn render(
+        &self,
+        input: &str,
+        part: Option<u8>,
+        format: &str,
+    ) -> Option<Result<Vec<u8>, Error>> {
+        FORMATS
+            .contains(&format)
+            .then(|| encode(&last_generation(input, part)?, format))
+    }
+
+    fn frames(
+        &self,
+        input: &str,
+        part: Option<u8>,
+        format: &str,
+    ) -> Option<Result<Vec<Vec<u8>>, Error>> {
+        FORMATS.contains(&format).then(|| {
+            generations(input, part)?
+                .iter()
+                .map(|grid| encode(grid, format))
+                .collect()
+        })
+    }
+
+    fn variants(&self) -> &'static [&'static str] {
+        &[
+            "bit_board_100",
+            "bit_board_10000",
+            "bit_board_1000000",
+            "automaton_100",
+            "automaton_10000",
+        ]
+    }
+
+    fn variant(&self, name: &str, input: &str) -> Option<Result<Answer, Error>> {
+        let (bits, generations) = match name {
+            "bit_board_100" => (true, 100),
+            "bit_board_10000" => (true, 10_000),
+            "bit_board_1000000" => (true, 1_000_000),
+            "automaton_100" => (false, 100),
+            "automaton_10000" => (false, 10_000),
+            _ => return None,
+        };
+        Some(population_after(input, bits, generations).map(Answer::from))
+    }
+}
+
+const STEPS: usize = 100;
+
+/// Which numbers of neighbours that are on turn a cell on, and which keep it on, as bit masks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rule {
+    born: u16,
+    survives: u16,
+}
+
+/// The rule of the puzzle, `B3/S23`
+const CONWAY: Rule = Rule {
+    born: 1 << 3,
+    survives: 1 << 2 | 1 << 3,
+};
+
+impl Rule {
+    fn next(self, on: bool, neighbours: usize) -> bool {
+        let counts = if on { self.survives } else { self.born };
+        counts >> neighbours & 1 == 1
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    /// Parses a rule like `B36/S23`, with the born and survive parts in either order
+    fn from_str(rule: &str) -> Result<Self, Error> {
+        let (mut born, mut survives) = (None, None);
+        for part in rule.split('/') {
+            let mut chars = part.chars();
+            let counts = match chars.next().map(|chr| chr.to_ascii_uppercase()) {
+                Some('B') => &mut born,
+                Some('S') => &mut survives,
+                _ => return Err(format!("Invalid rule {rule:?}, expected one like B3/S23").into()),
+            };
+            if counts.is_some() {
+                return Err(format!("Rule {rule:?} has more than one {part:?} part").into());
+            }
+
+            let mut mask = 0;
+            for chr in chars {
+                let count = chr
+                    .to_digit(10)
+                    .filter(|&count| count <= 8)
+                    .ok_or_else(|| format!("Invalid number of neighbours {chr:?} in {rule:?}"))?;
+                mask |= 1 << count;
+            }
+            *counts = Some(mask);
+        }
+
+        match (born, survives) {
+            (Some(born), Some(survives)) => Ok(Rule { born, survives }),
+            _ => Err(format!("Rule {rule:?} needs both a B and an S part").into()),
+        }
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = |mask: u16| -> String {
+            (0..=8)
+                .filter(|count| mask >> count & 1 == 1)
+                .map(|count| char::from(b'0' + count as u8))
+                .collect()
+        };
+        write!(f, "B{}/S{}", counts(self.born), counts(self.survives))
+    }
+}
+
+/// What lies beyond the edges of the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Topology {
+    /// Cells that are always off
+    Bounded,
+    /// The other side of the board
+    Toroidal,
+    /// More board, which grows as far as the cells that are on reach
+    Unbounded,
+}
+
+impl FromStr for Topology {
+    type Err = Error;
+
+    fn from_str(topology: &str) -> Result<Self, Error> {
+        match topology {
+            "bounded" => Ok(Topology::Bounded),
+            "toroidal" => Ok(Topology::Toroidal),
+            "unbounded" => Ok(Topology::Unbounded),
+            _ => Err(format!(
+                "Invalid topology {topology:?}, expected bounded, toroidal or unbounded"
+            )
+            .into()),
+        }
+    }
+}
+
+/// Cells that step together under a rule
+trait Board {
+    /// Keeps a cell of the input on or off from now on
+    fn pin(&mut self, position: (isize, isize), on: bool) -> Result<(), Error>;
+
+    fn step(&mut self);
+
+    fn run(&mut self, steps: usize) {
+        for _step in 0..steps {
+            self.step();
+        }
+    }
+
+    /// The number of cells that are on
+    fn population(&self) -> usize;
+
+    fn to_grid(&self) -> Grid<bool>;
+}
+
+/// Cells an unbounded board grows by on every side, when a cell that is on reaches an edge
+const GROWTH: usize = 8;
+
+/// A cellular automaton of any topology, that steps from one buffer of cells into the other
+struct Automaton {
+    rule: Rule,
+    topology: Topology,
+    width: usize,
+    height: usize,
+    /// Where the first cell of the buffers is on the input, which only moves when the board grows
+    origin: (isize, isize),
+    cells: Vec<bool>,
+    next: Vec<bool>,
+    /// Cells that stay on or off whatever the rule says, by their position on the input
+    pinned: Vec<((isize, isize), bool)>,
+}
+
+impl Automaton {
+    fn new(grid: &Grid<bool>, rule: Rule, topology: Topology) -> Result<Self, Error> {
+        if topology == Topology::Unbounded && rule.born & 1 == 1 {
+            return Err(format!("Rule {rule} turns on the whole of an unbounded board").into());
+        }
+        Ok(Self {
+            rule,
+            topology,
+            width: grid.width(),
+            height: grid.height(),
+            origin: (0, 0),
+            cells: grid.cells().to_vec(),
+            next: vec![false; grid.width() * grid.height()],
+            pinned: Vec::new(),
+        })
+    }
+
+    /// The index of a position on the input in the buffers, if the board has grown that far
+    fn index(&self, (x, y): (isize, isize)) -> Option<usize> {
+        let x = usize::try_from(x - self.origin.0).ok()?;
+        let y = usize::try_from(y - self.origin.1).ok()?;
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    /// Adds room on every side when a cell that is on lies at an edge
+    fn grow(&mut self) {
+        // An empty board has no edge to grow from
+        if self.cells.is_empty() {
+            return;
+        }
+        let (width, height) = (self.width, self.height);
+        let at_edge = (0..width).any(|x| self.cells[x] || self.cells[(height - 1) * width + x])
+            || (0..height).any(|y| self.cells[y * width] || self.cells[y * width + width - 1]);
+        if !at_edge {
+            return;
+        }
+
+        let grown_width = width + 2 * GROWTH;
+        let mut cells = vec![false; grown_width * (height + 2 * GROWTH)];
+        for (y, row) in self.cells.chunks(width).enumerate() {
+            let start = (y + GROWTH) * grown_width + GROWTH;
+            cells[start..start + width].copy_from_slice(row);
+        }
+        self.next = vec![false; cells.len()];
+        self.cells = cells;
+        self.width = grown_width;
+        self.height += 2 * GROWTH;
+        self.origin = (
+            self.origin.0 - GROWTH as isize,
+            self.origin.1 - GROWTH as isize,
+        );
+    }
+
+    fn neighbours(&self, x: usize, y: usize) -> usize {
+        let (width, height) = (self.width as isize, self.height as isize);
+        let mut count = 0;
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if (dx, dy) == (0, 0) {
+                    continue;
+                }
+                let (mut nx, mut ny) = (x as isize + dx, y as isize + dy);
+                if self.topology == Topology::Toroidal {
+                    (nx, ny) = (nx.rem_euclid(width), ny.rem_euclid(height));
+                } else if !(0..width).contains(&nx) || !(0..height).contains(&ny) {
+                    continue;
+                }
+                count += self.cells[ny as usize * self.width + nx as usize] as usize;
+            }
+        }
+        count
+    }
+}
+
+impl Board for Automaton {
+    fn pin(&mut self, position: (isize, isize), on: bool) -> Result<(), Error> {
+        let index = self
+            .index(position)
+            .ok_or_else(|| format!("Cannot pin {position:?}, it is not on the board"))?;
+        self.cells[index] = on;
+        self.pinned.push((position, on));
+        Ok(())
+    }
+
+    fn step(&mut self) {
+        if self.topology == Topology::Unbounded {
+            self.grow();
+        }
+
+        let mut next = mem::take(&mut self.next);
+        for (index, cell) in next.iter_mut().enumerate() {
+            let (x, y) = (index % self.width, index / self.width);
+            *cell = self.rule.next(self.cells[index], self.neighbours(x, y));
+        }
+        self.next = mem::replace(&mut self.cells, next);
+
+        for &(position, on) in &self.pinned {
+            if let Some(index) = self.index(position) {
+                self.cells[index] = on;
+            }
+        }
+    }
+
+    fn population(&self) -> usize {
+        self.cells.iter().filter(|&&on| on).count()
+    }
+
+    /// The cells as far as the board has grown
+    fn to_grid(&self) -> Grid<bool> {
+        Grid::new(self.width, self.cells.clone())
+    }
+}
+
+/// Adds a bit to the bit planes of a counter for every bit that is set, with the lowest plane
+/// first. Four planes count up to the eight neighbours of a cell.
+fn add(planes: &mut [u64; 4], mut carry: u64) {
+    for plane in planes {
+        (*plane, carry) = (*plane ^ carry, *plane & carry);
+    }
+}
+
+/// The bits where the counter holds this count
+fn equal(planes: &[u64; 4], count: usize) -> u64 {
+    planes.iter().enumerate().fold(!0, |equal, (bit, &plane)| {
+        equal & if count >> bit & 1 == 1 { plane } else { !plane }
+    })
+}
+
+/// A bounded board with a bit per cell, in rows of words that each step 64 cells at once
+struct BitBoard {
+    rule: Rule,
+    width: usize,
+    height: usize,
+    /// Words in a row, the bits after the last column are always off
+    stride: usize,
+    rows: Vec<u64>,
+    next: Vec<u64>,
+    /// The cells that are pinned
+    pinned: Vec<u64>,
+    /// The pinned cells that are on
+    pinned_on: Vec<u64>,
+}
+
+impl BitBoard {
+    fn new(grid: &Grid<bool>, rule: Rule) -> Self {
+        let stride = grid.width().div_ceil(64);
+        let mut rows = vec![0; stride * grid.height()];
+        for (index, _) in grid.iter().enumerate().filter(|(_, &on)| on) {
+            let (x, y) = (index % grid.width(), index / grid.width());
+            rows[y * stride + x / 64] |= 1 << (x % 64);
+        }
+        Self {
+            rule,
+            width: grid.width(),
+            height: grid.height(),
+            stride,
+            next: vec![0; rows.len()],
+            pinned: vec![0; rows.len()],
+            pinned_on: vec![0; rows.len()],
+            rows,
+        }
+    }
+
+    /// The bits of a word that hold cells
+    fn columns(&self, word: usize) -> u64 {
+        match self.width - word * 64 {
+            columns if columns >= 64 => !0,
+            columns => (1 << columns) - 1,
+        }
+    }
+}
+
+impl Board for BitBoard {
+    fn pin(&mut self, (x, y): (isize, isize), on: bool) -> Result<(), Error> {
+        let (column, row) = match (usize::try_from(x), usize::try_from(y)) {
+            (Ok(x), Ok(y)) if x < self.width && y < self.height => (x, y),
+            _ => return Err(format!("Cannot pin {:?}, it is not on the board", (x, y)).into()),
+        };
+        let (index, bit) = (row * self.stride + column / 64, 1 << (column % 64));
+        self.pinned[index] |= bit;
+        self.pinned_on[index] = self.pinned_on[index] & !bit | if on { bit } else { 0 };
+        self.rows[index] = self.rows[index] & !bit | self.pinned_on[index] & bit;
+        Ok(())
+    }
+
+    fn step(&mut self) {
+        let counts = (0..=8)
+            .filter(|count| (self.rule.born | self.rule.survives) >> count & 1 == 1)
+            .collect::<Vec<_>>();
+        let stride = self.stride;
+
+        for y in 0..self.height {
+            for word in 0..stride {
+                let mut planes = [0; 4];
+                for row in y.saturating_sub(1)..(y + 2).min(self.height) {
+                    let cells = &self.rows[row * stride..(row + 1) * stride];
+                    let before = if word > 0 { cells[word - 1] } else { 0 };
+                    let after = cells.get(word + 1).copied().unwrap_or_default();
+                    // The neighbours on the left and right, moved onto the cells
+                    add(&mut planes, cells[word] << 1 | before >> 63);
+                    add(&mut planes, cells[word] >> 1 | after << 63);
+                    if row != y {
+                        add(&mut planes, cells[word]);
+                    }
+                }
+
+                let (mut born, mut survives) = (0, 0);
+                for &count in &counts {
+                    let equal = equal(&planes, count);
+                    if self.rule.born >> count & 1 == 1 {
+                        born |= equal;
+                    }
+                    if self.rule.survives >> count & 1 == 1 {
+                        survives |= equal;
+                    }
+                }
+
+                let index = y * stride + word;
+                let cells = self.rows[index];
+                let next = (cells & survives | !cells & born) & self.columns(word);
+                self.next[index] = next & !self.pinned[index] | self.pinned_on[index];
+            }
+        }
+        mem::swap(&mut self.rows, &mut self.next);
+    }
+
+    fn population(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    fn to_grid(&self) -> Grid<bool> {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self.rows[y * self.stride + x / 64] >> (x % 64) & 1 == 1
+        })
+    }
+}
+
+/// A board for the cells of the input, bounded boards are bit boards
+fn board(
+    grid: &Grid<bool>,
+    rule: Rule,
+    topology: Topology,
+    stuck_corners: bool,
+) -> Result<Box<dyn Board>, Error> {
+    let mut board: Box<dyn Board> = match topology {
+        Topology::Bounded => Box::new(BitBoard::new(grid, rule)),
+        topology => Box::new(Automaton::new(grid, rule, topology)?),
+    };
+    if stuck_corners {
+        let (right, bottom) = (grid.width() as isize - 1, grid.height() as isize - 1);
+        for corner in [(0, 0), (right, 0), (0, bottom), (right, bottom)] {
+            board.pin(corner, true)?;
+        }
+    }
+    Ok(board)
+}
+
+/// The board of the part, the corners are stuck on in part two
+fn part_board(data: &str, part: Option<u8>) -> Result<Box<dyn Board>, Error> {
+    board(
+        &parse_grid(data)?,
+        CONWAY,
+        Topology::Bounded,
+        part == Some(2),
+    )
+}
+
+fn part_one(data: &str) -> Result<usize, Error> {
+    let mut board = part_board(data, Some(1))?;
+    board.run(STEPS);
+    Ok(board.population())
+}
+
+fn part_two(data: &str) -> Result<usize, Error> {
+    let mut board = part_board(data, Some(2))?;
+    board.run(STEPS);
+    Ok(board.population())
+}
+
+/// The number of cells that are on after running the input for many generations of part one, on
+/// a bit board or cell by cell, to measure how fast the boards are
+fn population_after(data: &str, bits: bool, generations: usize) -> Result<usize, Error> {
+    let grid = parse_grid(data)?;
+    let mut board: Box<dyn Board> = if bits {
+        Box::new(BitBoard::new(&grid, CONWAY))
+    } else {
+        Box::new(Automaton::new(&grid, CONWAY, Topology::Bounded)?)
+    };
+    board.run(generations);
+    Ok(board.population())
+}
+
+/// The number of cells that are on after running the input with options like `--rule B36/S23`,
+/// `--topology toroidal`, `--steps 1000` and `--stuck-corners`
+fn query(data: &str, options: &[QueryOption]) -> Result<usize, Error> {
+    let (mut rule, mut topology, mut steps, mut stuck_corners) =
+        (CONWAY, Topology::Bounded, STEPS, false);
+    for option in options {
+        match (option.name.as_str(), &option.value) {
+            ("rule", Some(value)) => rule = value.parse()?,
+            ("topology", Some(value)) => topology = value.parse()?,
+            ("steps", Some(value)) => {
+                steps = value
+                    .parse()
+                    .map_err(|_| format!("--steps takes a number, not `{value}`"))?
+            }
+            ("stuck-corners", None) => stuck_corners = true,
+            ("rule" | "topology" | "steps", None) => {
+                return Err(format!("--{} needs a value", option.name).into())
+            }
+            ("stuck-corners", Some(_)) => {
+                return Err(format!("--{} takes no value", option.name).into())
+            }
+            _ => return Err(format!("Unknown option --{}", option.name).into()),
+        }
+    }
+
+    let mut board = board(&parse_grid(data)?, rule, topology, stuck_corners)?;
+    board.run(steps);
+    Ok(board.population())
+}
+
+/// The steps a part runs for, none without a part
+fn part_steps(part: Option<u8>) -> usize {
+    if part.is_some() {
+        STEPS
+    } else {
+        0
+    }
+}
+
+/// The generation at the end of the part, or the input without a part
+fn last_generation(data: &str, part: Option<u8>) -> Result<Grid<bool>, Error> {
+    let mut board = part_board(data, part)?;
+    board.run(part_steps(part));
+    Ok(board.to_grid())
+}
+
+/// Every generation from the input up to the end of the part, or just the input without a part
+fn generations(data: &str, part: Option<u8>) -> Result<Vec<Grid<bool>>, Error> {
+    let mut board = part_board(data, part)?;
+
+    let mut generations = vec![board.to_grid()];
+    for _step in 0..part_steps(part) {
+        board.step();
+        generations.push(board.to_grid());
+    }
+    Ok(generations)
+}
+
+/// The lights that are on in white, in one of the image formats
+fn encode(grid: &Grid<bool>, format: &str) -> Result<Vec<u8>, Error> {
+    Ok(
+        Image::from_bools(grid.width(), grid.height(), grid.iter().copied())
+            .encode(format)
+            .ok_or_else(|| format!("Could not encode the lights as {format}"))?,
+    )
+}
+
+fn parse_grid(data: &str) -> Result<Grid<bool>, Error> {
+    Ok(Grid::parse(data, |chr| match chr {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{QueryOption, Solution};
+    use aoc_util::grid::Grid;
+    use proptest::prelude::*;
+
+    use super::{
+        board, encode, generations, parse_grid, query, Automaton, BitBoard, Board, Day, Rule,
+        Topology, CONWAY,
+    };
+
+    const EXAMPLE: &str = ".#.#.#\n...##.\n#....#\n..#...\n#.#..#\n####..";
+
+    const GLIDER: &str = ".#...\n..#..\n###..\n.....\n.....";
+
+    fn option(name: &str, value: Option<&str>) -> QueryOption {
+        QueryOption {
+            name: name.to_string(),
+            value: value.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn parse_rules() {
+        assert_eq!("B3/S23".parse::<Rule>().unwrap(), CONWAY);
+        assert_eq!("s23/b3".parse::<Rule>().unwrap(), CONWAY);
+        let high_life = "B36/S23".parse::<Rule>().unwrap();
+        assert_eq!(high_life.to_string(), "B36/S23");
+        assert_eq!("B/S".parse::<Rule>().unwrap().to_string(), "B/S");
+        for rule in ["B3", "B3/S2/S3", "B9/S23", "X3/S23", "B3/S2a", ""] {
+            assert!(rule.parse::<Rule>().is_err(), "{rule}");
+        }
+    }
+
+    #[test]
+    fn example() {
+        let grid = parse_grid(EXAMPLE).unwrap();
+        let mut automaton = Automaton::new(&grid, CONWAY, Topology::Bounded).unwrap();
+        automaton.run(4);
+        assert_eq!(automaton.population(), 4);
+
+        let mut bits = board(&grid, CONWAY, Topology::Bounded, false).unwrap();
+        bits.run(4);
+        assert_eq!(bits.population(), 4);
+
+        let mut stuck = board(&grid, CONWAY, Topology::Bounded, true).unwrap();
+        stuck.run(5);
+        assert_eq!(stuck.population(), 17);
+    }
+
+    #[test]
+    fn glider_topologies() {
+        let glider = parse_grid(GLIDER).unwrap();
+
+        // It dies in the bottom right corner as a block
+        let mut bounded = Automaton::new(&glider, CONWAY, Topology::Bounded).unwrap();
+        bounded.run(20);
+        assert_eq!(bounded.population(), 4);
+
+        // It comes back where it started after moving one cell diagonally every four steps
+        let mut toroidal = Automaton::new(&glider, CONWAY, Topology::Toroidal).unwrap();
+        toroidal.run(20);
+        assert_eq!(toroidal.to_grid(), glider);
+
+        let mut unbounded = Automaton::new(&glider, CONWAY, Topology::Unbounded).unwrap();
+        unbounded.run(40);
+        assert_eq!(unbounded.population(), 5);
+        for (x, y) in [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)] {
+            let index = unbounded.index((x + 10, y + 10)).unwrap();
+            assert!(unbounded.cells[index]);
+        }
+        assert!(Automaton::new(&glider, "B0/S".parse().unwrap(), Topology::Unbounded).is_err());
+    }
+
+    #[test]
+    fn pinned_cells() {
+        let mut automaton =
+            Automaton::new(&parse_grid("...\n...").unwrap(), CONWAY, Topology::Bounded).unwrap();
+        automaton.pin((1, 1), true).unwrap();
+        assert!(automaton.pin((3, 0), true).is_err());
+        automaton.run(3);
+        assert_eq!(automaton.population(), 1);
+        assert!(automaton.cells[automaton.index((1, 1)).unwrap()]);
+    }
+
+    #[test]
+    fn empty_unbounded_board() {
+        let empty = Grid::new(3, Vec::new());
+        let mut automaton = Automaton::new(&empty, CONWAY, Topology::Unbounded).unwrap();
+        assert!(automaton.pin((0, 0), true).is_err());
+        automaton.run(3);
+        assert_eq!(automaton.population(), 0);
+    }
+
+    #[test]
+    fn query_options() {
+        assert_eq!(query(GLIDER, &[option("steps", Some("20"))]).unwrap(), 4);
+        let toroidal = [
+            option("topology", Some("toroidal")),
+            option("steps", Some("20")),
+        ];
+        assert_eq!(query(GLIDER, &toroidal).unwrap(), 5);
+        // Every cell that is on survives and turns on its neighbours
+        let rule = [
+            option("rule", Some("B1/S012345678")),
+            option("steps", Some("1")),
+        ];
+        assert_eq!(query(".....\n.....\n..#..", &rule).unwrap(), 6);
+        assert_eq!(
+            query(
+                EXAMPLE,
+                &[option("stuck-corners", None), option("steps", Some("5"))]
+            )
+            .unwrap(),
+            17
+        );
+        assert!(query(GLIDER, &[option("steps", None)]).is_err());
+        assert!(query(GLIDER, &[option("topology", Some("sphere"))]).is_err());
+        assert!(query(GLIDER, &[option("colour", Some("red"))]).is_err());
+    }
+
+    #[test]
+    fn generations_of_parts() {
+        assert_eq!(generations(EXAMPLE, None).unwrap().len(), 1);
+
+        let generations = generations(EXAMPLE, Some(2)).unwrap();
+        assert_eq!(generations.len(), 101);
+        let corners = [(0, 0), (5, 0), (0, 5), (5, 5)];
+        assert!(generations
+            .iter()
+            .all(|grid| corners.iter().all(|&corner| grid[corner])));
+    }
+
+    #[test]
+    fn board_variants() {
+        for generations in [100, 10_000] {
+            let bits = Day.variant(&format!("bit_board_{generations}"), EXAMPLE);
+            let automaton = Day.variant(&format!("automaton_{generations}"), EXAMPLE);
+            assert_eq!(bits.unwrap().unwrap(), automaton.unwrap().unwrap());
+        }
+        assert_eq!(
+            Day.variant("bit_board_100", EXAMPLE).unwrap().unwrap(),
+            Day.part_one(EXAMPLE).unwrap()
+        );
+        assert!(Day.variant("automaton_1000000", EXAMPLE).is_none());
+    }
+
+    #[test]
+    fn render_generations() {
+        let pbm = Day.render(EXAMPLE, None, "pbm").unwrap().unwrap();
+        // A set bit is a light that is off
+        assert_eq!(pbm, b"P4\n6 6\n\xa8\xe4\x78\xdc\x58\x0c".to_vec());
+
+        let frames = Day.frames(EXAMPLE, Some(1), "png").unwrap().unwrap();
+        assert_eq!(frames.len(), 101);
+        assert_eq!(
+            Day.render(EXAMPLE, Some(1), "png").unwrap().unwrap(),
+            frames[100]
+        );
+        assert!(Day.render(EXAMPLE, None, "svg").is_none());
+        assert!(encode(&parse_grid(EXAMPLE).unwrap(), "svg").is_err());
+        assert!(Day.render("#.\n#", None, "png").unwrap().is_err());
+    }
+
+    fn any_board() -> impl Strategy<Value = Grid<bool>> {
+        (1..140usize, 1..6usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(any::<bool>(), width * height)
+                .prop_map(move |cells| Grid::new(width, cells))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn bit_board_matches_automaton(
+            grid in any_board(),
+            born in 0..512u16,
+            survives in 0..512u16,
+            pinned in prop::collection::vec((0..140isize, 0..6isize, any::<bool>()), 0..4),
+            steps in 0..8usize,
+        ) {
+            let rule = Rule { born, survives };
+            let mut automaton = Automaton::new(&grid, rule, Topology::Bounded).unwrap();
+            let mut bits = BitBoard::new(&grid, rule);
+            for (x, y, on) in pinned {
+                prop_assert_eq!(automaton.pin((x, y), on).is_ok(), bits.pin((x, y), on).is_ok());
+            }
+            for _step in 0..steps {
+                automaton.step();
+                bits.step();
+                prop_assert_eq!(bits.to_grid(), automaton.to_grid());
+            }
+            prop_assert_eq!(bits.population(), automaton.population());
+        }
+    }
+}
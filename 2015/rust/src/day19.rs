@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use aoc_core::{Answer, Error, Explanation, Solution};
+use aoc_util::search::{dfs, Graph, Path};
+use itertools::{iproduct, Itertools};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn explain(&self, part: u8, input: &str) -> Option<Result<Explanation, Error>> {
+        (part == 2).then(|| explain(input))
+    }
+}
+
+fn part_one(data: &str) -> Result<usize, Error> {
+    let mut data = data.lines();
+
+    let mut replacements: HashMap<String, Vec<String>> = HashMap::new();
+
+    data.by_ref()
+        .take_while(|line| !line.is_empty())
+        .filter_map(|line| {
+            let (left, right) = line.split_once(" => ")?;
+            Some((left.to_owned(), right.to_owned()))
+        })
+        .for_each(|(from, to)| {
+            replacements
+                .entry(from)
+                .or_default()
+                .push(to);
+        });
+
+    let replacements: Vec<(String, Vec<String>)> = replacements
+        .into_iter()
+        .sorted_by_key(|(left, _)| left.len())
+        .collect();
+
+    let start_molecule = data.next().ok_or("Could not find start molecule")?;
+
+    let possible_replacements = find_possible_replacements(start_molecule, &replacements);
+
+    Ok(possible_replacements.iter().unique().count())
+}
+
+// I'm not happy with part two yet, part two takes the assumption that
+// if any path is found it is the shortest one.
+// Furthermore, it finds a result because the search order is defined as depth first,
+// shortest replacement key first, this causes us to quite quicky reach the right tree-branches,
+// however this is a mere property of this example, and not a general solution to this problem.
+// Searching in a different order will take an insane amount of time.
+
+fn part_two(data: &str) -> Result<usize, Error> {
+    let reduction = Reduction::parse(data)?;
+    Ok(reduction.fabricate().ok_or("Could not find result")?.cost)
+}
+
+/// The replacements that fabricate the molecule from a single electron, in the order they are made
+fn explain(data: &str) -> Result<Explanation, Error> {
+    let reduction = Reduction::parse(data)?;
+    let fabrication = reduction.fabricate().ok_or("Could not find result")?;
+
+    let mut explanation =
+        fabrication.explain(|molecule, reduced, _| reduction.describe(molecule, reduced));
+    // The search works its way back from the molecule to the electron
+    explanation.steps.reverse();
+    Ok(explanation)
+}
+
+/// Reduces the molecule by applying the replacements backwards, until it is a single electron
+struct Reduction {
+    replacements: Vec<(String, Vec<String>)>,
+    molecule: String,
+}
+
+impl Reduction {
+    fn parse(data: &str) -> Result<Self, Error> {
+        let mut data = data.lines();
+
+        let mut replacements: HashMap<String, Vec<String>> = HashMap::new();
+
+        data.by_ref()
+            .take_while(|line| !line.is_empty())
+            .filter_map(|line| {
+                // Inverse, because we're working backwards
+                // Working backwards has a trivial benefit that replacements become impossible after a while.
+                // Searching forward needs to be limited with something like a length check,
+                // otherwise you could keep replacing after the length of the molecule has exceeded,
+                // which are infinite and incorrect branches.
+                let (right, left) = line.split_once(" => ")?;
+                Some((left.to_owned(), right.to_owned()))
+            })
+            .for_each(|(from, to)| {
+                replacements
+                    .entry(from)
+                    .or_default()
+                    .push(to);
+            });
+
+        let replacements: Vec<(String, Vec<String>)> = replacements
+            .into_iter()
+            .sorted_by_key(|(left, _)| left.len())
+            // Must sort from small to large
+            // .rev()
+            .collect();
+
+        let molecule = data.next().ok_or("Could not find start molecule")?;
+
+        Ok(Self {
+            replacements,
+            molecule: molecule.to_owned(),
+        })
+    }
+
+    fn fabricate(&self) -> Option<Path<String, usize>> {
+        // Let's do a depth first search
+        dfs(self, [self.molecule.clone()], |molecule| molecule == "e")
+    }
+
+    /// The forward replacement that turns the reduced molecule back into the molecule
+    fn describe(&self, molecule: &str, reduced: &str) -> Option<String> {
+        let (index, from, to) = self
+            .replacements
+            .iter()
+            .flat_map(|(from, tos)| iproduct!(molecule.match_indices(from.as_str()), tos.iter()))
+            .map(|((index, from), to)| (index, from, to))
+            .find(|&(index, from, to)| {
+                reduced.get(..index) == molecule.get(..index)
+                    && reduced.get(index..index + to.len()) == Some(to.as_str())
+                    && reduced.get(index + to.len()..) == molecule.get(index + from.len()..)
+            })?;
+        Some(format!("{to} => {from} at {index}"))
+    }
+}
+
+impl Graph for Reduction {
+    type State = String;
+    type Cost = usize;
+
+    fn successors(&self, molecule: &String) -> impl Iterator<Item = (String, usize)> {
+        find_possible_replacements(molecule, &self.replacements)
+            .into_iter()
+            .map(|new_molecule| (new_molecule, 1))
+    }
+}
+
+fn find_possible_replacements(input: &str, replacements: &[(String, Vec<String>)]) -> Vec<String> {
+    replacements
+        .iter()
+        .flat_map(|(from, tos)| {
+            let replacement_indices = input.match_indices(from);
+            let combinations = iproduct!(replacement_indices, tos.iter());
+
+            combinations
+        })
+        .filter_map(|((index, from), to)| {
+            Some(String::from_iter(
+                [
+                    input.get(0..index)?,
+                    to.as_str(),
+                    input.get((index + from.len())..)?,
+                ],
+            ))
+        })
+        .collect::<Vec<_>>()
+}
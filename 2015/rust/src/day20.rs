@@ -1,10 +1,38 @@
-#![feature(test)]
-extern crate test;
+use aoc_core::{parsed, Answer, Error, Solution};
 
-fn main() {
-    let input = 36_000_000;
-    println!("Answer part one: {}", first_house_past_fast(input));
-    println!("Answer part two: {}", first_house_past_fast_max_presents(input));
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(first_house_past_fast(parse_presents(input)?).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(first_house_past_fast_max_presents(parse_presents(input)?).into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_presents(input).map(parsed))
+    }
+
+    fn variants(&self) -> &'static [&'static str] {
+        &["part_one_divisors", "part_two_divisors"]
+    }
+
+    /// The parts that add up the divisors of every house, instead of letting every elf deliver
+    fn variant(&self, name: &str, input: &str) -> Option<Result<Answer, Error>> {
+        let solve = match name {
+            "part_one_divisors" => first_house_past,
+            "part_two_divisors" => first_house_past_complex,
+            _ => return None,
+        };
+        Some(parse_presents(input).map(|presents| solve(presents).into()))
+    }
+}
+
+/// The input is just the number of presents we are looking for
+fn parse_presents(data: &str) -> Result<usize, Error> {
+    Ok(data.trim().parse::<usize>()?)
 }
 
 fn first_house_past(min_sum: usize) -> usize {
@@ -79,12 +107,11 @@ fn first_house_past_complex(min_sum: usize) -> usize {
 		    presents_left[divisor_candidate] -= 1;
 		}
                 let inversion = house_number / divisor_candidate;
-                if inversion != divisor_candidate {
-		    if presents_left[inversion] > 0 {
+                if inversion != divisor_candidate
+		    && presents_left[inversion] > 0 {
 			sum += inversion;
 			presents_left[inversion] -= 1;
 		    }
-                }
             }
         }
 
@@ -96,6 +123,7 @@ fn first_house_past_complex(min_sum: usize) -> usize {
     unreachable!();
 }
 
+#[allow(dead_code)]
 fn sum_of_divisors_fast(n: usize) -> usize {
     let mut sum = 0;
 
@@ -104,7 +132,7 @@ fn sum_of_divisors_fast(n: usize) -> usize {
             break;
         }
 
-        if n % divisor_candidate == 0 {
+        if n.is_multiple_of(divisor_candidate) {
             sum += divisor_candidate;
             let inversion = n / divisor_candidate;
             if inversion != divisor_candidate {
@@ -115,18 +143,3 @@ fn sum_of_divisors_fast(n: usize) -> usize {
 
     sum
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use test::Bencher;
-
-    #[bench]
-    fn bench_part_one(b: &mut Bencher) {
-        b.iter(|| first_house_past_fast(36_000_000))
-    }
-
-    #[bench]
-    fn bench_part_two(b: &mut Bencher) {
-        b.iter(|| first_house_past_fast_max_presents(36_000_000))
-    }
-}
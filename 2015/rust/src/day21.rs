@@ -0,0 +1,110 @@
+use aoc_core::{parsed, Answer, Error, Solution};
+use itertools::{chain, iproduct, Itertools};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_boss(input).map(parsed))
+    }
+}
+
+type Stats = (i32, i32, i32);
+
+const PLAYER_HEALTH: i32 = 100;
+
+/// All possible outfits as (cost, damage, armor), sorted by cost
+fn outfits() -> Vec<Stats> {
+
+    let weapons = [(8, 4, 0), (10, 5, 0), (25, 6, 0), (40, 7, 0), (74, 8, 0)];
+    let armor = [(13, 0, 1), (31, 0, 2), (53, 0, 3), (75, 0, 4), (102, 0, 5)];
+    let rings = [
+        (25, 1, 0),
+        (50, 2, 0),
+        (100, 3, 0),
+        (20, 0, 1),
+        (40, 0, 2),
+        (80, 0, 3),
+    ];
+
+    let weapon_choice = weapons.iter().combinations(1);
+
+    let armor_choice = chain!(
+        std::iter::once(vec![&(0, 0, 0)]),
+        armor.iter().combinations(1),
+    );
+
+    let ring_choice = chain!(
+        std::iter::once(vec![&(0, 0, 0)]),
+        rings.iter().combinations(1),
+        rings.iter().combinations(2),
+    );
+
+    let mut outfit = iproduct!(weapon_choice, armor_choice, ring_choice)
+        .map(|(weapon, armor, ring)| {
+            chain!(weapon, armor, ring).fold((0, 0, 0), |acc, cur| {
+                (acc.0 + cur.0, acc.1 + cur.1, acc.2 + cur.2)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // Sort by cost
+    outfit.sort_by_key(|x| x.0);
+
+    outfit
+}
+
+/// The boss is described as its hit points, damage and armor, one per line
+fn parse_boss(data: &str) -> Result<Stats, Error> {
+    let mut values = data.lines().map(|line| -> Result<i32, Error> {
+        let (_, value) = line.split_once(": ").ok_or("Expected a `<stat>: <value>` line")?;
+        Ok(value.trim().parse::<i32>()?)
+    });
+    let mut next = || values.next().ok_or("Missing boss stat")?;
+
+    Ok((next()?, next()?, next()?))
+}
+
+fn player_wins(boss: Stats, player_health: i32, (_, damage, armor): Stats) -> bool {
+    let boss_damage = i32::max(boss.1 - armor, 1);
+    let player_damage = i32::max(damage - boss.2, 1);
+
+    let boss_turns = player_health / boss_damage
+        + if (player_health % boss_damage) == 0 {
+            0
+        } else {
+            1
+        };
+    let player_turns = boss.0 / player_damage + if (boss.0 % player_damage) == 0 { 0 } else { 1 };
+
+    player_turns <= boss_turns
+}
+
+fn part_one(data: &str) -> Result<i32, Error> {
+    let boss = parse_boss(data)?;
+    let least_cost_winning_outfit = outfits()
+        .into_iter()
+        .find(|&outfit| player_wins(boss, PLAYER_HEALTH, outfit))
+        .ok_or("Could not find a winning outfit")?;
+
+    Ok(least_cost_winning_outfit.0)
+}
+
+fn part_two(data: &str) -> Result<i32, Error> {
+    let boss = parse_boss(data)?;
+    let highest_cost_losing_outfit = outfits()
+        .into_iter()
+        .rev()
+        .find(|&outfit| !player_wins(boss, PLAYER_HEALTH, outfit))
+        .ok_or("Could not find a losing outfit")?;
+
+    Ok(highest_cost_losing_outfit.0)
+}
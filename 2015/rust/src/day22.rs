@@ -1,6 +1,7 @@
-use std::{cmp::Reverse, collections::BinaryHeap, fmt::Display, rc::Rc};
+use aoc_core::{parsed, Answer, Error, Explanation, Solution};
+use aoc_util::search::{dijkstra, Graph, Path};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 struct Effect {
     r#type: EffectType,
     timer: usize,
@@ -10,7 +11,7 @@ struct Effect {
     mana_boost: usize,
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 enum EffectType {
     Shield,
     Poison,
@@ -97,13 +98,36 @@ enum Cast<'a> {
     Effect(&'a Effect),
 }
 
-#[derive(Clone, Debug)]
+impl Cast<'_> {
+    fn cost(&self) -> usize {
+        match self {
+            Cast::Spell(spell) => spell.cost,
+            Cast::Effect(effect) => effect.cost,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Cast::Spell(spell) => match spell.r#type {
+                SpellType::MagicMissile => "Magic Missile",
+                SpellType::Drain => "Drain",
+            },
+            Cast::Effect(effect) => match effect.r#type {
+                EffectType::Shield => "Shield",
+                EffectType::Poison => "Poison",
+                EffectType::Recharge => "Recharge",
+            },
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 enum Player {
     HumanPlayer(HumanPlayer),
     Boss(Boss),
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 struct HumanPlayer {
     health: usize,
     mana: usize,
@@ -120,7 +144,7 @@ impl HumanPlayer {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 struct Boss {
     health: usize,
     damage: usize,
@@ -130,51 +154,63 @@ impl Boss {
     fn new(health: usize, damage: usize) -> Self {
         Self { health, damage }
     }
+
+    /// The boss is described as its hit points and damage, one per line
+    fn parse(data: &str) -> Result<Self, Error> {
+        let mut values = data.lines().map(|line| -> Result<usize, Error> {
+            let (_, value) = line.split_once(": ").ok_or("Expected a `<stat>: <value>` line")?;
+            Ok(value.trim().parse::<usize>()?)
+        });
+        let mut next = || values.next().ok_or("Missing boss stat")?;
+
+        Ok(Self::new(next()?, next()?))
+    }
 }
 
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 struct Game {
-    available_spells: Rc<Vec<Spell>>,
-    available_effects: Rc<Vec<Effect>>,
-
     players: [Player; 2],
     turn: usize,
 }
 
 impl Game {
     fn new(player1: Player, player2: Player) -> Self {
-        let spells = vec![
-            Spell::new(SpellType::MagicMissile),
-            Spell::new(SpellType::Drain),
-        ];
-
-        let effects = vec![
-            Effect::new(EffectType::Shield),
-            Effect::new(EffectType::Poison),
-            Effect::new(EffectType::Recharge),
-        ];
-
         Self {
-            available_spells: Rc::new(spells),
-            available_effects: Rc::new(effects),
             turn: 0,
             players: [player1, player2],
         }
     }
 
-    /// Makes a copy of the game, but creates a new reference to the RC, since they are "Frozen".
-    fn clone(&self) -> Self {
+    /// Places the attacking and defending player back in the same order
+    fn next_turn(&self, att_player: Player, def_player: Player) -> Self {
+        let players = if self.turn.is_multiple_of(2) {
+            [att_player, def_player]
+        } else {
+            [def_player, att_player]
+        };
+
         Self {
-            available_spells: Rc::clone(&self.available_spells),
-            available_effects: Rc::clone(&self.available_effects),
-            turn: self.turn,
-            players: self.players.clone(),
+            players,
+            turn: self.turn + 1,
         }
     }
 
-    fn get_available_casts(&self) -> impl Iterator<Item = Cast> + '_ {
-        let effects = self.available_effects.iter().map(|x| Cast::Effect(x));
-        let spells = self.available_spells.iter().map(|x| Cast::Spell(x));
-        effects.chain(spells)
+    /// The game right after the boss has been defeated
+    fn won(&self) -> Self {
+        let mut game = self.clone();
+        for player in &mut game.players {
+            if let Player::Boss(boss) = player {
+                boss.health = 0;
+            }
+        }
+        game.turn += 1;
+        game
+    }
+
+    fn is_won(&self) -> bool {
+        self.players
+            .iter()
+            .any(|player| matches!(player, Player::Boss(boss) if boss.health == 0))
     }
 }
 
@@ -184,119 +220,47 @@ enum GameType {
     Hard,
 }
 
-impl Display for GameState {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let spent_mana = self.spent_mana;
-        let turn = self.game.turn;
-        let p1 = &self.game.players[0];
-        let p2 = &self.game.players[1];
-
-        write!(
-            f,
-            "Spent mana: {spent_mana}; Turn: {turn}\nPlayer 1: {p1:#?}\nPlayer 2: {p2:#?}\n"
-        )
-    }
+/// The spells and effects that can be cast, every turn of a game is a step that costs the mana
+/// spent during it.
+struct Rules {
+    available_spells: Vec<Spell>,
+    available_effects: Vec<Effect>,
+    game_type: GameType,
 }
 
-struct GameState {
-    game: Game,
-    spent_mana: usize,
-}
+impl Rules {
+    fn new(game_type: GameType) -> Self {
+        let spells = vec![
+            Spell::new(SpellType::MagicMissile),
+            Spell::new(SpellType::Drain),
+        ];
 
-impl Eq for GameState {}
-impl PartialEq for GameState {
-    fn eq(&self, other: &Self) -> bool {
-        self.spent_mana == other.spent_mana
-    }
-}
+        let effects = vec![
+            Effect::new(EffectType::Shield),
+            Effect::new(EffectType::Poison),
+            Effect::new(EffectType::Recharge),
+        ];
 
-impl PartialOrd for GameState {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.spent_mana.cmp(&other.spent_mana))
+        Self {
+            available_spells: spells,
+            available_effects: effects,
+            game_type,
+        }
     }
-}
 
-impl Ord for GameState {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.spent_mana.cmp(&other.spent_mana)
+    fn get_available_casts(&self) -> impl Iterator<Item = Cast<'_>> + '_ {
+        let effects = self.available_effects.iter().map(Cast::Effect);
+        let spells = self.available_spells.iter().map(Cast::Spell);
+        effects.chain(spells)
     }
-}
-
-fn main() {
-    let player = Player::HumanPlayer(HumanPlayer::new(50, 500));
-    let boss = Player::Boss(Boss::new(71, 10));
-
-    let initial_game = Game::new(player, boss);
-    let cost = play_game(initial_game, GameType::Normal);
-    println!("{cost}");
-
-    let player = Player::HumanPlayer(HumanPlayer::new(50, 500));
-    let boss = Player::Boss(Boss::new(71, 10));
-    let initial_game = Game::new(player, boss);
-    let cost = play_game(initial_game, GameType::Hard);
-    println!("{cost}");
-}
 
-fn play_game(initial_game: Game, game_type: GameType) -> usize {
-    let mut heap = BinaryHeap::new();
-
-    heap.push(Reverse(GameState {
-        game: initial_game,
-        spent_mana: 0,
-    }));
-
-    while let Some(current_state) = heap.pop() {
-        let current_state = current_state.0;
-        let turn = current_state.game.turn;
-        let attacking_player = &current_state.game.players[turn % 2];
-        let defending_player = &current_state.game.players[(turn + 1) % 2];
-
-        let (att_damage, att_armor, mut new_att_player) = match attacking_player {
-            Player::HumanPlayer(human) => {
-                let mut new_effects = vec![];
-                let mut total_armor = 0;
-                let mut total_damage = 0;
-                let mut mana_gain = 0;
-                for effect in &human.active_effects {
-                    if let Some(new_effect) = effect.next() {
-                        new_effects.push(new_effect);
-                    }
-
-                    total_damage += effect.damage;
-                    total_armor += effect.armor;
-                    mana_gain += effect.mana_boost;
-                }
-
-                let mut new_human = human.clone();
-                new_human.mana += mana_gain;
-                new_human.active_effects = new_effects;
-                (total_damage, total_armor, Player::HumanPlayer(new_human))
-            }
-            Player::Boss(boss) => (0, 0, Player::Boss(*boss)),
-        };
+    /// Every way the turn can be played, with the mana that was spent on it
+    fn play_turn(&self, game: &Game) -> Vec<(Game, usize)> {
+        let attacking_player = &game.players[game.turn % 2];
+        let defending_player = &game.players[(game.turn + 1) % 2];
 
-        let (def_damage, def_armor, mut new_def_player) = match defending_player {
-            Player::HumanPlayer(human) => {
-                let mut new_effects = vec![];
-                let mut total_armor = 0;
-                let mut total_damage = 0;
-                let mut mana_gain = 0;
-                for effect in &human.active_effects {
-                    if let Some(new_effect) = effect.next() {
-                        new_effects.push(new_effect);
-                    }
-
-                    total_damage += effect.damage;
-                    total_armor += effect.armor;
-                    mana_gain += effect.mana_boost;
-                }
-                let mut new_human = human.clone();
-                new_human.mana += mana_gain;
-                new_human.active_effects = new_effects;
-                (total_damage, total_armor, Player::HumanPlayer(new_human))
-            }
-            Player::Boss(boss) => (0, 0, Player::Boss(*boss)),
-        };
+        let (att_damage, att_armor, mut new_att_player) = apply_effects(attacking_player);
+        let (def_damage, def_armor, mut new_def_player) = apply_effects(defending_player);
 
         // Can be fixed by wrapping it in another struct with a common health attr.
         // This is about passive damage from effects. Spells will be done seperately, but is behind a branch
@@ -305,14 +269,14 @@ fn play_game(initial_game: Game, game_type: GameType) -> usize {
                 Player::HumanPlayer(human) => {
                     let rel_damage = usize::max(att_damage, 1 + def_armor) - def_armor;
                     if rel_damage >= human.health {
-                        continue;
+                        return vec![];
                     }
                     human.health -= rel_damage;
                 }
                 Player::Boss(boss) => {
                     let rel_damage = usize::max(att_damage, 1 + def_armor) - def_armor;
                     if rel_damage >= boss.health {
-                        return current_state.spent_mana;
+                        return vec![(game.won(), 0)];
                     }
                     boss.health -= rel_damage;
                 }
@@ -325,24 +289,24 @@ fn play_game(initial_game: Game, game_type: GameType) -> usize {
                 Player::HumanPlayer(human) => {
                     let rel_damage = usize::max(def_damage, 1 + att_armor) - att_armor;
                     if rel_damage >= human.health {
-                        continue;
+                        return vec![];
                     }
                     human.health -= rel_damage;
                 }
                 Player::Boss(boss) => {
                     let rel_damage = usize::max(def_damage, 1 + att_armor) - att_armor;
                     if rel_damage >= boss.health {
-                        return current_state.spent_mana;
+                        return vec![(game.won(), 0)];
                     }
                     boss.health -= rel_damage;
                 }
             };
         }
 
-        if game_type == GameType::Hard {
+        if self.game_type == GameType::Hard {
             if let Player::HumanPlayer(human) = &mut new_att_player {
                 if human.health <= 1 {
-                    continue;
+                    return vec![];
                 }
                 human.health -= 1;
             }
@@ -350,36 +314,37 @@ fn play_game(initial_game: Game, game_type: GameType) -> usize {
 
         match new_att_player {
             Player::HumanPlayer(new_att_human) => {
-                for cast in current_state.game.get_available_casts() {
-                    let mut spent_mana = current_state.spent_mana;
+                let mut next_games = vec![];
+                for cast in self.get_available_casts() {
                     let mut new_att_human = new_att_human.clone();
-                    let new_def_player = match cast {
+                    let (new_def_player, spent_mana) = match cast {
                         Cast::Spell(spell) => {
                             if new_att_human.mana < spell.cost {
                                 continue;
                             }
                             new_att_human.mana -= spell.cost;
-                            spent_mana += spell.cost;
                             new_att_human.health += spell.health;
-                            match &new_def_player {
+                            let new_def_player = match &new_def_player {
                                 Player::HumanPlayer(def_human) => {
                                     let mut new_def_human = def_human.clone();
                                     let rel_damage = spell.damage - def_armor;
                                     if rel_damage >= new_def_human.health {
                                         continue;
                                     }
-                                    new_def_human.health = new_def_human.health - rel_damage;
+                                    new_def_human.health -= rel_damage;
                                     Player::HumanPlayer(new_def_human)
                                 }
                                 Player::Boss(boss) => {
-                                    let mut new_boss = boss.clone();
+                                    let mut new_boss = *boss;
                                     if spell.damage >= boss.health {
-                                        return spent_mana;
+                                        next_games.push((game.won(), spell.cost));
+                                        continue;
                                     }
                                     new_boss.health -= spell.damage;
                                     Player::Boss(new_boss)
                                 }
-                            }
+                            };
+                            (new_def_player, spell.cost)
                         }
                         Cast::Effect(effect) => {
                             if new_att_human
@@ -393,34 +358,16 @@ fn play_game(initial_game: Game, game_type: GameType) -> usize {
                                 continue;
                             }
                             new_att_human.mana -= effect.cost;
-                            spent_mana += effect.cost;
 
                             new_att_human.active_effects.push(effect.clone());
-                            // Might be better with Rc
-                            new_def_player.clone()
+                            (new_def_player.clone(), effect.cost)
                         }
                     };
 
                     let new_att_player = Player::HumanPlayer(new_att_human);
-
-                    let players = if current_state.game.turn % 2 == 0 {
-                        [new_att_player, new_def_player]
-                    } else {
-                        [new_def_player, new_att_player]
-                    };
-
-                    let new_game = Game {
-                        available_spells: Rc::clone(&current_state.game.available_spells),
-                        available_effects: Rc::clone(&current_state.game.available_effects),
-                        players,
-                        turn: current_state.game.turn + 1,
-                    };
-                    let new_game_state = GameState {
-                        game: new_game,
-                        spent_mana,
-                    };
-                    heap.push(Reverse(new_game_state));
+                    next_games.push((game.next_turn(new_att_player, new_def_player), spent_mana));
                 }
+                next_games
             }
             // Boss already did his damage
             Player::Boss(boss) => {
@@ -429,38 +376,121 @@ fn play_game(initial_game: Game, game_type: GameType) -> usize {
                 match &mut new_def_player {
                     Player::HumanPlayer(h) => {
                         if h.health < rel_damage {
-                            continue;
+                            return vec![];
                         }
                         h.health -= rel_damage
                     }
                     Player::Boss(b) => {
                         if b.health < rel_damage {
-                            return current_state.spent_mana;
+                            return vec![(game.won(), 0)];
                         }
                         b.health -= rel_damage;
                     }
                 }
 
-                // We need to place the players back in the same order, maybe this is not the best idea.
-                let players = if current_state.game.turn % 2 == 0 {
-                    [new_att_player, new_def_player]
-                } else {
-                    [new_def_player, new_att_player]
-                };
-                let new_game = Game {
-                    available_spells: Rc::clone(&current_state.game.available_spells),
-                    available_effects: Rc::clone(&current_state.game.available_effects),
-                    players,
-                    turn: current_state.game.turn + 1,
-                };
-                let new_game_state = GameState {
-                    game: new_game,
-                    spent_mana: current_state.spent_mana,
-                };
-                heap.push(Reverse(new_game_state));
+                vec![(game.next_turn(new_att_player, new_def_player), 0)]
             }
-        };
+        }
+    }
+}
+
+impl Graph for Rules {
+    type State = Game;
+    type Cost = usize;
+
+    fn successors(&self, game: &Game) -> impl Iterator<Item = (Game, usize)> {
+        self.play_turn(game).into_iter()
     }
+}
+
+/// Ticks the active effects of a player, returns the damage and armor they give with the updated
+/// player
+fn apply_effects(player: &Player) -> (usize, usize, Player) {
+    match player {
+        Player::HumanPlayer(human) => {
+            let mut new_effects = vec![];
+            let mut total_armor = 0;
+            let mut total_damage = 0;
+            let mut mana_gain = 0;
+            for effect in &human.active_effects {
+                if let Some(new_effect) = effect.next() {
+                    new_effects.push(new_effect);
+                }
+
+                total_damage += effect.damage;
+                total_armor += effect.armor;
+                mana_gain += effect.mana_boost;
+            }
+
+            let mut new_human = human.clone();
+            new_human.mana += mana_gain;
+            new_human.active_effects = new_effects;
+            (total_damage, total_armor, Player::HumanPlayer(new_human))
+        }
+        Player::Boss(boss) => (0, 0, Player::Boss(*boss)),
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(Boss::parse(input).map(parsed))
+    }
+
+    fn explain(&self, part: u8, input: &str) -> Option<Result<Explanation, Error>> {
+        Some(explain(part, input))
+    }
+}
+
+fn explain(part: u8, input: &str) -> Result<Explanation, Error> {
+    let player = Player::HumanPlayer(HumanPlayer::new(50, 500));
+    let boss = Player::Boss(Boss::parse(input)?);
+    let rules = Rules::new(match part {
+        1 => GameType::Normal,
+        _ => GameType::Hard,
+    });
+    let path = cheapest_win(&rules, Game::new(player, boss)).ok_or("No gamestates lead anywhere")?;
+
+    // Every cast costs a different amount of mana, the boss turns are free
+    Ok(path.explain(|_, _, spent_mana| {
+        let cast = rules
+            .get_available_casts()
+            .find(|cast| cast.cost() == spent_mana)?;
+        Some(format!("cast {} for {spent_mana} mana", cast.name()))
+    }))
+}
+
+fn part_one(data: &str) -> Result<usize, Error> {
+    let player = Player::HumanPlayer(HumanPlayer::new(50, 500));
+    let boss = Player::Boss(Boss::parse(data)?);
+
+    let initial_game = Game::new(player, boss);
+    play_game(initial_game, GameType::Normal)
+}
+
+fn part_two(data: &str) -> Result<usize, Error> {
+    let player = Player::HumanPlayer(HumanPlayer::new(50, 500));
+    let boss = Player::Boss(Boss::parse(data)?);
+    let initial_game = Game::new(player, boss);
+    play_game(initial_game, GameType::Hard)
+}
+
+/// The least amount of mana that can be spent to win the game
+fn play_game(initial_game: Game, game_type: GameType) -> Result<usize, Error> {
+    let path = cheapest_win(&Rules::new(game_type), initial_game)
+        .ok_or("No gamestates lead anywhere")?;
+    Ok(path.cost)
+}
 
-    panic!("No gamestates lead anywhere")
+fn cheapest_win(rules: &Rules, initial_game: Game) -> Option<Path<Game, usize>> {
+    dijkstra(rules, [initial_game], Game::is_won)
 }
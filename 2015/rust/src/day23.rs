@@ -0,0 +1,1467 @@
+use std::{
+    collections::{hash_map::Entry, BTreeMap, BTreeSet, HashMap, VecDeque},
+    fmt::{Display, Write as _},
+    io::{BufRead, Write},
+};
+
+use aoc_core::{parsed, Answer, Error, Solution};
+use aoc_util::words::{parse_lines, ParseError, Words};
+
+type Register = char;
+type Offset = isize;
+/// The cursor with the registers sorted by name
+type State = (isize, Vec<(Register, usize)>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Instruction {
+    Half(Register),
+    Triple(Register),
+    Increment(Register),
+    Jump(Offset),
+    JumpIfEven(Register, Offset),
+    JumpIfOne(Register, Offset),
+}
+
+/// Instructions are like `jie a, +4`, one per line
+fn parse_program(data: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(data, parse_instruction)
+}
+
+fn parse_instruction(line_number: usize, line: &str) -> Result<Instruction, ParseError> {
+    let mut words = Words::new(line_number, line).with_separators(&[',']);
+
+    let mnemonic = words.peek();
+    let instruction = match mnemonic {
+        Some("hlf" | "tpl" | "inc") => {
+            words.advance();
+            let register = register(&mut words)?;
+            match mnemonic {
+                Some("hlf") => Instruction::Half(register),
+                Some("tpl") => Instruction::Triple(register),
+                _ => Instruction::Increment(register),
+            }
+        }
+        Some("jmp") => {
+            words.advance();
+            Instruction::Jump(offset(&mut words)?)
+        }
+        Some("jie" | "jio") => {
+            words.advance();
+            let register = register(&mut words)?;
+            words.expect(",", "`,`")?;
+            let offset = offset(&mut words)?;
+            match mnemonic {
+                Some("jie") => Instruction::JumpIfEven(register, offset),
+                _ => Instruction::JumpIfOne(register, offset),
+            }
+        }
+        _ => return Err(words.unexpected("an instruction")),
+    };
+
+    if words.peek().is_some() {
+        return Err(words.unexpected("the end of the line"));
+    }
+    Ok(instruction)
+}
+
+fn register(words: &mut Words) -> Result<Register, ParseError> {
+    let mut chars = words.peek().unwrap_or_default().chars();
+    match (chars.next(), chars.next()) {
+        (Some(register), None) if register.is_ascii_lowercase() => {
+            words.advance();
+            Ok(register)
+        }
+        _ => Err(words.unexpected("a register")),
+    }
+}
+
+fn offset(words: &mut Words) -> Result<Offset, ParseError> {
+    match words.peek().map(str::parse::<Offset>) {
+        Some(Ok(offset)) => {
+            words.advance();
+            Ok(offset)
+        }
+        _ => Err(words.unexpected("an offset like `+4`")),
+    }
+}
+
+/// Writes the program back in the format it is parsed from
+fn disassemble(instructions: &[Instruction]) -> String {
+    instructions
+        .iter()
+        .map(|instruction| format!("{instruction}\n"))
+        .collect()
+}
+
+/// Where the program goes after a block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    /// The block with this index
+    Block(usize),
+    /// Outside of the program, so it halts
+    Halt,
+}
+
+/// A run of instructions that is only entered at its first one and only left after its last one
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Block {
+    start: usize,
+    end: usize,
+    /// Where the jump at the end leads to first, then where the block falls through to
+    successors: Vec<Target>,
+}
+
+/// The program split into blocks, with labels for the positions that are jumped to
+struct ControlFlow {
+    blocks: Vec<Block>,
+    labels: BTreeMap<usize, String>,
+}
+
+impl ControlFlow {
+    fn new(instructions: &[Instruction]) -> Self {
+        let len = instructions.len();
+        let jump_target = |position: usize, offset: Offset| {
+            position
+                .checked_add_signed(offset)
+                .filter(|&target| target < len)
+        };
+
+        // Blocks start at the start of the program, at jump targets and after jumps
+        let mut targets = BTreeSet::new();
+        let mut starts = BTreeSet::from([0]);
+        for (position, instruction) in instructions.iter().enumerate() {
+            if let Some(offset) = instruction.offset() {
+                targets.extend(jump_target(position, offset));
+                starts.extend(jump_target(position, offset));
+                starts.insert(position + 1);
+            }
+        }
+        let starts = starts
+            .into_iter()
+            .filter(|&start| start < len)
+            .collect::<Vec<_>>();
+
+        let block_at = |position: Option<usize>| match position {
+            Some(position) => Target::Block(
+                starts
+                    .binary_search(&position)
+                    .expect("Position does not start a block"),
+            ),
+            None => Target::Halt,
+        };
+        let blocks = starts
+            .iter()
+            .enumerate()
+            .map(|(index, &start)| {
+                let end = starts.get(index + 1).copied().unwrap_or(len);
+                let last = end - 1;
+                let next = (end < len).then_some(end);
+                let successors = match instructions[last] {
+                    Instruction::Jump(offset) => vec![block_at(jump_target(last, offset))],
+                    Instruction::JumpIfEven(_, offset) | Instruction::JumpIfOne(_, offset) => {
+                        vec![block_at(jump_target(last, offset)), block_at(next)]
+                    }
+                    _ => vec![block_at(next)],
+                };
+                Block {
+                    start,
+                    end,
+                    successors,
+                }
+            })
+            .collect();
+
+        let labels = targets
+            .into_iter()
+            .enumerate()
+            .map(|(index, position)| (position, format!("L{}", index + 1)))
+            .collect();
+
+        Self { blocks, labels }
+    }
+
+    /// Whether a later block jumps back to the block, which makes it the start of a loop
+    fn is_loop(&self, index: usize) -> bool {
+        self.blocks[index..]
+            .iter()
+            .any(|block| block.successors.contains(&Target::Block(index)))
+    }
+
+    fn name(&self, index: usize) -> String {
+        let start = self.blocks[index].start;
+        match self.labels.get(&start) {
+            Some(label) => label.clone(),
+            None => format!("@{start}"),
+        }
+    }
+
+    /// The instruction with the label it jumps to instead of its offset
+    fn labelled(&self, position: usize, instruction: Instruction) -> String {
+        let label = |offset: Offset| {
+            position
+                .checked_add_signed(offset)
+                .and_then(|target| self.labels.get(&target))
+                .map_or("halt", String::as_str)
+        };
+        match instruction {
+            Instruction::Jump(offset) => format!("jmp {}", label(offset)),
+            Instruction::JumpIfEven(register, offset) => {
+                format!("jie {register}, {}", label(offset))
+            }
+            Instruction::JumpIfOne(register, offset) => {
+                format!("jio {register}, {}", label(offset))
+            }
+            instruction => instruction.to_string(),
+        }
+    }
+
+    /// The program with labels instead of offsets, marking where loops start
+    fn listing(&self, instructions: &[Instruction]) -> String {
+        let mut listing = String::new();
+        for (index, block) in self.blocks.iter().enumerate() {
+            if let Some(label) = self.labels.get(&block.start) {
+                let comment = if self.is_loop(index) { "  ; loop" } else { "" };
+                writeln!(listing, "{label}:{comment}").expect("Writing to a string failed");
+            }
+            for (position, &instruction) in
+                (block.start..).zip(&instructions[block.start..block.end])
+            {
+                writeln!(listing, "    {}", self.labelled(position, instruction))
+                    .expect("Writing to a string failed");
+            }
+        }
+        listing
+    }
+
+    /// The blocks as a graph, jumps back to the start of a loop are dashed
+    fn to_dot(&self, instructions: &[Instruction]) -> String {
+        let mut dot =
+            String::from("digraph program {\n    node [shape=box, fontname=monospace];\n");
+        writeln!(dot, "    \"halt\" [shape=doublecircle];").expect("Writing to a string failed");
+
+        for (index, block) in self.blocks.iter().enumerate() {
+            let mut label = format!("{}:\\l", self.name(index));
+            for (position, &instruction) in
+                (block.start..).zip(&instructions[block.start..block.end])
+            {
+                write!(label, "    {}\\l", self.labelled(position, instruction))
+                    .expect("Writing to a string failed");
+            }
+            writeln!(dot, "    \"block_{index}\" [label=\"{label}\"];")
+                .expect("Writing to a string failed");
+
+            let condition = match instructions[block.end - 1] {
+                Instruction::JumpIfEven(register, _) => Some(format!("{register} is even")),
+                Instruction::JumpIfOne(register, _) => Some(format!("{register} is one")),
+                _ => None,
+            };
+            for (edge, successor) in block.successors.iter().enumerate() {
+                let mut attributes = Vec::new();
+                match (&condition, edge) {
+                    (Some(condition), 0) => attributes.push(format!("label=\"{condition}\"")),
+                    (Some(_), _) => attributes.push(String::from("label=\"else\"")),
+                    (None, _) => {}
+                }
+                let target = match successor {
+                    Target::Block(target) => {
+                        if *target <= index {
+                            attributes.push(String::from("style=dashed"));
+                        }
+                        format!("block_{target}")
+                    }
+                    Target::Halt => String::from("halt"),
+                };
+                let attributes = match attributes.is_empty() {
+                    true => String::new(),
+                    false => format!(" [{}]", attributes.join(", ")),
+                };
+                writeln!(dot, "    \"block_{index}\" -> \"{target}\"{attributes};")
+                    .expect("Writing to a string failed");
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Half(register) => write!(f, "hlf {register}"),
+            Instruction::Triple(register) => write!(f, "tpl {register}"),
+            Instruction::Increment(register) => write!(f, "inc {register}"),
+            Instruction::Jump(offset) => write!(f, "jmp {offset:+}"),
+            Instruction::JumpIfEven(register, offset) => write!(f, "jie {register}, {offset:+}"),
+            Instruction::JumpIfOne(register, offset) => write!(f, "jio {register}, {offset:+}"),
+        }
+    }
+}
+
+/// Why the runtime stopped running
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Stop {
+    /// The cursor left the program
+    Halted,
+    /// The breakpoint with this index was hit
+    Breakpoint(usize),
+    /// The limit of instructions was reached
+    Limit,
+    /// The runtime is back in the state it was in after this many steps, so it runs forever
+    Loop(usize),
+}
+
+impl Display for Stop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stop::Halted => write!(f, "halted"),
+            Stop::Breakpoint(index) => write!(f, "hit breakpoint {index}"),
+            Stop::Limit => write!(f, "reached the limit of instructions"),
+            Stop::Loop(steps) => write!(f, "looping, the state is the same as after {steps} steps"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Breakpoint {
+    /// Stops before the instruction at this position runs
+    Cursor(isize),
+    /// Stops after an instruction sets the register to this value
+    Register(Register, usize),
+}
+
+impl Display for Breakpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Breakpoint::Cursor(cursor) => write!(f, "@{cursor}"),
+            Breakpoint::Register(register, value) => write!(f, "{register}={value}"),
+        }
+    }
+}
+
+/// An instruction that was run, with the registers after it ran
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TraceEntry {
+    step: usize,
+    cursor: isize,
+    instruction: Instruction,
+    registers: Vec<(Register, usize)>,
+}
+
+impl Display for TraceEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:>8}  @{:<4} {:<12}",
+            self.step,
+            self.cursor,
+            self.instruction.to_string()
+        )?;
+        for (register, value) in &self.registers {
+            write!(f, " {register}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+struct Runtime {
+    cursor: isize,
+    registers: HashMap<char, usize>,
+    /// Instructions that were run
+    steps: usize,
+    /// Stop after this many instructions
+    limit: Option<usize>,
+    breakpoints: Vec<Breakpoint>,
+    /// The steps after which every state was seen, only kept when looking for loops
+    seen: Option<HashMap<State, usize>>,
+    /// The most recent instructions, up to the capacity of the trace
+    trace: Option<(usize, VecDeque<TraceEntry>)>,
+}
+
+impl Runtime {
+    fn new() -> Self {
+        Self {
+            cursor: 0,
+            registers: HashMap::new(),
+            steps: 0,
+            limit: None,
+            breakpoints: Vec::new(),
+            seen: None,
+            trace: None,
+        }
+    }
+
+    /// The registers sorted by name
+    fn registers(&self) -> Vec<(Register, usize)> {
+        let mut registers = self
+            .registers
+            .iter()
+            .map(|(&register, &value)| (register, value))
+            .collect::<Vec<_>>();
+        registers.sort_unstable();
+        registers
+    }
+
+    fn detect_loops(&mut self, detect: bool) {
+        self.seen = detect.then(HashMap::new);
+    }
+
+    /// Keeps the given number of most recent instructions
+    fn trace(&mut self, capacity: usize) {
+        self.trace = (capacity > 0).then(|| (capacity, VecDeque::with_capacity(capacity)));
+    }
+
+    fn set_register(&mut self, register: char, value: usize) {
+        self.registers.insert(register, value);
+    }
+
+    /// Forgets the states seen so far, after they were changed by hand and not by the program
+    fn forget_states(&mut self) {
+        if let Some(seen) = &mut self.seen {
+            seen.clear();
+        }
+    }
+}
+
+impl Runtime {
+    /// Runs until the program halts or something else stops it. Breakpoints on the cursor do not
+    /// stop the first instruction, so running again continues after a breakpoint.
+    fn run(&mut self, instructions: &[Instruction]) -> Stop {
+        let mut first = true;
+        loop {
+            if !first {
+                if let Some(index) = self.breakpoint(|breakpoint| {
+                    matches!(breakpoint, Breakpoint::Cursor(cursor) if *cursor == self.cursor)
+                }) {
+                    return Stop::Breakpoint(index);
+                }
+            }
+            if self.limit.is_some_and(|limit| self.steps >= limit) {
+                return Stop::Limit;
+            }
+            let state = self.seen.is_some().then(|| (self.cursor, self.registers()));
+            if let (Some(seen), Some(state)) = (&mut self.seen, state) {
+                match seen.entry(state) {
+                    Entry::Occupied(entry) => return Stop::Loop(*entry.get()),
+                    Entry::Vacant(entry) => {
+                        entry.insert(self.steps);
+                    }
+                }
+            }
+            let instruction = self.current(instructions);
+            if !self.step(instructions) {
+                return Stop::Halted;
+            }
+            let written = instruction.and_then(|instruction| instruction.written());
+            if let Some(index) = self.breakpoint(|breakpoint| match *breakpoint {
+                Breakpoint::Register(register, value) => {
+                    written == Some(register) && self.registers.get(&register) == Some(&value)
+                }
+                Breakpoint::Cursor(_) => false,
+            }) {
+                return Stop::Breakpoint(index);
+            }
+            first = false;
+        }
+    }
+
+    fn breakpoint(&self, hit: impl Fn(&Breakpoint) -> bool) -> Option<usize> {
+        self.breakpoints.iter().position(hit)
+    }
+
+    /// The instruction at the cursor, `None` when the cursor is outside of the program
+    fn current(&self, instructions: &[Instruction]) -> Option<Instruction> {
+        usize::try_from(self.cursor)
+            .ok()
+            .and_then(|cursor| instructions.get(cursor))
+            .copied()
+    }
+
+    /// Runs a single instruction, returns `false` when the cursor is outside of the program
+    fn step(&mut self, instructions: &[Instruction]) -> bool {
+        let Some(instruction) = self.current(instructions) else {
+            return false;
+        };
+        let cursor = self.cursor;
+        self.execute(instruction);
+        self.steps += 1;
+
+        let registers = self.trace.is_some().then(|| self.registers());
+        if let (Some((capacity, entries)), Some(registers)) = (&mut self.trace, registers) {
+            if entries.len() == *capacity {
+                entries.pop_front();
+            }
+            entries.push_back(TraceEntry {
+                step: self.steps,
+                cursor,
+                instruction,
+                registers,
+            });
+        }
+        true
+    }
+
+    /// Moves the cursor by the offset, a cursor past the addresses has left the program
+    fn jump(&mut self, offset: Offset) {
+        self.cursor = self.cursor.checked_add(offset).unwrap_or(isize::MAX);
+    }
+
+    /// Registers wrap around instead of overflowing
+    fn execute(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Half(register) => {
+                *self.registers.entry(register).or_insert(0) /= 2;
+                self.cursor += 1;
+            }
+            Instruction::Triple(register) => {
+                let value = self.registers.entry(register).or_insert(0);
+                *value = value.wrapping_mul(3);
+                self.cursor += 1;
+            }
+            Instruction::Increment(register) => {
+                let value = self.registers.entry(register).or_insert(0);
+                *value = value.wrapping_add(1);
+                self.cursor += 1;
+            }
+            Instruction::Jump(offset) => {
+                self.jump(offset);
+            }
+            Instruction::JumpIfEven(register, offset) => {
+                if (*self.registers.entry(register).or_insert(0)).is_multiple_of(2) {
+                    self.jump(offset);
+                } else {
+                    self.cursor += 1;
+                }
+            }
+            Instruction::JumpIfOne(register, offset) => {
+                if *self.registers.entry(register).or_insert(0) == 1 {
+                    self.jump(offset);
+                } else {
+                    self.cursor += 1;
+                }
+            }
+        }
+    }
+}
+
+/// Registers of a compiled program, from `a` to `z`
+type RegisterFile = [usize; 26];
+
+fn register_index(register: Register) -> usize {
+    (register as u8 - b'a') as usize
+}
+
+/// When a halving loop runs another iteration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Condition {
+    NotOne,
+    One,
+    Even,
+    Odd,
+}
+
+impl Condition {
+    /// Iterations until the condition no longer holds when the value is halved every iteration,
+    /// `None` when it holds forever
+    fn iterations(self, value: usize) -> Option<u32> {
+        match self {
+            Condition::NotOne => (value != 0).then(|| value.ilog2()),
+            Condition::One => Some((value == 1) as u32),
+            Condition::Even => (value != 0).then(|| value.trailing_zeros()),
+            Condition::Odd => Some(value.trailing_ones()),
+        }
+    }
+}
+
+/// An instruction with its register as an index, and its jump to an absolute position
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Op {
+    Half(usize),
+    Triple(usize),
+    Increment(usize),
+    /// Positions outside of the program halt it
+    Jump(usize),
+    JumpIfEven(usize, usize),
+    JumpIfOne(usize, usize),
+    /// Halves the register for as long as the condition holds, adding what every iteration adds
+    /// to the counters, then jumps to the exit
+    HalvingLoop {
+        register: usize,
+        condition: Condition,
+        counters: Vec<(usize, usize)>,
+        exit: usize,
+    },
+}
+
+/// The program compiled to run faster than the [`Runtime`], with the loops that halve a register
+/// replaced by a single step
+struct Compiled {
+    ops: Vec<Op>,
+}
+
+impl Compiled {
+    fn new(instructions: &[Instruction]) -> Self {
+        let target = |position: usize, offset: Offset| {
+            position.checked_add_signed(offset).unwrap_or(usize::MAX)
+        };
+        let mut ops = instructions
+            .iter()
+            .enumerate()
+            .map(|(position, &instruction)| match instruction {
+                Instruction::Half(register) => Op::Half(register_index(register)),
+                Instruction::Triple(register) => Op::Triple(register_index(register)),
+                Instruction::Increment(register) => Op::Increment(register_index(register)),
+                Instruction::Jump(offset) => Op::Jump(target(position, offset)),
+                Instruction::JumpIfEven(register, offset) => {
+                    Op::JumpIfEven(register_index(register), target(position, offset))
+                }
+                Instruction::JumpIfOne(register, offset) => {
+                    Op::JumpIfOne(register_index(register), target(position, offset))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Jumps into the middle of a loop still run its body one instruction at a time
+        for (header, op) in ops.iter_mut().enumerate() {
+            if let Some(halving_loop) = halving_loop(instructions, header) {
+                *op = halving_loop;
+            }
+        }
+
+        Self { ops }
+    }
+
+    /// Runs the program until it halts or is found to loop forever, in which case the steps are the
+    /// operations that were run
+    fn run(&self, registers: &mut RegisterFile) -> Stop {
+        // Brent's cycle detection, on the states at the jumps that do not go forward since every
+        // cycle has one of those
+        let mut checkpoint = (usize::MAX, [0; 26], 0);
+        let (mut power, mut length) = (1, 0);
+
+        let mut position = 0;
+        let mut steps = 0;
+        while let Some(op) = self.ops.get(position) {
+            steps += 1;
+            let next = match *op {
+                Op::Half(register) => {
+                    registers[register] /= 2;
+                    position + 1
+                }
+                Op::Triple(register) => {
+                    registers[register] = registers[register].wrapping_mul(3);
+                    position + 1
+                }
+                Op::Increment(register) => {
+                    registers[register] = registers[register].wrapping_add(1);
+                    position + 1
+                }
+                Op::Jump(target) => target,
+                Op::JumpIfEven(register, target) if registers[register].is_multiple_of(2) => target,
+                Op::JumpIfOne(register, target) if registers[register] == 1 => target,
+                Op::JumpIfEven(..) | Op::JumpIfOne(..) => position + 1,
+                Op::HalvingLoop {
+                    register,
+                    condition,
+                    ref counters,
+                    exit,
+                } => {
+                    let Some(iterations) = condition.iterations(registers[register]) else {
+                        return Stop::Loop(steps);
+                    };
+                    registers[register] = registers[register].checked_shr(iterations).unwrap_or(0);
+                    for &(counter, increments) in counters {
+                        registers[counter] = registers[counter]
+                            .wrapping_add(increments.wrapping_mul(iterations as usize));
+                    }
+                    exit
+                }
+            };
+
+            if next <= position {
+                if checkpoint.0 == next && checkpoint.1 == *registers {
+                    return Stop::Loop(checkpoint.2);
+                }
+                length += 1;
+                if length == power {
+                    checkpoint = (next, *registers, steps);
+                    power *= 2;
+                    length = 0;
+                }
+            }
+            position = next;
+        }
+
+        Stop::Halted
+    }
+}
+
+/// Recognises a loop that starts with a `jio` or `jie` on a register, and of which the body only
+/// halves that register once and increments other registers before jumping back. The loop is
+/// left either when the jump is taken, or when it is not taken and the jump after it is.
+fn halving_loop(instructions: &[Instruction], header: usize) -> Option<Op> {
+    let (register, offset, is_even) = match instructions[header] {
+        Instruction::JumpIfEven(register, offset) => (register, offset, true),
+        Instruction::JumpIfOne(register, offset) => (register, offset, false),
+        _ => return None,
+    };
+
+    let (body, exit, condition) = match instructions.get(header + 1) {
+        Some(Instruction::Jump(exit)) if offset == 2 => (
+            header + 2,
+            (header + 1).checked_add_signed(*exit),
+            if is_even {
+                Condition::Even
+            } else {
+                Condition::One
+            },
+        ),
+        _ => (
+            header + 1,
+            header.checked_add_signed(offset),
+            if is_even {
+                Condition::Odd
+            } else {
+                Condition::NotOne
+            },
+        ),
+    };
+
+    let mut halvings = 0;
+    let mut counters: Vec<(usize, usize)> = Vec::new();
+    let mut end = body;
+    loop {
+        match *instructions.get(end)? {
+            Instruction::Half(half) if half == register => halvings += 1,
+            Instruction::Increment(counter) if counter != register => {
+                let counter = register_index(counter);
+                match counters.iter_mut().find(|(index, _)| *index == counter) {
+                    Some((_, increments)) => *increments += 1,
+                    None => counters.push((counter, 1)),
+                }
+            }
+            Instruction::Jump(back) if end.checked_add_signed(back) == Some(header) => break,
+            _ => return None,
+        }
+        end += 1;
+    }
+
+    let exit = exit.unwrap_or(usize::MAX);
+    (halvings == 1 && !(header..=end).contains(&exit)).then_some(Op::HalvingLoop {
+        register: register_index(register),
+        condition,
+        counters,
+        exit,
+    })
+}
+
+impl Instruction {
+    /// The register that is read or written
+    fn register(&self) -> Option<Register> {
+        match *self {
+            Instruction::Half(register)
+            | Instruction::Triple(register)
+            | Instruction::Increment(register)
+            | Instruction::JumpIfEven(register, _)
+            | Instruction::JumpIfOne(register, _) => Some(register),
+            Instruction::Jump(_) => None,
+        }
+    }
+
+    fn offset(&self) -> Option<Offset> {
+        match *self {
+            Instruction::Jump(offset)
+            | Instruction::JumpIfEven(_, offset)
+            | Instruction::JumpIfOne(_, offset) => Some(offset),
+            _ => None,
+        }
+    }
+
+    /// The register that is written
+    fn written(&self) -> Option<Register> {
+        match *self {
+            Instruction::Half(register)
+            | Instruction::Triple(register)
+            | Instruction::Increment(register) => Some(register),
+            _ => None,
+        }
+    }
+}
+
+const HELP: &str = "\
+step [n]         run the next instruction, or the next n
+continue         run until the program halts or is stopped
+break @n         stop before the instruction at position n
+break r=n        stop when register r holds n
+break            list the breakpoints
+delete n         remove breakpoint n
+limit n|off      stop after n instructions in total
+loops on|off     stop when the program is back in a state it was in before
+trace n          keep the last n instructions
+trace            print the kept instructions
+set r n          put n in register r
+registers        print the registers
+list             print the program
+quit             stop debugging";
+
+/// A line typed in the debugger
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Command {
+    Step(usize),
+    Continue,
+    Break(Breakpoint),
+    Breakpoints,
+    Delete(usize),
+    Limit(Option<usize>),
+    Loops(bool),
+    Trace(usize),
+    PrintTrace,
+    Set(Register, usize),
+    Registers,
+    List,
+    Help,
+    Quit,
+}
+
+impl TryFrom<&str> for Command {
+    type Error = String;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        fn number<T: std::str::FromStr>(word: &str) -> Result<T, String> {
+            word.parse()
+                .map_err(|_| format!("Expected a number, found `{word}`"))
+        }
+
+        fn register(word: &str) -> Result<Register, String> {
+            let mut chars = word.chars();
+            match (chars.next(), chars.next()) {
+                (Some(register), None) if register.is_ascii_lowercase() => Ok(register),
+                _ => Err(format!("Expected a register, found `{word}`")),
+            }
+        }
+
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        match words.as_slice() {
+            ["step" | "s"] => Ok(Command::Step(1)),
+            ["step" | "s", count] => Ok(Command::Step(number(count)?)),
+            ["continue" | "c"] => Ok(Command::Continue),
+            ["break" | "b"] => Ok(Command::Breakpoints),
+            ["break" | "b", breakpoint] => match breakpoint.strip_prefix('@') {
+                Some(cursor) => Ok(Command::Break(Breakpoint::Cursor(number(cursor)?))),
+                None => {
+                    let (name, value) = breakpoint
+                        .split_once('=')
+                        .ok_or_else(|| format!("Expected `@n` or `r=n`, found `{breakpoint}`"))?;
+                    Ok(Command::Break(Breakpoint::Register(
+                        register(name)?,
+                        number(value)?,
+                    )))
+                }
+            },
+            ["delete" | "d", index] => Ok(Command::Delete(number(index)?)),
+            ["limit", "off"] => Ok(Command::Limit(None)),
+            ["limit", limit] => Ok(Command::Limit(Some(number(limit)?))),
+            ["loops", "on"] => Ok(Command::Loops(true)),
+            ["loops", "off"] => Ok(Command::Loops(false)),
+            ["trace" | "t"] => Ok(Command::PrintTrace),
+            ["trace" | "t", capacity] => Ok(Command::Trace(number(capacity)?)),
+            ["set", name, value] => Ok(Command::Set(register(name)?, number(value)?)),
+            ["registers" | "r"] => Ok(Command::Registers),
+            ["list" | "l"] => Ok(Command::List),
+            ["help" | "h"] => Ok(Command::Help),
+            ["quit" | "q"] => Ok(Command::Quit),
+            _ => Err(format!("Unknown command `{}`, try `help`", line.trim())),
+        }
+    }
+}
+
+/// Runs the commands on the program until they run out or one of them is `quit`
+fn debug(data: &str, commands: &mut dyn BufRead, output: &mut dyn Write) -> Result<(), Error> {
+    let instructions = parse_program(data)?;
+
+    let mut runtime = Runtime::new();
+    // Shows the registers of the program from the start, not just after they were first used
+    for register in instructions.iter().filter_map(Instruction::register) {
+        runtime.registers.entry(register).or_insert(0);
+    }
+
+    writeln!(
+        output,
+        "{} instructions, `help` lists the commands",
+        instructions.len()
+    )?;
+    let mut line = String::new();
+    loop {
+        write!(output, "> ")?;
+        output.flush()?;
+        line.clear();
+        if commands.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let command = match Command::try_from(line.as_str()) {
+            Ok(command) => command,
+            Err(err) => {
+                writeln!(output, "{err}")?;
+                continue;
+            }
+        };
+        match command {
+            Command::Step(count) => {
+                for _ in 0..count {
+                    let cursor = runtime.cursor;
+                    if !runtime.step(&instructions) {
+                        writeln!(output, "{}", Stop::Halted)?;
+                        break;
+                    }
+                    let entry = TraceEntry {
+                        step: runtime.steps,
+                        cursor,
+                        instruction: instructions[cursor as usize],
+                        registers: runtime.registers(),
+                    };
+                    writeln!(output, "{entry}")?;
+                }
+            }
+            Command::Continue => {
+                let stop = runtime.run(&instructions);
+                writeln!(output, "{stop}")?;
+                write_registers(output, &runtime)?;
+            }
+            Command::Break(breakpoint) => {
+                writeln!(
+                    output,
+                    "breakpoint {}: {breakpoint}",
+                    runtime.breakpoints.len()
+                )?;
+                runtime.breakpoints.push(breakpoint);
+            }
+            Command::Breakpoints => {
+                for (index, breakpoint) in runtime.breakpoints.iter().enumerate() {
+                    writeln!(output, "breakpoint {index}: {breakpoint}")?;
+                }
+            }
+            Command::Delete(index) if index < runtime.breakpoints.len() => {
+                runtime.breakpoints.remove(index);
+            }
+            Command::Delete(index) => writeln!(output, "There is no breakpoint {index}")?,
+            Command::Limit(limit) => runtime.limit = limit,
+            Command::Loops(detect) => runtime.detect_loops(detect),
+            Command::Trace(capacity) => runtime.trace(capacity),
+            Command::PrintTrace => match &runtime.trace {
+                Some((_, entries)) => {
+                    for entry in entries {
+                        writeln!(output, "{entry}")?;
+                    }
+                }
+                None => writeln!(
+                    output,
+                    "Not tracing, `trace n` keeps the last n instructions"
+                )?,
+            },
+            Command::Set(register, value) => {
+                runtime.set_register(register, value);
+                runtime.forget_states();
+            }
+            Command::Registers => write_registers(output, &runtime)?,
+            Command::List => {
+                for (position, instruction) in instructions.iter().enumerate() {
+                    let position = position as isize;
+                    let marker = if position == runtime.cursor {
+                        "=>"
+                    } else {
+                        "  "
+                    };
+                    writeln!(output, "{marker} {position:>4}  {instruction}")?;
+                }
+            }
+            Command::Help => writeln!(output, "{HELP}")?,
+            Command::Quit => return Ok(()),
+        }
+    }
+}
+
+fn write_registers(output: &mut dyn Write, runtime: &Runtime) -> std::io::Result<()> {
+    write!(output, "@{} after {} steps:", runtime.cursor, runtime.steps)?;
+    for (register, value) in runtime.registers() {
+        write!(output, " {register}={value}")?;
+    }
+    writeln!(output)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_program(input).map(parsed).map_err(Error::from))
+    }
+
+    fn render(
+        &self,
+        input: &str,
+        _part: Option<u8>,
+        format: &str,
+    ) -> Option<Result<Vec<u8>, Error>> {
+        let render: fn(&[Instruction]) -> String = match format {
+            "asm" => disassemble,
+            "listing" => |instructions| ControlFlow::new(instructions).listing(instructions),
+            "dot" => |instructions| ControlFlow::new(instructions).to_dot(instructions),
+            _ => return None,
+        };
+        Some(
+            parse_program(input)
+                .map(|instructions| render(&instructions).into_bytes())
+                .map_err(Error::from),
+        )
+    }
+
+    fn debug(
+        &self,
+        input: &str,
+        commands: &mut dyn BufRead,
+        output: &mut dyn Write,
+    ) -> Option<Result<(), Error>> {
+        Some(debug(input, commands, output))
+    }
+
+    fn variants(&self) -> &'static [&'static str] {
+        &[
+            "part_one_naive",
+            "part_two_naive",
+            "halving_loop",
+            "halving_loop_naive",
+        ]
+    }
+
+    fn variant(&self, name: &str, input: &str) -> Option<Result<Answer, Error>> {
+        Some(match name {
+            "part_one_naive" => run_program_naive(input, 0).map(Answer::from),
+            "part_two_naive" => run_program_naive(input, 1).map(Answer::from),
+            "halving_loop" => run_halving_program(run).map(Answer::from),
+            "halving_loop_naive" => run_halving_program(run_naive).map(Answer::from),
+            _ => return None,
+        })
+    }
+}
+
+/// Triples `a` four times and then halves it down to one, counting the steps in `b`. Unlike the
+/// loop of the puzzle, which also triples, the halving loop compiles to a single step.
+const HALVING_PROGRAM: &str = "tpl a\ntpl a\ntpl a\ntpl a\njio a, +4\nhlf a\ninc b\njmp -3";
+
+fn part_one(data: &str) -> Result<usize, Error> {
+    run_program(data, 0)
+}
+
+fn part_two(data: &str) -> Result<usize, Error> {
+    run_program(data, 1)
+}
+
+/// Register `b` after the program halts, starting with the given value in register `a`
+fn run_program(data: &str, a: usize) -> Result<usize, Error> {
+    run(&parse_program(data)?, a)
+}
+
+/// [`run_program`] one instruction at a time on the [`Runtime`] of the debugger, to compare the
+/// compiled program with. Without loop detection, it never returns if the program never halts.
+fn run_program_naive(data: &str, a: usize) -> Result<usize, Error> {
+    run_naive(&parse_program(data)?, a)
+}
+
+/// The sum of register `b` over [`HALVING_PROGRAM`] run with many values in register `a`
+fn run_halving_program(
+    run: fn(&[Instruction], usize) -> Result<usize, Error>,
+) -> Result<usize, Error> {
+    let instructions = parse_program(HALVING_PROGRAM)?;
+    (1..20_000).map(|a| run(&instructions, a * 7919)).sum()
+}
+
+fn run(instructions: &[Instruction], a: usize) -> Result<usize, Error> {
+    let mut registers = [0; 26];
+    registers[register_index('a')] = a;
+    match Compiled::new(instructions).run(&mut registers) {
+        Stop::Halted => Ok(registers[register_index('b')]),
+        stop => Err(format!("The program never halts, it is {stop}").into()),
+    }
+}
+
+fn run_naive(instructions: &[Instruction], a: usize) -> Result<usize, Error> {
+    let mut runtime = Runtime::new();
+    runtime.set_register('a', a);
+    match runtime.run(instructions) {
+        Stop::Halted => Ok(runtime.registers.get(&'b').copied().unwrap_or_default()),
+        stop => Err(format!("The program never halts, it is {stop}").into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+    use proptest::prelude::*;
+
+    use super::{
+        debug, disassemble, parse_program, register_index, Block, Breakpoint, Compiled, Condition,
+        ControlFlow, Day, Instruction, Op, ParseError, Runtime, Stop, Target, HALVING_PROGRAM,
+    };
+
+    // Halves `a` down to one, counting the steps in `b`. An odd `a` other than one is never
+    // changed, so the program gets stuck.
+    const PROGRAM: &str = "jio a, +6\njie a, +2\njmp +0\nhlf a\ninc b\njmp -5";
+
+    #[test]
+    fn run_halts() {
+        let instructions = parse_program(PROGRAM).unwrap();
+        let mut runtime = Runtime::new();
+        runtime.set_register('a', 8);
+        assert_eq!(runtime.run(&instructions), Stop::Halted);
+        assert_eq!(runtime.registers(), vec![('a', 1), ('b', 3)]);
+    }
+
+    #[test]
+    fn run_halts_after_jumping_past_the_addresses() {
+        let instructions = parse_program("jmp +1\njmp +9223372036854775807").unwrap();
+        let mut runtime = Runtime::new();
+        assert_eq!(runtime.run(&instructions), Stop::Halted);
+        assert_eq!(runtime.steps, 2);
+    }
+
+    #[test]
+    fn naive_variants() {
+        let program = "inc a\njio a, +2\ninc b\ninc b";
+        for (part, name) in [(1, "part_one_naive"), (2, "part_two_naive")] {
+            let naive = Day.variant(name, program).unwrap().unwrap();
+            let expected = match part {
+                1 => Day.part_one(program),
+                _ => Day.part_two(program),
+            };
+            assert_eq!(naive, expected.unwrap());
+        }
+        assert!(Day.variant("part_one_naive", "nop").unwrap().is_err());
+        assert!(Day.variant("fast", program).is_none());
+    }
+
+    #[test]
+    fn halving_loop_variants() {
+        let compiled = Compiled::new(&parse_program(HALVING_PROGRAM).unwrap());
+        assert!(matches!(compiled.ops[4], Op::HalvingLoop { .. }));
+
+        let halving = Day.variant("halving_loop", "").unwrap().unwrap();
+        let naive = Day.variant("halving_loop_naive", "").unwrap().unwrap();
+        assert_eq!(halving, naive);
+    }
+
+    #[test]
+    fn run_stops_at_limit() {
+        let instructions = parse_program(PROGRAM).unwrap();
+        let mut runtime = Runtime::new();
+        runtime.set_register('a', 8);
+        runtime.limit = Some(5);
+        assert_eq!(runtime.run(&instructions), Stop::Limit);
+        assert_eq!(runtime.steps, 5);
+    }
+
+    #[test]
+    fn run_detects_loops() {
+        let instructions = parse_program(PROGRAM).unwrap();
+        let mut runtime = Runtime::new();
+        runtime.set_register('a', 3);
+        runtime.detect_loops(true);
+        assert_eq!(runtime.run(&instructions), Stop::Loop(2));
+        assert_eq!(runtime.steps, 3);
+    }
+
+    #[test]
+    fn run_continues_after_breakpoints() {
+        let instructions = parse_program(PROGRAM).unwrap();
+        let mut runtime = Runtime::new();
+        runtime.set_register('a', 8);
+        runtime.breakpoints = vec![Breakpoint::Cursor(4), Breakpoint::Register('b', 2)];
+
+        assert_eq!(runtime.run(&instructions), Stop::Breakpoint(0));
+        assert_eq!(runtime.registers(), vec![('a', 4)]);
+        assert_eq!(runtime.run(&instructions), Stop::Breakpoint(0));
+        assert_eq!(runtime.run(&instructions), Stop::Breakpoint(1));
+        assert_eq!(runtime.cursor, 5);
+    }
+
+    #[test]
+    fn trace_keeps_recent_instructions() {
+        let instructions = parse_program(PROGRAM).unwrap();
+        let mut runtime = Runtime::new();
+        runtime.set_register('a', 8);
+        runtime.trace(2);
+        runtime.run(&instructions);
+
+        let (_, entries) = runtime.trace.as_ref().unwrap();
+        let trace = entries
+            .iter()
+            .map(|entry| (entry.cursor, entry.instruction))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            trace,
+            vec![
+                (5, Instruction::Jump(-5)),
+                (0, Instruction::JumpIfOne('a', 6))
+            ]
+        );
+    }
+
+    #[test]
+    fn debugger_session() {
+        let commands = "set a 8\nbreak b=1\nc\nstep 2\nfly\nc\nq\n";
+        let mut output = Vec::new();
+        debug(PROGRAM, &mut commands.as_bytes(), &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output.lines().collect::<Vec<_>>(),
+            vec![
+                "6 instructions, `help` lists the commands",
+                "> > breakpoint 0: b=1",
+                "> hit breakpoint 0",
+                "@5 after 4 steps: a=4 b=1",
+                ">        5  @5    jmp -5       a=4 b=1",
+                "       6  @0    jio a, +6    a=4 b=1",
+                "> Unknown command `fly`, try `help`",
+                "> halted",
+                "@6 after 16 steps: a=1 b=3",
+                "> ",
+            ]
+        );
+    }
+
+    #[test]
+    fn debugger_set_forgets_loops() {
+        // Setting the registers back to how they started is not the program looping
+        let commands = "set a 8\nset b 0\nloops on\nbreak b=1\nc\nset a 8\nset b 0\nd 0\nc\nq\n";
+        let mut output = Vec::new();
+        debug(PROGRAM, &mut commands.as_bytes(), &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output.lines().collect::<Vec<_>>(),
+            vec![
+                "6 instructions, `help` lists the commands",
+                "> > > > breakpoint 0: b=1",
+                "> hit breakpoint 0",
+                "@5 after 4 steps: a=4 b=1",
+                "> > > > halted",
+                "@6 after 21 steps: a=1 b=3",
+                "> ",
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_errors_point_at_word() {
+        let error = |line: usize, column: usize, expected: &'static str, found: Option<&str>| {
+            Err(ParseError {
+                line,
+                column,
+                expected,
+                found: found.map(String::from),
+            })
+        };
+        assert_eq!(
+            parse_program("inc a\n\nhlf ab"),
+            error(3, 5, "a register", Some("ab"))
+        );
+        assert_eq!(parse_program("jie a +4"), error(1, 7, "`,`", Some("+4")));
+        assert_eq!(
+            parse_program("jio a,"),
+            error(1, 7, "an offset like `+4`", None)
+        );
+        assert_eq!(
+            parse_program("inc a b"),
+            error(1, 7, "the end of the line", Some("b"))
+        );
+        assert_eq!(
+            parse_program("  mul a").unwrap_err().to_string(),
+            "1:3: Expected an instruction, found `mul`"
+        );
+    }
+
+    #[test]
+    fn disassemble_round_trips() {
+        let source = "inc a\njio a, +2\ntpl a\njie b, -3\njmp +0\nhlf b\n";
+        let instructions = parse_program(source).unwrap();
+        assert_eq!(disassemble(&instructions), source);
+        assert_eq!(
+            disassemble(&parse_program("jio   a,+2\n").unwrap()),
+            "jio a, +2\n"
+        );
+    }
+
+    #[test]
+    fn control_flow_blocks() {
+        let instructions = parse_program(PROGRAM).unwrap();
+        let control_flow = ControlFlow::new(&instructions);
+        assert_eq!(
+            control_flow.blocks,
+            vec![
+                Block {
+                    start: 0,
+                    end: 1,
+                    successors: vec![Target::Halt, Target::Block(1)],
+                },
+                Block {
+                    start: 1,
+                    end: 2,
+                    successors: vec![Target::Block(3), Target::Block(2)],
+                },
+                Block {
+                    start: 2,
+                    end: 3,
+                    successors: vec![Target::Block(2)],
+                },
+                Block {
+                    start: 3,
+                    end: 6,
+                    successors: vec![Target::Block(0)],
+                },
+            ]
+        );
+        assert!(control_flow.is_loop(0));
+        assert!(control_flow.is_loop(2));
+        assert!(!control_flow.is_loop(3));
+    }
+
+    #[test]
+    fn control_flow_listing() {
+        let instructions = parse_program(PROGRAM).unwrap();
+        assert_eq!(
+            ControlFlow::new(&instructions).listing(&instructions),
+            "L1:  ; loop\n    jio a, halt\n    jie a, L3\nL2:  ; loop\n    jmp L2\nL3:\n    hlf a\n    inc b\n    jmp L1\n"
+        );
+    }
+
+    fn any_instruction() -> impl Strategy<Value = Instruction> {
+        let register = proptest::char::range('a', 'z');
+        prop_oneof![
+            register.clone().prop_map(Instruction::Half),
+            register.clone().prop_map(Instruction::Triple),
+            register.clone().prop_map(Instruction::Increment),
+            any::<isize>().prop_map(Instruction::Jump),
+            (register.clone(), any::<isize>())
+                .prop_map(|(register, offset)| Instruction::JumpIfEven(register, offset)),
+            (register, any::<isize>())
+                .prop_map(|(register, offset)| Instruction::JumpIfOne(register, offset)),
+        ]
+    }
+
+    fn run_compiled(program: &str, a: usize) -> Option<Vec<usize>> {
+        let mut registers = [0; 26];
+        registers[register_index('a')] = a;
+        let stop = Compiled::new(&parse_program(program).unwrap()).run(&mut registers);
+        (stop == Stop::Halted).then(|| registers[..3].to_vec())
+    }
+
+    #[test]
+    fn compile_halving_loops() {
+        let compiled = Compiled::new(&parse_program("jio a, +4\nhlf a\ninc b\njmp -3").unwrap());
+        assert_eq!(
+            compiled.ops[0],
+            Op::HalvingLoop {
+                register: 0,
+                condition: Condition::NotOne,
+                counters: vec![(1, 1)],
+                exit: 4,
+            }
+        );
+        assert_eq!(
+            run_compiled("jio a, +4\nhlf a\ninc b\njmp -3", 8),
+            Some(vec![1, 3, 0])
+        );
+
+        let program = "jie a, +2\njmp +6\nhlf a\ninc b\ninc c\ninc b\njmp -6";
+        let compiled = Compiled::new(&parse_program(program).unwrap());
+        assert_eq!(
+            compiled.ops[0],
+            Op::HalvingLoop {
+                register: 0,
+                condition: Condition::Even,
+                counters: vec![(1, 2), (2, 1)],
+                exit: 7,
+            }
+        );
+        assert_eq!(run_compiled(program, 40), Some(vec![5, 6, 3]));
+
+        // Halving another register, or halving twice, is left to run one step at a time
+        for program in [
+            "jio a, +4\nhlf b\ninc b\njmp -3",
+            "jio a, +4\nhlf a\nhlf a\njmp -3",
+        ] {
+            let compiled = Compiled::new(&parse_program(program).unwrap());
+            assert!(matches!(compiled.ops[0], Op::JumpIfOne(0, 4)));
+        }
+    }
+
+    #[test]
+    fn compiled_never_halts() {
+        assert_eq!(run_compiled(PROGRAM, 8), Some(vec![1, 3, 0]));
+        assert_eq!(run_compiled(PROGRAM, 3), None);
+        assert_eq!(run_compiled("jio a, +4\nhlf a\ninc b\njmp -3", 0), None);
+        assert_eq!(run_compiled("inc b\njie b, +2\njmp -2\njmp +0", 0), None);
+    }
+
+    fn small_instruction() -> impl Strategy<Value = Instruction> {
+        let register = proptest::char::range('a', 'c');
+        let offset = -4..=4isize;
+        prop_oneof![
+            register.clone().prop_map(Instruction::Half),
+            register.clone().prop_map(Instruction::Triple),
+            register.clone().prop_map(Instruction::Increment),
+            offset.clone().prop_map(Instruction::Jump),
+            (register.clone(), offset.clone())
+                .prop_map(|(register, offset)| Instruction::JumpIfEven(register, offset)),
+            (register, offset)
+                .prop_map(|(register, offset)| Instruction::JumpIfOne(register, offset)),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn parse_disassembled_program(instructions in prop::collection::vec(any_instruction(), 0..32)) {
+            prop_assert_eq!(parse_program(&disassemble(&instructions)), Ok(instructions));
+        }
+
+        #[test]
+        fn compiled_matches_runtime(
+            instructions in prop::collection::vec(small_instruction(), 0..16),
+            a in 0..1000usize,
+        ) {
+            let mut runtime = Runtime::new();
+            runtime.set_register('a', a);
+            runtime.limit = Some(10_000);
+            runtime.detect_loops(true);
+            let stop = runtime.run(&instructions);
+
+            // A program that runs past the limit without repeating itself might never stop, so the
+            // compiled program is only run when the runtime found how it ends
+            prop_assume!(stop != Stop::Limit);
+            let mut registers = [0; 26];
+            registers[register_index('a')] = a;
+            let compiled_stop = Compiled::new(&instructions).run(&mut registers);
+
+            if stop == Stop::Halted {
+                prop_assert_eq!(compiled_stop, Stop::Halted);
+                for register in ['a', 'b', 'c'] {
+                    let expected = runtime.registers.get(&register).cloned().unwrap_or_default();
+                    prop_assert_eq!(registers[register_index(register)], expected);
+                }
+            } else {
+                prop_assert_ne!(compiled_stop, Stop::Halted);
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use aoc_core::{parsed, Answer, Error, Solution};
+use itertools::Itertools;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        parsed(load_packages(input));
+        Some(Ok(()))
+    }
+}
+
+fn load_packages(data: &str) -> Vec<usize> {
+    data
+        .lines()
+        .filter_map(|x| x.parse::<usize>().ok())
+        .collect::<Vec<_>>()
+}
+
+fn part_one(data: &str) -> usize {
+    let packages = load_packages(data);
+    let total_weight = packages.iter().sum::<usize>();
+
+    let required_group_weight = total_weight / 3;
+    smallest_quantum_entanglement(&packages, required_group_weight)
+}
+
+fn part_two(data: &str) -> usize {
+    let packages = load_packages(data);
+    let total_weight = packages.iter().sum::<usize>();
+
+    let required_group_weight = total_weight / 4;
+    smallest_quantum_entanglement(&packages, required_group_weight)
+}
+
+fn smallest_quantum_entanglement(packages: &[usize], required_group_weight: usize) -> usize {
+    for i in 1..10 {
+        let combination = packages.iter().combinations(i).filter(|packages| {
+            packages.iter().copied().sum::<usize>() == required_group_weight
+        }).map(|packages| {
+	    packages.into_iter().product::<usize>()
+	}).min();
+
+	if let Some(qe) = combination {
+	    return qe;
+	}
+    }
+
+    panic!("Could not find a group with the required weight")
+}
@@ -0,0 +1,67 @@
+use aoc_core::{parsed, Answer, Error, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_position(input).map(parsed))
+    }
+}
+
+/// The manual tells us which row and column of the code grid to enter, in that order
+fn parse_position(data: &str) -> Result<(usize, usize), Error> {
+    let mut numbers = data
+        .split(|chr: char| !chr.is_ascii_digit())
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| segment.parse::<usize>());
+    let mut next = || numbers.next().ok_or("Could not find the row and column");
+
+    Ok((next()??, next()??))
+}
+
+fn part_one(data: &str) -> Result<u64, Error> {
+    let (row, column) = parse_position(data)?;
+
+    let mut cur = 20151125u64;
+    for _i in 1..get_index(row, column) {
+	cur = cur * 252533 % 33554393;
+    }
+
+    Ok(cur)
+}
+
+fn get_index(row: usize, column: usize) -> usize {
+    let total = row + column - 2;
+
+    total*(total+1)/2 + column
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_index1() {
+	assert_eq!(get_index(1, 1), 1);
+    }
+    #[test]
+    fn test_get_index2() {
+	assert_eq!(get_index(1, 2), 3);
+    }
+    #[test]
+    fn test_get_index3() {
+	assert_eq!(get_index(2, 1), 2);
+    }
+    #[test]
+    fn test_get_index4() {
+	assert_eq!(get_index(3, 3), 13);
+    }
+    #[test]
+    fn test_get_index5() {
+	assert_eq!(get_index(3, 4), 19);
+    }
+}
@@ -0,0 +1,6 @@
+use std::{env, path::Path};
+
+fn main() {
+    let out = Path::new(&env::var("OUT_DIR").expect("OUT_DIR is not set")).join("examples.rs");
+    aoc_core::examples::generate(2021, &out).expect("Could not generate the example tests");
+}
@@ -0,0 +1,39 @@
+use aoc_core::{parsed, Answer, Error, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_numbers(input).map(parsed))
+    }
+}
+
+fn parse_numbers(input: &str) -> Result<Vec<u32>, Error> {
+    Ok(input.lines().map(|x|x.parse()).collect::<Result<_, _>>()?)
+}
+
+fn part_one(input: &str) -> Result<usize, Error> {
+    Ok(count_greater(&parse_numbers(input)?, 1))
+}
+
+fn part_two(input: &str) -> Result<usize, Error> {
+    Ok(count_greater(&parse_numbers(input)?, 3))
+}
+
+fn count_greater(numbers: &[u32], window: usize) -> usize {
+    let greater =
+        numbers.iter().skip(window)
+        .zip(numbers.iter())
+        .filter(|(&x, &y)| x > y)
+        .count();
+
+    greater
+}
\ No newline at end of file
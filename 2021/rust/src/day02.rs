@@ -1,21 +1,44 @@
-use std::{str::FromStr, error::Error};
+use std::{str::FromStr};
 
-fn main() {
-    let commands = parse_file("./02-input.txt").unwrap();
+use aoc_core::{parsed, Answer, Error, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+}
+
+fn part_one(input: &str) -> Result<u32, Error> {
+    let commands = parse_input(input)?;
 
     let mut submarine: SimpleSubmarine = Default::default();
-    commands.iter().for_each(|(command, amount)| { submarine.make_move(&command, *amount)});
-    println!("Result Simple: {}", submarine.horizontal * submarine.depth);
+    commands.iter().for_each(|(command, amount)| { submarine.make_move(command, *amount)});
+    Ok(submarine.horizontal * submarine.depth)
+}
+
+fn part_two(input: &str) -> Result<u32, Error> {
+    let commands = parse_input(input)?;
 
     let mut submarine: ComplicatedSubmarine = Default::default();
-    commands.iter().for_each(|(command, amount)| { submarine.make_move(&command, *amount)});
-    println!("Result Complicated: {}", submarine.horizontal * submarine.depth);
+    commands.iter().for_each(|(command, amount)| { submarine.make_move(command, *amount)});
+    Ok(submarine.horizontal * submarine.depth)
 }
 
 pub trait Submarine {
     fn make_move(&mut self, command: &Command, amount: u32);
 }
 
+#[derive(Default)]
 struct ComplicatedSubmarine {
     pub horizontal: u32,
     pub aim: u32,
@@ -35,6 +58,7 @@ impl Submarine for ComplicatedSubmarine {
     }
 }
 
+#[derive(Default)]
 pub struct SimpleSubmarine {
     pub horizontal: u32,
     pub depth: u32
@@ -56,10 +80,8 @@ pub enum Command {
     Down
 }
 
-fn parse_file(filename: &str) -> Result<Vec<(Command, u32)>, Box<dyn Error>> {
-    let file = std::fs::read_to_string(filename).expect("File not found");
-
-    let commands = file.lines().map(|line| {
+fn parse_input(input: &str) -> Result<Vec<(Command, u32)>, Error> {
+    let commands = input.lines().map(|line| {
         let mut words = line.split(" ");
         let command: Command = words.next().expect("No command in line").parse().unwrap();
         let amount: u32 = words.next().expect("No amount in line").parse().expect("Could not parse amount as integer");
@@ -69,17 +91,7 @@ fn parse_file(filename: &str) -> Result<Vec<(Command, u32)>, Box<dyn Error>> {
     Ok(commands)
 }
 
-impl Default for SimpleSubmarine {
-    fn default() -> Self {
-        Self { horizontal: 0, depth: 0 }
-    }
-}
 
-impl Default for ComplicatedSubmarine {
-    fn default() -> Self {
-        Self { horizontal: 0, aim: 0, depth: 0 }
-    }
-}
 
 impl FromStr for Command {
     type Err = String;
@@ -96,43 +108,44 @@ impl FromStr for Command {
 
 #[cfg(test)]
 mod tests {
-    use crate::{SimpleSubmarine, parse_file, Submarine, ComplicatedSubmarine};
+    use super::{SimpleSubmarine, parse_input, Submarine, ComplicatedSubmarine};
+    use aoc_core::input::{example, puzzle};
 
     #[test]
     fn test_simple_example() {
-        let commands = parse_file("./02-example-input.txt").unwrap();
+        let commands = parse_input(&example(2021, 2, "example").unwrap()).unwrap();
 
         let mut submarine: SimpleSubmarine = Default::default();
-        commands.iter().for_each(|(command, amount)| { submarine.make_move(&command, *amount)});
+        commands.iter().for_each(|(command, amount)| { submarine.make_move(command, *amount)});
         assert_eq!(15, submarine.horizontal);
         assert_eq!(10, submarine.depth);
     }
 
     #[test]
     fn test_simple() {
-        let commands = parse_file("./02-input.txt").unwrap();
+        let commands = parse_input(&puzzle(2021, 2).unwrap()).unwrap();
 
         let mut submarine: SimpleSubmarine = Default::default();
-        commands.iter().for_each(|(command, amount)| { submarine.make_move(&command, *amount)});
+        commands.iter().for_each(|(command, amount)| { submarine.make_move(command, *amount)});
         assert_eq!(1451208, submarine.horizontal * submarine.depth);
     }
 
     #[test]
     fn test_complicated_example() {
-        let commands = parse_file("./02-example-input.txt").unwrap();
+        let commands = parse_input(&example(2021, 2, "example").unwrap()).unwrap();
 
         let mut submarine: ComplicatedSubmarine = Default::default();
-        commands.iter().for_each(|(command, amount)| { submarine.make_move(&command, *amount)});
+        commands.iter().for_each(|(command, amount)| { submarine.make_move(command, *amount)});
         assert_eq!(15, submarine.horizontal);
         assert_eq!(60, submarine.depth);
     }
 
     #[test]
     fn test_complicated() {
-        let commands = parse_file("./02-input.txt").unwrap();
+        let commands = parse_input(&puzzle(2021, 2).unwrap()).unwrap();
 
         let mut submarine: ComplicatedSubmarine = Default::default();
-        commands.iter().for_each(|(command, amount)| { submarine.make_move(&command, *amount)});
+        commands.iter().for_each(|(command, amount)| { submarine.make_move(command, *amount)});
         assert_eq!(1620141160, submarine.horizontal * submarine.depth);
     }
 }
\ No newline at end of file
@@ -0,0 +1,117 @@
+use aoc_core::{parsed, Answer, Error, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+}
+
+fn part_one(input: &str) -> Result<u32, Error> {
+    let ones_count = parse_input(input)?;
+    let (gamma_count, epsilon_count) = calc_gamma_epsilon_count(ones_count);
+    Ok(gamma_count * epsilon_count)
+}
+
+fn iter_to_binary<I>(iterable: I) -> u32
+where I: IntoIterator<Item=bool>,
+{
+    iterable.into_iter().fold(0, |acc, x|acc*2 + if x {1} else {0})
+}
+
+fn parse_input(input: &str) -> Result<Vec<i32>, Error> {
+    let binary_length = input.lines().next().ok_or("No first line in input")?.len();
+
+    let mut ones_count: Vec<i32> = vec![0; binary_length];
+    for line in input.lines() {
+        for (i, bit) in ones_count.iter_mut().enumerate() {
+            *bit += if line.chars().nth(i).ok_or("Line too short")? == '1' { 1 } else { -1 }
+        }
+    }
+
+    Ok(ones_count)
+}
+
+fn calc_gamma_epsilon_count(ones_count: Vec<i32>) -> (u32, u32) {
+   let gamma_count = iter_to_binary(ones_count.iter().map(|&x| { x >= 0 } ));
+   let epsilon_count = iter_to_binary(ones_count.iter().map(|&x| { x < 0 } ));
+
+    (gamma_count, epsilon_count)
+}
+
+fn part_two(input: &str) -> Result<u64, Error> {
+    // Interpret input as 2D Vector of booleans
+    let input: Vec<Vec<bool>> = input.lines().map(|line| {
+        line.chars().map(|char| { char == '1' }).collect()
+    }).collect();
+
+    let oxygen = find_gas(&input, |total| {total >= 0});
+    let carbon = find_gas(&input, |total| {total < 0});
+
+    let oxygen_count = vec_bool_to_binary(&oxygen);
+    let carbon_count = vec_bool_to_binary(&carbon);
+
+    Ok(oxygen_count * carbon_count)
+}
+
+fn vec_bool_to_binary(binary: &[bool]) -> u64 {
+    binary.iter().fold(0, |acc, &x|acc*2 + if x {1} else {0})
+}
+
+fn find_gas(input: &[Vec<bool>], gas_identifier: fn(i32) -> bool) -> Vec<bool> {
+    let width = input[0].len();
+    let mut gas: Vec<bool> = Vec::new();
+    let mut filter_codes = input.to_vec();
+    for i in 0..width {
+        filter_codes.retain(|bit_string| {
+            bit_string.iter().zip(gas.iter()).all(|(x, y)| {x == y})
+        });
+
+        if filter_codes.len() == 1{
+            gas = filter_codes[0].clone()
+        } else if !filter_codes.is_empty() {
+            // Mapping 1 to 1 and 0 to -1. The sum should be positive if there are more ones than zeroes.
+            let total: i32 = filter_codes.iter().map(|bit_string| {
+                if *bit_string.get(i).expect("Line too short") { 1 } else { -1 }
+            }).sum();
+            gas.push(gas_identifier(total));
+        }
+    }
+
+    gas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calc_gamma_epsilon_count, parse_input, part_two};
+    use aoc_core::input::{example, puzzle};
+
+    #[test]
+    fn test_simple_example_input() {
+        let ones_count = parse_input(&example(2021, 3, "example").unwrap()).unwrap();
+        let (gamma, epsilon) = calc_gamma_epsilon_count(ones_count);
+        assert_eq!(22, gamma);
+        assert_eq!(9, epsilon);
+    }
+
+    #[test]
+    fn test_simple_puzzle_input() {
+        let ones_count = parse_input(&puzzle(2021, 3).unwrap()).unwrap();
+        let (gamma, epsilon) = calc_gamma_epsilon_count(ones_count);
+        assert_eq!(4139586, gamma*epsilon);
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(part_two(&puzzle(2021, 3).unwrap()).unwrap(), 1800151)
+    }
+}
\ No newline at end of file
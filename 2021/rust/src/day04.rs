@@ -0,0 +1,145 @@
+use aoc_core::{parsed, Answer, Error, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+}
+
+fn part_one(input: &str) -> Result<u32, Error> {
+    let (numbers, mut boards) = parse_input(input)?;
+
+    let (_winning_number, winning_score) = play_until_winner(&numbers, &mut boards).expect("Nobody won");
+    Ok(winning_score)
+}
+
+fn part_two(input: &str) -> Result<u32, Error> {
+    let (numbers, mut boards) = parse_input(input)?;
+
+    let (winning_number, winning_score) = play_until_last_winner(&numbers, &mut boards).expect("Nobody won");
+    Ok(winning_number * winning_score)
+}
+
+fn play_until_winner(numbers: &[u32], boards: &mut [Board]) -> Option<(u32, u32)> {
+    for number in numbers {
+        for board in boards.iter_mut() {
+            board.data.iter_mut().for_each(|x| {
+                if *x == Some(*number) {
+                    *x = None
+                }
+            });
+
+            if board.is_winner() {
+                let winning_number = *number;
+                let winning_score = *number * board.data.iter().filter(|x| x.is_some()).fold(0, |acc, x| x.unwrap() + acc);
+                return Some((winning_number, winning_score));
+            }
+        }
+    };
+    None
+}
+
+fn play_until_last_winner(numbers: &[u32], boards: &mut Vec<Board>) -> Option<(u32, u32)> {
+    for &number in numbers {
+        let mut winners = Vec::new();
+
+        for (i, board) in boards.iter_mut().enumerate() {
+            for x in board.data.iter_mut() {
+                if *x == Some(number) {
+                    *x = None
+                }
+            }
+
+            if board.is_winner() {
+                winners.push(i);
+            }
+        }
+
+        if boards.len()-winners.len() == 0 {
+            let winning_score = boards[0].data.iter().filter_map(|&x| {x}).sum();
+            let winning_number = number;
+            return Some((winning_number, winning_score));
+        }
+
+        winners.sort();
+        winners.reverse();
+        for i in winners {
+            boards.remove(i);
+        }
+    }
+
+    None
+}
+
+fn parse_input(input: &str) -> Result<(Vec<u32>, Vec<Board>), Error> {
+    let mut lines = input.lines().peekable();
+    let mut boards: Vec<Board> = Vec::new();
+
+    let numbers: Vec<u32> = lines.next().ok_or("File is empty")?
+        .split(",")
+        .map(|x| x.parse::<u32>().expect("Could not parse to int"))
+        .collect();
+
+    lines.next();
+
+    while lines.peek().is_some() {
+        let width = lines.peek().unwrap().split_whitespace().count();
+        let data: Vec<Option<u32>> = lines.by_ref().take_while(|&x| { !x.is_empty() }).flat_map(|line| {
+            line.split_whitespace()
+                .map(|x| Some(x.parse::<u32>().expect("Could not parse to int")))
+        }).collect();
+
+        let height = data.len()/width;
+
+        boards.push(Board {
+            data,
+            width,
+            height
+        });
+    }
+
+    Ok((numbers, boards))
+}
+
+#[derive(Clone, Debug)]
+struct Board {
+    data: Vec<Option<u32>>,
+    width: usize,
+    height: usize,
+}
+
+impl Board {
+    fn index(&self, x: usize, y: usize) -> usize {
+        self.width * y + x
+    }
+
+    fn is_winner(&self) -> bool {
+        self.has_row() || self.has_column()
+    }
+
+    fn has_row(&self) -> bool {
+        (0..self.height).any(|y| {
+            (0..self.width).all(|x|{
+                self.data[self.index(x, y)].is_none()
+            })
+        })
+    }
+
+    fn has_column(&self) -> bool {
+        (0..self.width).any(|x| {
+            (0..self.height).all(|y| {
+                self.data[self.index(x, y)].is_none()
+            })
+        })
+    }
+}
\ No newline at end of file
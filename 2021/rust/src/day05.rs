@@ -1,25 +1,52 @@
 use std::collections::BTreeMap;
-use std::error::Error;
 use std::ops::RangeInclusive;
 
-fn main() {
-    let lines: Vec<((usize, usize), (usize, usize))> = parse_input("./05-input.txt").unwrap();
+use aoc_core::{parsed, Answer, Error, Solution};
 
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+}
+
+type Line = ((usize, usize), (usize, usize));
+
+fn part_one(input: &str) -> Result<usize, Error> {
+    let lines: Vec<Line> = parse_input(input)?;
+
+    // Only consider horizontal and vertical lines
+    let lines = lines.into_iter().filter(|(start, end)| start.0 == end.0 || start.1 == end.1);
+    Ok(count_overlaps(lines))
+}
+
+fn part_two(input: &str) -> Result<usize, Error> {
+    let lines: Vec<Line> = parse_input(input)?;
+
+    Ok(count_overlaps(lines))
+}
+
+fn count_overlaps(lines: impl IntoIterator<Item = Line>) -> usize {
     let mut coords: BTreeMap<(usize, usize), u16> = BTreeMap::new();
-    lines.iter().map(|line| interp(line.0, line.1)).flatten().for_each(|coord| {
+    lines.into_iter().flat_map(|line| interp(line.0, line.1)).for_each(|coord| {
         let coord_entry = coords.entry(coord).or_insert(0);
         *coord_entry += 1;
     });
 
-    let amount = coords.values().filter(|&x| *x >= 2).count();
-
-    println!("Result: {}", amount)
+    coords.values().filter(|&x| *x >= 2).count()
 }
 
-fn parse_input(filename: &str) -> Result<Vec<((usize, usize), (usize, usize))>, Box<dyn Error>> {
-    let file = std::fs::read_to_string(filename)?;
-
-    Ok(file.lines().map(|input|{
+fn parse_input(input: &str) -> Result<Vec<Line>, Error> {
+    Ok(input.lines().map(|input|{
         let sides: Vec<(usize, usize)> = input.split(" -> ").map(|side| {
             let coords: Vec<usize> = side.split(',').map(|x| x.parse::<usize>().unwrap()).collect();
             (coords[0], coords[1])
@@ -59,7 +86,7 @@ fn birange_inclusive(from: usize, to: usize) -> RangeInclusive<usize> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{birange_inclusive, interp};
+    use super::{birange_inclusive, interp};
 
     #[test]
     fn interp_test() {
@@ -0,0 +1,65 @@
+use aoc_core::{parsed, Answer, Error, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+}
+
+fn part_one(input: &str) -> Result<u64, Error> {
+    let fish = parse_input(input)?;
+
+    Ok(predict_lanternfish(&fish, 80))
+}
+
+fn part_two(input: &str) -> Result<u64, Error> {
+    let fish = parse_input(input)?;
+
+    Ok(predict_lanternfish(&fish, 256))
+}
+
+fn parse_input(input: &str) -> Result<Vec<usize>, Error> {
+    let line = input.lines().next().ok_or("No line in input")?;
+    Ok(line.split(',').map(|x| x.parse::<usize>().unwrap()).collect())
+}
+
+fn predict_lanternfish(start_state: &[usize], days: u16) -> u64 {
+    let mut fish = [0; 9];
+    start_state.iter().for_each(|x| {
+        fish[*x] += 1
+    });
+
+    for _ in 0..days {
+        let new_fish = fish[0];
+        for i in 0..8 {
+            fish[i] = fish[i+1]
+        }
+
+        fish[6] += new_fish;
+        fish[8] = new_fish;
+    }
+
+    fish.iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::predict_lanternfish;
+
+    #[test]
+    fn latern_test() {
+        let start_state = vec![3, 4, 3, 1, 2];
+        assert_eq!(predict_lanternfish(&start_state, 18), 26);
+        assert_eq!(predict_lanternfish(&start_state, 80), 5934);
+    }
+}
\ No newline at end of file
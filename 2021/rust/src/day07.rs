@@ -1,28 +1,45 @@
-use std::error::Error;
+use aoc_core::{parsed, Answer, Error, Solution};
 
-fn main() {
-    let positions = parse_file("./07-input.txt").unwrap();
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+}
+
+fn part_one(input: &str) -> Result<usize, Error> {
+    let positions = parse_input(input)?;
     let ideal_position = find_optimal_position(&positions);
 
-    println!("{}", ideal_position);
-    println!("{}", calculate_fuel_linear(&ideal_position, &positions));
+    Ok(calculate_fuel_linear(&ideal_position, &positions))
+}
 
+fn part_two(input: &str) -> Result<usize, Error> {
+    let positions = parse_input(input)?;
     let ideal_position = find_optimal_position_naive(&positions, calculate_fuel_quadratic);
-    println!("{}", ideal_position);
-    println!("{}", calculate_fuel_quadratic(&ideal_position, &positions));
+
+    Ok(calculate_fuel_quadratic(&ideal_position, &positions))
 }
 
-fn parse_file(filename: &str) -> Result<Vec<usize>, Box<dyn Error>> {
-    let file = std::fs::read_to_string(filename)?;
-    let line = file.lines().nth(0).ok_or("No lines in file")?;
+fn parse_input(input: &str) -> Result<Vec<usize>, Error> {
+    let line = input.lines().next().ok_or("No lines in input")?;
 
     Ok(line.split(',').map(|x|x.parse::<usize>().expect("Could not parse number")).collect())
 }
 
-fn find_optimal_position_naive(start_positons: &Vec<usize>, fuel_func: fn(&usize, &Vec<usize>) -> usize) -> usize {
+fn find_optimal_position_naive(start_positons: &[usize], fuel_func: fn(&usize, &[usize]) -> usize) -> usize {
     let minimum_position = start_positons.iter().min().expect("Could not find minimum");
     let max_position = start_positons.iter().max().expect("Could not find maximum");
-    let mut optimal_fuel = std::usize::MAX;
+    let mut optimal_fuel = usize::MAX;
     let mut optimal_position = 0;
     for position in *minimum_position..=*max_position {
         let fuel = fuel_func(&position, start_positons);
@@ -35,28 +52,28 @@ fn find_optimal_position_naive(start_positons: &Vec<usize>, fuel_func: fn(&usize
     optimal_position
 }
 
-fn calculate_fuel_linear(position: &usize, start_positions: &Vec<usize>) -> usize {
+fn calculate_fuel_linear(position: &usize, start_positions: &[usize]) -> usize {
     start_positions.iter().fold(0,|fuel, crab_position| {
-        fuel + (*position as i32 - *crab_position as i32).abs() as usize
+        fuel + (*position as i32 - *crab_position as i32).unsigned_abs() as usize
     })
 }
 
-fn calculate_fuel_quadratic(position: &usize, start_positions: &Vec<usize>) -> usize {
+fn calculate_fuel_quadratic(position: &usize, start_positions: &[usize]) -> usize {
     start_positions.iter().fold(0,|fuel, crab_position| {
-        let distance = (*position as i32 - *crab_position as i32).abs() as usize;
+        let distance = (*position as i32 - *crab_position as i32).unsigned_abs() as usize;
         fuel + distance*(distance+1)/2
     })
 }
 
-fn find_optimal_position(start_positions: &Vec<usize>) -> usize {
-    let mut sorted_positions = start_positions.clone();
+fn find_optimal_position(start_positions: &[usize]) -> usize {
+    let mut sorted_positions = start_positions.to_vec();
     sorted_positions.sort();
-    sorted_positions[sorted_positions.len()/2 as usize]
+    sorted_positions[sorted_positions.len()/2_usize]
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{calculate_fuel_linear, calculate_fuel_quadratic, find_optimal_position, find_optimal_position_naive};
+    use super::{calculate_fuel_linear, calculate_fuel_quadratic, find_optimal_position, find_optimal_position_naive};
 
     #[test]
     fn test_find_optimal_position_naive() {
@@ -0,0 +1,113 @@
+use std::collections::{HashSet};
+use std::convert::TryInto;
+
+use aoc_core::{Answer, Error, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(decode_displays(input).0.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(decode_displays(input).1.into())
+    }
+}
+
+/// Returns the amount of easy digits (1, 4, 7 and 8) and the sum of all decoded outputs
+fn decode_displays(input: &str) -> (usize, u32) {
+    let mut unique_count = 0;
+    let mut total_count = 0;
+
+    for line in input.lines() {
+        let mut digit_iterator = line.split("|").map(|x| {
+            x.split_whitespace().map(|y| {
+                y.chars().collect()
+            }).collect::<Vec<_>>()
+        });
+
+        let left_side_digits = digit_iterator.next().expect("Could not split line");
+        let right_side_digits = digit_iterator.next().expect("Could not split line");
+
+        let left_digit_buckets = find_unique_digits(&left_side_digits);
+        let right_digit_buckets = find_unique_digits(&right_side_digits);
+
+        let number_map = find_number_mappings(&left_digit_buckets).expect("Error in number mapping");
+
+        unique_count += right_digit_buckets[2].len() + right_digit_buckets[3].len()
+            + right_digit_buckets[4].len() + right_digit_buckets[7].len();
+
+        let digits_iter = right_side_digits.iter().map(|digit| {
+            number_map.iter().position(|&x| x == digit).unwrap() as u8
+        });
+
+        let count: u32 = digits_iter.fold(0, |x, y| {x * 10 + y as u32});
+        total_count += count;
+    }
+
+    (unique_count, total_count)
+}
+
+fn find_number_mappings<'a>(sample: &[Vec<&'a HashSet<char>>; 8]) -> Result<[&'a HashSet<char>; 10], &'static str> {
+    let mut number_map: [Option<&HashSet<char>>; 10] = [None; 10];
+    number_map[1] = Some(sample[2][0]);
+    number_map[7] = Some(sample[3][0]);
+    number_map[4] = Some(sample[4][0]);
+    number_map[8] = Some(sample[7][0]);
+
+    number_map[6] = Some(sample[6].iter().find(|x| {
+        !number_map[7].unwrap().is_subset(x)
+    }).ok_or("Six set not found")?);
+
+    number_map[9] = Some(sample[6].iter().find(|x| {
+        number_map[4].unwrap().is_subset(x)
+    }).ok_or("Nine set not found")?);
+
+    number_map[0] = Some(sample[6].iter().find(|x| {
+        **x != number_map[6].unwrap() && **x != number_map[9].unwrap()
+    }).ok_or("Zero set not found")?);
+
+    number_map[3] = Some(sample[5].iter().find(|x| {
+        number_map[1].unwrap().is_subset(x)
+    }).ok_or("Three set not found")?);
+
+    number_map[5] = Some(sample[5].iter().find(|x| {
+        x.is_subset((number_map[6]).unwrap())
+    }).ok_or("Five set not found")?);
+
+    number_map[2] = Some(sample[5].iter().find(|x| {
+        **x != number_map[3].unwrap() && **x != number_map[5].unwrap()
+    }).ok_or("Two set not found")?);
+
+    Ok(number_map.iter().map(|x| x.unwrap()).collect::<Vec<&HashSet<char>>>().as_slice().try_into().unwrap())
+}
+
+fn find_unique_digits(sample: &[HashSet<char>]) -> [Vec<&HashSet<char>>; 8] {
+    let mut segment_count: [Vec<&HashSet<char>>; 8] = Default::default();
+    for word in sample.iter() {
+        let length = word.len();
+        segment_count[length].push(word);
+    }
+    segment_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_unique_digits;
+
+    #[test]
+    fn count_test() {
+        let sample = ["fdgacbe", "cefdb", "cefbgd", "gcbe"]
+            .iter()
+            .map(|word| word.chars().collect())
+            .collect::<Vec<_>>();
+        let buckets = find_unique_digits(&sample);
+
+        assert_eq!(buckets[7].len(), 1);
+        assert_eq!(buckets[6].len(), 1);
+        assert_eq!(buckets[5].len(), 1);
+        assert_eq!(buckets[4].len(), 1);
+        assert!(buckets[2].is_empty());
+    }
+}
\ No newline at end of file
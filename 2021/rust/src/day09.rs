@@ -0,0 +1,97 @@
+use std::{collections::{BinaryHeap, HashSet}};
+
+use aoc_core::{parsed, Answer, Error, Solution};
+use aoc_util::grid::Grid;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+}
+
+fn part_one(input: &str) -> Result<u32, Error> {
+   let board = parse_input(input)?;
+
+   let low_points = board.get_low_points();
+   let total_risk_score: u32 = low_points.iter().map(|position| {
+      board.board[*position] as u32 + 1
+   }).sum();
+   Ok(total_risk_score)
+}
+
+fn part_two(input: &str) -> Result<usize, Error> {
+   let board = parse_input(input)?;
+
+   let mut basin_sizes: BinaryHeap<usize> = board.find_basins().iter().map(|x| {x.len()}).collect::<BinaryHeap<usize>>();
+   
+
+   Ok((0..3).map(|_| {
+      basin_sizes.pop().expect("Could not find enough basins")
+   }).product::<usize>())
+}
+
+fn parse_input(input: &str) -> Result<Board, Error> {
+   let board = Grid::parse(input, |chr| {
+      chr.to_digit(10).map(|digit| digit as u8)
+   })?;
+
+   Ok(Board{
+      board
+   })
+}
+
+type Position = (usize, usize);
+
+struct Board {
+   board: Grid<u8>
+}
+
+impl Board {
+   fn get_low_points(&self) -> Vec<Position> {
+      self.board.positions().filter(|position| {
+         self.is_low_point(position)
+      }).collect()
+   }
+
+   fn find_basins(&self) -> Vec<HashSet<Position>> {
+      let mut basins: Vec<HashSet<Position>> = vec!();
+      for low_point in self.get_low_points() {
+         let mut basin: HashSet<Position> = HashSet::new();
+         let mut queue: Vec<Position> = vec![low_point];
+
+         while let Some((x, y)) = queue.pop() {
+            
+
+            self.board.neighbours4(x, y).filter(|neighbour| {
+               self.board[*neighbour] != 9
+            }).for_each(|neighbour| {
+               if !basin.contains(&neighbour) {
+                  basin.insert(neighbour);
+                  queue.push(neighbour);
+               }
+            });
+         }
+
+         basins.push(basin);
+      }
+
+      basins
+   }
+
+   fn is_low_point(&self, &(x, y): &Position) -> bool {
+      let local_value = self.board[(x, y)];
+      self.board.neighbours4(x, y).all(|neighbour| {
+         self.board[neighbour] >= local_value
+      })
+   }
+}
@@ -1,9 +1,25 @@
-use std::error::Error;
+use aoc_core::{parsed, Answer, Error, Solution};
 
 const CHUNK_PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
 
-fn main() {
-    let results = parse_file("./10-input.txt").unwrap();
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+}
+
+fn part_one(input: &str) -> Result<u32, Error> {
+    let results = parse_input(input)?;
 
     let error_score = results.iter().filter_map(|x| {
         if let LineResult::Error((_expected, actual)) = x {
@@ -19,6 +35,12 @@ fn main() {
         }
     }).sum::<u32>();
 
+    Ok(error_score)
+}
+
+fn part_two(input: &str) -> Result<i64, Error> {
+    let results = parse_input(input)?;
+
     let mut completion_scores = results.iter().filter_map(|x| {
         if let LineResult::Incomplete(completion) = x {
             return Some(completion.iter().map(|char| {
@@ -36,15 +58,11 @@ fn main() {
 
     completion_scores.sort();
 
-    let completion_score = completion_scores[completion_scores.len()/2];
-
-    println!("Error score: {}", error_score);
-    println!("Completion score: {}", completion_score);
+    Ok(completion_scores[completion_scores.len()/2])
 }
 
-fn parse_file(filename: &str) -> Result<Vec<LineResult>, Box<dyn Error>> {
-    let file = std::fs::read_to_string(filename)?;
-    Ok(file.lines().map(|x| parse_line(x)).collect())
+fn parse_input(input: &str) -> Result<Vec<LineResult>, Error> {
+    Ok(input.lines().map(parse_line).collect())
 }
 
 enum LineResult {
@@ -0,0 +1,95 @@
+use std::{collections::{BTreeSet}, fmt::Display};
+
+use aoc_core::{parsed, Answer, Error, Solution};
+use aoc_util::grid::Grid;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(Board::parse(input).map(parsed))
+    }
+}
+
+fn part_one(input: &str) -> Result<u32, Error> {
+    let mut flash_count = 0;
+    let mut board = Board::parse(input)?;
+    for _generation in 0..100 {
+        flash_count += board.generation();
+    }
+    Ok(flash_count)
+}
+
+fn part_two(input: &str) -> Result<u32, Error> {
+
+    let mut board = Board::parse(input)?;
+    let mut i = 0;
+    loop {
+        i += 1;
+        if board.data.cells().len() == board.generation() as usize{
+            break;
+        }
+    };
+    Ok(i)
+}
+
+pub struct Board {
+    data: Grid<u8>,
+}
+
+impl Board {
+    pub fn parse(input: &str) -> Result<Board, Error> {
+        let data = Grid::parse(input, |char| {
+            char.to_digit(10).map(|digit| digit as u8)
+        })?;
+
+        Ok(Board {
+            data
+        })
+    }
+
+    pub fn generation(&mut self) -> u32 {
+        let mut to_flash: Vec<(usize, usize)> = Vec::new();
+        for (x, y) in self.data.positions() {
+            let octo = &mut self.data[(x, y)];
+            *octo += 1;
+            if *octo > 9 {
+                to_flash.push((x, y));
+            }
+        }
+
+        let mut flashed: BTreeSet<(usize, usize)> = BTreeSet::new();
+        while let Some((x, y)) = to_flash.pop() {
+            
+            let neighbours = self.data.neighbours8(x, y).collect::<Vec<_>>();
+            for (n_x, n_y) in neighbours {
+                self.data[(n_x, n_y)] += 1;
+                if self.data[(n_x, n_y)] > 9
+                    && ! to_flash.contains(&(n_x, n_y)) && ! flashed.contains(&(n_x, n_y)) {
+                        to_flash.push((n_x, n_y));
+                    }
+            }
+            flashed.insert((x, y));
+        }
+
+        for position in &flashed {
+            self.data[*position] = 0;
+        }
+
+        flashed.len() as u32
+    }
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.data)
+    }
+}
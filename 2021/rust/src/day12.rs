@@ -1,11 +1,35 @@
 use std::collections::{BTreeMap, BTreeSet};
-use std::error::Error;
 
-fn main() {
-    let graph = Graph::from_file("./12-input.txt").unwrap();
+use aoc_core::{parsed, Answer, Error, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(Graph::parse(input).map(parsed))
+    }
+}
+
+fn part_one(input: &str) -> Result<usize, Error> {
+    let graph = Graph::parse(input)?;
     let paths = graph.find_paths("start", "end");
 
-    println!("Path count: {}", paths.len());
+    Ok(paths.len())
+}
+
+fn part_two(input: &str) -> Result<usize, Error> {
+    let graph = Graph::parse(input)?;
+    let paths = graph.find_paths_with_duplicate("start", "end");
+
+    Ok(paths.len())
 }
 
 pub struct Graph {
@@ -13,17 +37,16 @@ pub struct Graph {
 }
 
 impl Graph {
-    pub fn from_file(path: &str) -> Result<Graph, Box<dyn Error>> {
-        let file = std::fs::read_to_string(path)?;
+    pub fn parse(input: &str) -> Result<Graph, Error> {
         let mut edges: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
 
-        file.lines().map(|line| {
+        input.lines().map(|line| {
             let parts = line.split('-').take(2).collect::<Vec<&str>>();
             (parts[0], parts[1])
 
         }).for_each(|(left, right)| {
-            for (ref origin, ref destination) in [(left, right), (right, left)].iter() {
-                match edges.get_mut(origin.clone()) {
+            for (origin, destination) in [(left, right), (right, left)].iter() {
+                match edges.get_mut(*origin) {
                     None => {
                         let mut new_set = BTreeSet::new();
                         new_set.insert(String::from(*destination));
@@ -49,18 +72,18 @@ impl Graph {
         self.find_path_with_duplicate_recursive(&Vec::new(), start, start, end, false)
     }
 
-    fn find_path_recursive(&self, old_path: &Vec<String>, current: &str, end: &str) -> Vec<Vec<String>> {
+    fn find_path_recursive(&self, old_path: &[String], current: &str, end: &str) -> Vec<Vec<String>> {
         if current == end {
-            let mut new_path = old_path.clone();
+            let mut new_path = old_path.to_vec();
             new_path.push(String::from(current));
-            return vec![new_path];
+            vec![new_path]
         } else {
             let mut path_collection :Vec<Vec<String>> = Vec::new();
             match Self::get_cave_type(current).unwrap() {
                 CaveType::SMALL => {
                     // TODO: &String can't be right
                     if ! old_path.contains(&String::from(current)) {
-                        let mut new_path = old_path.clone();
+                        let mut new_path = old_path.to_vec();
                         new_path.push(String::from(current));
                         for next in self.edges.get(current).unwrap() {
                             path_collection.extend(self.find_path_recursive(&new_path, next, end));
@@ -68,7 +91,7 @@ impl Graph {
                     }
                 },
                 CaveType::BIG => {
-                    let mut new_path = old_path.clone();
+                    let mut new_path = old_path.to_vec();
                     new_path.push(String::from(current));
                     for next in self.edges.get(current).unwrap() {
                         path_collection.extend(self.find_path_recursive(&new_path, next, end));
@@ -80,24 +103,24 @@ impl Graph {
         }
     }
 
-    fn find_path_with_duplicate_recursive(&self, old_path: &Vec<String>, current: &str, start: &str, end: &str, has_visited_twice: bool) -> Vec<Vec<String>> {
+    fn find_path_with_duplicate_recursive(&self, old_path: &[String], current: &str, start: &str, end: &str, has_visited_twice: bool) -> Vec<Vec<String>> {
         if current == end {
-            let mut new_path = old_path.clone();
+            let mut new_path = old_path.to_vec();
             new_path.push(String::from(current));
-            return vec![new_path];
+            vec![new_path]
         } else {
             let mut path_collection :Vec<Vec<String>> = Vec::new();
             match Self::get_cave_type(current).unwrap() {
                 CaveType::SMALL => {
                     // TODO: &String can't be right
                     if ! old_path.contains(&String::from(current)) {
-                        let mut new_path = old_path.clone();
+                        let mut new_path = old_path.to_vec();
                         new_path.push(String::from(current));
                         for next in self.edges.get(current).unwrap() {
                             path_collection.extend(self.find_path_with_duplicate_recursive(&new_path, next, start, end, has_visited_twice));
                         }
                     } else if ! has_visited_twice && current != start {
-                        let mut new_path = old_path.clone();
+                        let mut new_path = old_path.to_vec();
                         new_path.push(String::from(current));
                         for next in self.edges.get(current).unwrap() {
                             path_collection.extend(self.find_path_with_duplicate_recursive(&new_path, next, start, end, true));
@@ -105,7 +128,7 @@ impl Graph {
                     }
                 },
                 CaveType::BIG => {
-                    let mut new_path = old_path.clone();
+                    let mut new_path = old_path.to_vec();
                     new_path.push(String::from(current));
                     for next in self.edges.get(current).unwrap() {
                         path_collection.extend(self.find_path_with_duplicate_recursive(&new_path, next, start, end, has_visited_twice));
@@ -128,6 +151,7 @@ impl Graph {
     }
 }
 
+#[allow(clippy::upper_case_acronyms)]
 enum CaveType {
     SMALL,
     BIG
@@ -135,11 +159,12 @@ enum CaveType {
 
 #[cfg(test)]
 mod tests {
-    use crate::Graph;
+    use super::Graph;
+    use aoc_core::input::{example, puzzle};
 
     #[test]
     fn test_part_one_first_input() {
-        let graph = Graph::from_file("./12-first-example-input.txt").unwrap();
+        let graph = Graph::parse(&example(2021, 12, "first-example").unwrap()).unwrap();
         let paths = graph.find_paths("start", "end");
 
         assert_eq!(10, paths.len());
@@ -147,7 +172,7 @@ mod tests {
 
     #[test]
     fn test_part_one_second_input() {
-        let graph = Graph::from_file("./12-second-example-input.txt").unwrap();
+        let graph = Graph::parse(&example(2021, 12, "second-example").unwrap()).unwrap();
         let paths = graph.find_paths("start", "end");
 
         assert_eq!(19, paths.len());
@@ -155,7 +180,7 @@ mod tests {
 
     #[test]
     fn test_part_one_third_input() {
-        let graph = Graph::from_file("./12-third-example-input.txt").unwrap();
+        let graph = Graph::parse(&example(2021, 12, "third-example").unwrap()).unwrap();
         let paths = graph.find_paths("start", "end");
 
         assert_eq!(226, paths.len());
@@ -163,7 +188,7 @@ mod tests {
 
     #[test]
     fn test_part_one_puzzle_input() {
-        let graph = Graph::from_file("./12-input.txt").unwrap();
+        let graph = Graph::parse(&puzzle(2021, 12).unwrap()).unwrap();
         let paths = graph.find_paths("start", "end");
 
         assert_eq!(4573, paths.len());
@@ -171,7 +196,7 @@ mod tests {
 
     #[test]
     fn test_part_two_first_input() {
-        let graph = Graph::from_file("./12-first-example-input.txt").unwrap();
+        let graph = Graph::parse(&example(2021, 12, "first-example").unwrap()).unwrap();
         let paths = graph.find_paths_with_duplicate("start", "end");
 
         assert_eq!(36, paths.len());
@@ -179,7 +204,7 @@ mod tests {
 
     #[test]
     fn test_part_two_second_input() {
-        let graph = Graph::from_file("./12-second-example-input.txt").unwrap();
+        let graph = Graph::parse(&example(2021, 12, "second-example").unwrap()).unwrap();
         let paths = graph.find_paths_with_duplicate("start", "end");
 
         assert_eq!(103, paths.len());
@@ -187,7 +212,7 @@ mod tests {
 
     #[test]
     fn test_part_two_third_input() {
-        let graph = Graph::from_file("./12-third-example-input.txt").unwrap();
+        let graph = Graph::parse(&example(2021, 12, "third-example").unwrap()).unwrap();
         let paths = graph.find_paths_with_duplicate("start", "end");
 
         assert_eq!(3509, paths.len());
@@ -195,7 +220,7 @@ mod tests {
 
     #[test]
     fn test_part_two_puzzle_input() {
-        let graph = Graph::from_file("./12-input.txt").unwrap();
+        let graph = Graph::parse(&puzzle(2021, 12).unwrap()).unwrap();
         let paths = graph.find_paths_with_duplicate("start", "end");
 
         assert_eq!(117509, paths.len());
@@ -1,35 +1,57 @@
-use std::{collections::BTreeSet, error::Error};
+use std::{collections::BTreeSet};
+
+use aoc_core::{parsed, Answer, Error, Solution};
 
 const FILLED_CHAR: char = '█';
 const EMPTY_CHAR: char  = ' ';
 
-fn main() {
-    let (mut board, instructions) = parse_file("./13-input.txt").unwrap();
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+}
+
+fn part_one(input: &str) -> Result<usize, Error> {
+    let (mut board, instructions) = parse_input(input)?;
 
-    // First star
     board.perform_instruction(&instructions[0]);
-    println!("Result part one: {:?}", board.data.len());
+    Ok(board.data.len())
+}
+
+fn part_two(input: &str) -> Result<String, Error> {
+    let (mut board, instructions) = parse_input(input)?;
 
-    // Second star
     for instruction in &instructions {
         board.perform_instruction(instruction);
     }
 
-    println!("Result part 2:\n{}", board.print_board());
+    Ok(board.print_board())
 }
 
-fn parse_file(filename: &str) -> Result<(Board, Vec<(String, usize)>), Box<dyn Error>> {
-    let file = std::fs::read_to_string(filename)?;
-    let mut lines = file.lines();
+type Fold = (String, usize);
+type Dots = BTreeSet<(usize, usize)>;
+
+fn parse_input(input: &str) -> Result<(Board, Vec<Fold>), Error> {
+    let mut lines = input.lines();
 
-    let coords: BTreeSet<(usize, usize)>= lines.by_ref().take_while(|&x| x != "").map(|line| {
+    let coords: BTreeSet<(usize, usize)>= lines.by_ref().take_while(|&x| !x.is_empty()).map(|line| {
         let parts: Vec<usize> = line.split(",").map(|x| {x.parse::<usize>().expect("Could not parse as int")}).collect();
-        return (parts[0], parts[1])
+        (parts[0], parts[1])
     }).collect();
 
     let instructions: Vec<(String, usize)> = lines.by_ref().map(|line| {
         let mut parts = line.split(" ").nth(2).expect("Instruction too short").split("=");
-        return (String::from(parts.next().unwrap()), parts.next().unwrap().parse::<usize>().expect("Could not parse fold location to int"))
+        (String::from(parts.next().unwrap()), parts.next().unwrap().parse::<usize>().expect("Could not parse fold location to int"))
     }).collect();
 
     let board = Board { data: coords };
@@ -44,7 +66,7 @@ struct Board {
 impl Board {
     fn perform_instruction(&mut self, instruction: &(String, usize)) {
         if instruction.0 == "x" {
-            let (mut keep, fold): (BTreeSet<(usize, usize)>, BTreeSet<(usize, usize)>) = self.data.iter().partition(|(x, _y)| {
+            let (mut keep, fold): (Dots, Dots) = self.data.iter().partition(|(x, _y)| {
                 x < &instruction.1
             });
             fold.iter().map(|(x, y)| {
@@ -54,7 +76,7 @@ impl Board {
             });
             self.data = keep;
         } else {
-            let (mut keep, fold): (BTreeSet<(usize, usize)>, BTreeSet<(usize, usize)>) = self.data.iter().partition(|(_x, y)| {
+            let (mut keep, fold): (Dots, Dots) = self.data.iter().partition(|(_x, y)| {
                 y < &instruction.1
             });
             fold.iter().map(|(x, y)| {
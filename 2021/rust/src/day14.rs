@@ -1,19 +1,41 @@
-use std::{collections::HashMap, error::Error, convert::TryInto};
+use std::{collections::HashMap, convert::TryInto};
 
-fn main() {
-    let (start_polymer, char_map) = parse_file("./14-input.txt").unwrap();
+use aoc_core::{parsed, Answer, Error, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+}
+
+fn part_one(input: &str) -> Result<u64, Error> {
+    polymer_score(input, 10)
+}
+
+fn part_two(input: &str) -> Result<u64, Error> {
+    polymer_score(input, 40)
+}
+
+/// Difference between the most and least common element after growing the polymer
+fn polymer_score(input: &str, iterations: usize) -> Result<u64, Error> {
+    let (start_polymer, char_map) = parse_input(input)?;
 
     let grow_map: HashMap<[char; 2], [[char; 2]; 2]> = char_map.iter().map(|(start, &middle) | {
         (*start, [[start[0], middle], [middle, start[1]]])
     }).collect();
 
-    let letter_map = grow_polymer(&start_polymer, 10, &grow_map);
-    let answer = letter_map.values().max().unwrap() - letter_map.values().min().unwrap();
-    println!("answer part 1: {}", answer);
-
-    let letter_map = grow_polymer(&start_polymer, 40, &grow_map);
-    let answer = letter_map.values().max().unwrap() - letter_map.values().min().unwrap();
-    println!("answer part 2: {}", answer);
+    let letter_map = grow_polymer(&start_polymer, iterations, &grow_map);
+    Ok(letter_map.values().max().unwrap() - letter_map.values().min().unwrap())
 }
 
 fn grow_polymer(start_polymer: &[char], iterations: usize, grow_map: &HashMap<[char; 2], [[char; 2]; 2]>) -> HashMap<char, u64> {
@@ -24,7 +46,7 @@ fn grow_polymer(start_polymer: &[char], iterations: usize, grow_map: &HashMap<[c
         *chunk_entry += 1;
     }
     
-    chunk_count = replicate(iterations, chunk_count, &grow_map);
+    chunk_count = replicate(iterations, chunk_count, grow_map);
 
     let mut letter_map: HashMap<char, u64> = HashMap::new();
 
@@ -57,14 +79,15 @@ fn replicate(iterations: usize, start_chunk_count: HashMap<[char; 2], u64>, grow
         chunk_count = new_chunk_count;
     }
 
-    return chunk_count;
+    chunk_count
 }
 
-fn parse_file(filename: &str) -> Result<(Vec<char>, HashMap<[char; 2], char>), Box<dyn Error>> {
-    let data = std::fs::read_to_string(filename)?;
-    let mut lines = data.lines();
+type Rules = HashMap<[char; 2], char>;
+
+fn parse_input(input: &str) -> Result<(Vec<char>, Rules), Error> {
+    let mut lines = input.lines();
 
-    let start_polymer_data = lines.next().ok_or("No start polymer found in data file")?;
+    let start_polymer_data = lines.next().ok_or("No start polymer found in input file")?;
     let start_polymer: Vec<char> = start_polymer_data.chars().collect();
 
     lines.next().ok_or("No newline between polymer and mapping")?;
@@ -81,11 +104,12 @@ fn parse_file(filename: &str) -> Result<(Vec<char>, HashMap<[char; 2], char>), B
 
 #[cfg(test)]
 mod tests {
-    use crate::parse_file;
+    use super::parse_input;
+    use aoc_core::input::example;
 
     #[test]
     fn test_example_first_iteration() {
-        let (start_polymer, char_map) = parse_file("./14-example-input.txt").unwrap();
+        let (_start_polymer, _char_map) = parse_input(&example(2021, 14, "example").unwrap()).unwrap();
 
     }
 
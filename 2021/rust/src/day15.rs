@@ -0,0 +1,200 @@
+use aoc_core::{parsed, Answer, Error, Explanation, Solution};
+use aoc_util::{
+    grid::{self, Grid},
+    search::{astar, Graph, Path},
+};
+
+// Right now this works, but is clearly not a right architecture. I think this could be better solved by using composition.
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(Board::parse(input).map(parsed))
+    }
+
+    fn explain(&self, part: u8, input: &str) -> Option<Result<Explanation, Error>> {
+        Some(explain(part, input))
+    }
+}
+
+fn explain(part: u8, input: &str) -> Result<Explanation, Error> {
+    let path = match part {
+        1 => safest_path(&Board::parse(input)?),
+        _ => safest_path(&ComplicatedBoard::parse(input, 5, 5)?),
+    }
+    .ok_or("Could not reach the end")?;
+
+    Ok(path.explain(|_, &(x, y), risk| Some(format!("enter ({x}, {y}) with risk {risk}"))))
+}
+
+fn part_one(input: &str) -> Result<u32, Error> {
+    let board = Board::parse(input)?;
+    lowest_total_risk(&board)
+}
+
+fn part_two(input: &str) -> Result<u32, Error> {
+    let board = ComplicatedBoard::parse(input, 5, 5)?;
+    lowest_total_risk(&board)
+}
+
+fn lowest_total_risk(board: &impl PathFindable) -> Result<u32, Error> {
+    let path = safest_path(board).ok_or("Could not reach the end")?;
+    Ok(path.cost)
+}
+
+fn safest_path(board: &impl PathFindable) -> Option<Path<(usize, usize), u32>> {
+    let end = (board.width()-1, board.height()-1);
+    let route = Route { board, end };
+    astar(&route, [(0, 0)], |&coord| coord == end)
+}
+
+pub struct Board {
+    pub fields: Grid<u8>
+}
+
+pub struct ComplicatedBoard {
+    pub fields: Grid<u8>,
+
+    pub times_width: usize,
+    pub times_height: usize
+}
+
+fn parse_fields(input: &str) -> Result<Grid<u8>, Error> {
+    Ok(Grid::parse(input, |digit| {
+        digit.to_digit(10).map(|digit| digit as u8)
+    })?)
+}
+
+impl ComplicatedBoard {
+    pub fn parse(input: &str, times_width: usize, times_height: usize) -> Result<Self, Error> {
+        Ok(Self {
+            fields: parse_fields(input)?,
+            times_width,
+            times_height
+        })
+    }
+
+    fn width(&self) -> usize {
+        self.fields.width() * self.times_width
+    }
+
+    fn height(&self) -> usize {
+        self.fields.height() * self.times_height
+    }
+
+    fn field(&self, coord: (usize, usize)) -> u32 {
+        let width_add_score = (coord.0 / self.fields.width()) as u32;
+        let height_add_score = (coord.1 / self.fields.height()) as u32;
+        
+        let mapped_coord = (coord.0 % self.fields.width(), coord.1 % self.fields.height());
+
+        (self.fields[mapped_coord] as u32 + width_add_score + height_add_score - 1) % 9 + 1
+    }
+
+    pub fn to_string(&self) -> Result<String, Error> {
+        let output_iter = (0..(self.height())).map(|y|{
+            (0..(self.width())).map(|x|{
+                self.field((x, y)).to_string()
+            }).collect::<String>()
+        }).collect::<Vec<String>>();
+
+        Ok(output_iter.join("\n"))
+    }
+}
+
+impl Board {
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        Ok(Self {
+            fields: parse_fields(input)?
+        })
+    }
+
+    fn field(&self, coord: (usize, usize)) -> u32 {
+        self.fields[coord] as u32
+    }
+
+    pub fn to_string(&self) -> Result<String, Error> {
+        Ok(self.fields.to_string())
+    }
+}
+
+impl PathFindable for ComplicatedBoard {
+    fn width(&self) -> usize {
+        self.width()
+    }
+
+    fn height(&self) -> usize {
+        self.height()
+    }
+
+    fn field_cost(&self, coord: (usize, usize)) -> u32 {
+        self.field(coord)
+    }
+}
+
+impl PathFindable for Board {
+    fn width(&self) -> usize {
+        self.fields.width()
+    }
+
+    fn height(&self) -> usize {
+        self.fields.height()
+    }
+
+    fn field_cost(&self, coord: (usize, usize)) -> u32 {
+        self.field(coord)
+    }
+}
+
+trait PathFindable {
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+    fn field_cost(&self, coord: (usize, usize)) -> u32;
+
+    #[allow(dead_code)]
+    fn coordinate(&self, position: usize) -> (usize, usize) {
+        (position % self.width(), position / self.width())
+    }
+    
+    fn euclidian_distance(&self, start: (usize, usize), end: (usize, usize)) -> u32 {
+        (abs_diff(start.0, end.0) + abs_diff(start.1, end.1)) as u32
+    }
+
+    fn find_neighbours(&self, coord: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        grid::neighbours4(coord.0, coord.1, self.width(), self.height())
+    }
+}
+
+/// The paths over a board towards the end
+struct Route<'a, B> {
+    board: &'a B,
+    end: (usize, usize)
+}
+
+impl<B: PathFindable> Graph for Route<'_, B> {
+    type State = (usize, usize);
+    type Cost = u32;
+
+    fn successors(&self, &coord: &(usize, usize)) -> impl Iterator<Item = ((usize, usize), u32)> {
+        self.board.find_neighbours(coord).map(move |neighbour_coord| {
+            (neighbour_coord, self.board.field_cost(neighbour_coord))
+        })
+    }
+
+    fn heuristic(&self, &coord: &(usize, usize)) -> u32 {
+        self.board.euclidian_distance(coord, self.end)
+    }
+}
+
+fn abs_diff(x: usize, y: usize) -> usize {
+    x.abs_diff(y)
+}
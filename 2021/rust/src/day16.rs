@@ -1,14 +1,30 @@
-use std::{collections::HashMap, rc::Rc};
+use std::convert::TryFrom;
+
+use aoc_core::{Answer, Error, Solution};
 
 const WORD_LENGTH: usize = 128;
-fn main() {
-    let file = std::fs::read_to_string("./16-input.txt").unwrap();
-    let data = file.lines().next().unwrap();
-    // println!("Data: \"{}\"", data);
-    println!("Version sum: {}", sum_versions(data));
-    // print_packet(data);
-
-    println!("Result: {}", calculate_hex_string(data).unwrap())
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+}
+
+fn part_one(input: &str) -> Result<u32, Error> {
+    let data = input.lines().next().ok_or("No line in input")?;
+    Ok(sum_versions(data))
+}
+
+fn part_two(input: &str) -> Result<u64, Error> {
+    let data = input.lines().next().ok_or("No line in input")?;
+    let result = calculate_hex_string(data)?;
+    Ok(u64::try_from(result).expect("Result does not fit in 64 bits"))
 }
 
 fn calculate_hex_string(hex_string: &str) -> Result<u128, String> {
@@ -79,15 +95,15 @@ fn create_ast_from_messages(messages: Vec<Message>) -> Result<Node, String> {
 fn print_packet(data: &str) {
     let (cursor_start, byte_array) = parse_hex_string(data);
     let mut reader = Reader::from_slice(&byte_array, cursor_start);
-    read_packet(&mut reader, &mut PrintVisitor{});
+    read_packet(&mut reader, &mut PrintVisitor{}).expect("Could not read packet");
 }
 
 fn sum_versions(data: &str) -> u32{
     let (cursor_start, byte_array) = parse_hex_string(data);
     let mut version_visitor = VersionSumVisitor { sum: 0 };
     let mut reader = Reader::from_slice(&byte_array, cursor_start);
-    let res = read_packet(&mut reader, &mut version_visitor);
-    match res{_=>{}}
+    let _res = read_packet(&mut reader, &mut version_visitor);
+    {}
     version_visitor.sum
 }
 
@@ -280,7 +296,7 @@ impl CollectVisitor {
     }
 
     fn get_data(self) -> Vec<Message> {
-        return self.data;
+        self.data
     }
 }
 
@@ -317,9 +333,10 @@ impl MessagePacketType {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use aoc_core::input::puzzle;
+    
 
-    use crate::{parse_hex_string, Reader, PrintVisitor, WORD_LENGTH, read_packet, sum_versions, calculate_hex_string};
+    use super::{parse_hex_string, Reader, WORD_LENGTH, sum_versions, calculate_hex_string};
 
     #[test]
     fn test_parse_hex_string() {
@@ -415,8 +432,8 @@ mod tests {
 
     #[test]
     fn test_first_puzzle() {
-        let file = std::fs::read_to_string("./16-input.txt").unwrap();
-        let data = file.lines().next().unwrap();
+        let file = puzzle(2021, 16).unwrap();
+        let _data = file.lines().next().unwrap();
         // assert_eq!(883, sum_versions(data));
     }
 
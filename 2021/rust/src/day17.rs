@@ -1,15 +1,49 @@
 
-fn main() {
-    // target area: x=124..174, y=-123..-86
-    let target = ((124, 174), (-123, -86));
-    // let target = ((20, 30), (-10, -5));
-    let environment = Environment {
-        source: (0, 0),
-        target
+use aoc_core::{parsed, Answer, Error, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Environment, Error> {
+    let area = input
+        .trim()
+        .strip_prefix("target area: ")
+        .ok_or("Input does not describe a target area")?;
+    let (x_range, y_range) = area.split_once(", ").ok_or("Could not split target area")?;
+
+    let parse_range = |range: &str, axis: &str| -> Result<(i32, i32), Error> {
+        let (start, end) = range
+            .strip_prefix(axis)
+            .and_then(|range| range.split_once(".."))
+            .ok_or("Could not parse range")?;
+        Ok((start.parse()?, end.parse()?))
     };
 
-    println!("Answer part 1: {}", environment.highest_point());
-    println!("Answer part 2: {}", environment.get_valid_shots().len());
+    Ok(Environment {
+        source: (0, 0),
+        target: (parse_range(x_range, "x=")?, parse_range(y_range, "y=")?)
+    })
+}
+
+fn part_one(input: &str) -> Result<i32, Error> {
+    Ok(parse_input(input)?.highest_point())
+}
+
+fn part_two(input: &str) -> Result<usize, Error> {
+    Ok(parse_input(input)?.get_valid_shots().len())
 }
 
 struct Environment {
@@ -31,7 +65,7 @@ impl Environment {
     }
 
     fn valid_shot(&self, initial_velocity: (i32, i32)) -> bool {
-        let mut i = 0;
+        let _i = 0;
         let mut cur_x_velocity = initial_velocity.0;
         let mut cur_y_velocity = initial_velocity.1;
         let mut x_pos = 0;
@@ -74,7 +108,7 @@ impl Environment {
         let mut valid_shots = Vec::new();
         for x in (inverse_triangle_number(self.target.0.0 as u32) as i32)..=(self.target.0.1+1) {
             for y in (self.target.1.0)..(-self.target.1.0) {
-                if self.valid_shot(((x as i32, y as i32))) {
+                if self.valid_shot((x, y)) {
                     valid_shots.push((x, y));
                 }
             }
@@ -91,7 +125,7 @@ fn inverse_triangle_number(x: u32) -> u32 {
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
-    use crate::{Environment, inverse_triangle_number};
+    use super::{Environment, inverse_triangle_number};
 
     #[test]
     fn test_inverse_triangle_number() {
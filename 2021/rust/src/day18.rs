@@ -1,8 +1,20 @@
-fn main() {
-    // Part 1
-    let file = std::fs::read_to_string("./18-input.txt").unwrap();
-    let mut lines = file.lines();
-    let start_line = lines.next().unwrap();
+use aoc_core::{Answer, Error, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+}
+
+fn part_one(input: &str) -> Result<u32, Error> {
+    let mut lines = input.lines();
+    let start_line = lines.next().ok_or("No line in input")?;
     let mut tree = parse_line(start_line);
     
     for line in lines {
@@ -10,14 +22,15 @@ fn main() {
         tree = tree.add_reduce(&add_tree);
     }
 
-    println!("Answer part 1: {}", tree.magnitude());
+    Ok(tree.magnitude())
+}
 
-    // Part 2
-    let trees: Vec<Tree> = file.lines().map(parse_line).collect();
+fn part_two(input: &str) -> Result<u32, Error> {
+    let trees: Vec<Tree> = input.lines().map(parse_line).collect();
     let mut max_magnitude = 0;
     for x_tree in &trees {
         for y_tree in &trees {
-            let mut combined_tree = x_tree.add_reduce(&y_tree);
+            let mut combined_tree = x_tree.add_reduce(y_tree);
             let magnitude = combined_tree.magnitude();
             if magnitude > max_magnitude {
                 max_magnitude = magnitude;
@@ -25,7 +38,7 @@ fn main() {
         }
     }
 
-    println!("Answer part 2: {}", max_magnitude);
+    Ok(max_magnitude)
 }
 
 
@@ -142,7 +155,7 @@ impl Tree {
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse_line, Tree};
+    use super::{parse_line, Tree};
 
     fn assert_tree(left: Tree, right: Tree) {
         let iter = right.data.iter();
@@ -296,8 +309,8 @@ mod tests {
 
     #[test]
     fn test_addition1() {
-        let mut first_tree = parse_line("[[[[7,0],[7,7]],[[7,7],[7,8]]],[[[7,7],[8,8]],[[7,7],[8,7]]]]");
-        let mut second_tree = parse_line("[7,[5,[[3,8],[1,4]]]]");
+        let first_tree = parse_line("[[[[7,0],[7,7]],[[7,7],[7,8]]],[[[7,7],[8,8]],[[7,7],[8,7]]]]");
+        let second_tree = parse_line("[7,[5,[[3,8],[1,4]]]]");
         let check_tree = parse_line("[[[[[7,0],[7,7]],[[7,7],[7,8]]],[[[7,7],[8,8]],[[7,7],[8,7]]]],[7,[5,[[3,8],[1,4]]]]]");
 
         let new_tree = first_tree.add(&second_tree);
@@ -306,8 +319,8 @@ mod tests {
 
     #[test]
     fn test_addition2() {
-        let mut first_tree = parse_line("[[[[7,7],[7,8]],[[9,5],[8,7]]],[[[6,8],[0,8]],[[9,9],[9,0]]]]");
-        let mut second_tree = parse_line("[[2,[2,2]],[8,[8,1]]]");
+        let first_tree = parse_line("[[[[7,7],[7,8]],[[9,5],[8,7]]],[[[6,8],[0,8]],[[9,9],[9,0]]]]");
+        let second_tree = parse_line("[[2,[2,2]],[8,[8,1]]]");
         let check_tree = parse_line("[[[[[7,7],[7,8]],[[9,5],[8,7]]],[[[6,8],[0,8]],[[9,9],[9,0]]]],[[2,[2,2]],[8,[8,1]]]]");
 
         let new_tree = first_tree.add(&second_tree);
@@ -316,8 +329,8 @@ mod tests {
 
     #[test]
     fn test_addition3() {
-        let mut first_tree = parse_line("[[[[6,6],[6,6]],[[6,0],[6,7]]],[[[7,7],[8,9]],[8,[8,1]]]]");
-        let mut second_tree = parse_line("[2,9]");
+        let first_tree = parse_line("[[[[6,6],[6,6]],[[6,0],[6,7]]],[[[7,7],[8,9]],[8,[8,1]]]]");
+        let second_tree = parse_line("[2,9]");
         let check_tree = parse_line("[[[[[6,6],[6,6]],[[6,0],[6,7]]],[[[7,7],[8,9]],[8,[8,1]]]],[2,9]]");
 
         let new_tree = first_tree.add(&second_tree);
@@ -326,8 +339,8 @@ mod tests {
 
     #[test]
     fn test_add_reduce1() {
-        let mut first_tree = parse_line("[[[[6,6],[7,7]],[[0,7],[7,7]]],[[[5,5],[5,6]],9]]");
-        let mut second_tree = parse_line("[1,[[[9,3],9],[[9,0],[0,7]]]]");
+        let first_tree = parse_line("[[[[6,6],[7,7]],[[0,7],[7,7]]],[[[5,5],[5,6]],9]]");
+        let second_tree = parse_line("[1,[[[9,3],9],[[9,0],[0,7]]]]");
         let check_tree = parse_line("[[[[7,8],[6,7]],[[6,8],[0,8]]],[[[7,7],[5,0]],[[5,5],[5,6]]]]");
 
         let mut new_tree = first_tree.add(&second_tree);
@@ -337,8 +350,8 @@ mod tests {
 
     #[test]
     fn test_add_reduce2() {
-        let mut first_tree = parse_line("[[[[7,8],[6,7]],[[6,8],[0,8]]],[[[7,7],[5,0]],[[5,5],[5,6]]]]");
-        let mut second_tree = parse_line("[[[5,[7,4]],7],1]");
+        let first_tree = parse_line("[[[[7,8],[6,7]],[[6,8],[0,8]]],[[[7,7],[5,0]],[[5,5],[5,6]]]]");
+        let second_tree = parse_line("[[[5,[7,4]],7],1]");
         let check_tree = parse_line("[[[[7,7],[7,7]],[[8,7],[8,7]]],[[[7,0],[7,7]],9]]");
 
         let mut new_tree = first_tree.add(&second_tree);
@@ -348,8 +361,8 @@ mod tests {
 
     #[test]
     fn test_add_reduce3() {
-        let mut first_tree = parse_line("[[[[7,7],[7,7]],[[8,7],[8,7]]],[[[7,0],[7,7]],9]]");
-        let mut second_tree = parse_line("[[[[4,2],2],6],[8,7]]");
+        let first_tree = parse_line("[[[[7,7],[7,7]],[[8,7],[8,7]]],[[[7,0],[7,7]],9]]");
+        let second_tree = parse_line("[[[[4,2],2],6],[8,7]]");
         let check_tree = parse_line("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]");
 
         let mut new_tree = first_tree.add(&second_tree);
@@ -0,0 +1,10 @@
+use aoc_core::{Answer, Error, Solution, Unsolved};
+
+/// Day 19 has not been solved yet
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, _input: &str) -> Result<Answer, Error> {
+        Err(Unsolved.into())
+    }
+}
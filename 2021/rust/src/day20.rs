@@ -0,0 +1,147 @@
+use std::convert::TryInto;
+
+use aoc_core::{parsed, Answer, Error, Solution};
+use aoc_util::grid::Grid;
+
+// const OFFSET: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+// const SURROUNINGS: [(isize, isize); 9] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 0), (0, 1), (1, -1), (1, 0), (1, 1)];
+const SURROUNINGS: [(isize, isize); 9] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (0, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+#[allow(dead_code)]
+const FILLED_CHAR: char = '█';
+#[allow(dead_code)]
+const EMPTY_CHAR: char  = ' ';
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+}
+
+fn part_one(input: &str) -> Result<usize, Error> {
+    lit_pixels_after(input, 2)
+}
+
+fn part_two(input: &str) -> Result<usize, Error> {
+    lit_pixels_after(input, 50)
+}
+
+fn lit_pixels_after(input: &str, generations: usize) -> Result<usize, Error> {
+    let mut board = parse_input(input)?;
+    for _i in 0..generations {
+        board.enhance();
+    }
+    Ok(board.lit_count())
+}
+
+fn parse_input(input: &str) -> Result<Board, Error> {
+    let (alg_str, image) = input.split_once("\n\n").ok_or("No newline between algorithm and image")?;
+
+    let alg = alg_str.chars().map(|character|character == '#').collect::<Vec<bool>>();
+    let alg = alg.try_into().map_err(|_| "Could not convert algorithm into an array of size 512")?;
+
+    let data = Grid::parse(image, |character| match character {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    let board = Board {
+        data,
+        algorithm: alg,
+        background: false
+    };
+
+    Ok(board)
+}
+
+/// The image only stores the part that has been looked at, every pixel outside of it is the
+/// background.
+pub struct Board {
+    pub algorithm: [bool; 512],
+    pub data: Grid<bool>,
+    background: bool
+}
+
+impl Board {
+    pub fn enhance(&mut self) {
+        // Every enhancement can light up pixels one step further out
+        let data = Grid::from_fn(self.data.width() + 2, self.data.height() + 2, |x, y| {
+            self.algorithm[self.get_algorithm_index(x as isize - 1, y as isize - 1)]
+        });
+
+        self.background = self.algorithm[if self.background { 511 } else { 0 }];
+        self.data = data;
+    }
+
+    pub fn lit_count(&self) -> usize {
+        self.data.iter().filter(|&&lit| lit).count()
+    }
+
+    fn pixel(&self, x: isize, y: isize) -> bool {
+        if x < 0 || y < 0 {
+            return self.background;
+        }
+
+        *self.data.get(x as usize, y as usize).unwrap_or(&self.background)
+    }
+
+    fn get_algorithm_index(&self, x: isize, y: isize) -> usize {
+        self.surroundings(x, y).into_iter()
+            .map(|(x, y)| { self.pixel(x, y) })
+            .fold(0, |acc, cur| { 2*acc + if cur {1} else {0} })
+    }
+
+    pub fn surroundings(&self, x: isize, y: isize) -> Vec<(isize, isize)> {
+        SURROUNINGS.iter().map(|(dx, dy)| {
+            (x + *dx, y + *dy)
+        }).collect()
+    }
+
+    #[allow(dead_code)]
+    fn print_board(&self) -> String {
+        self.data.render(|&lit| if lit { FILLED_CHAR } else { EMPTY_CHAR })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_input;
+    use aoc_core::input::{example, puzzle};
+
+    #[test]
+    fn test_example_scan() {
+        let board = parse_input(&example(2021, 20, "example").unwrap()).unwrap();
+        assert_eq!(board.get_algorithm_index(2, 2), 34);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let mut board = parse_input(&puzzle(2021, 20).unwrap()).unwrap();
+        for _i in 0..2 {
+            board.enhance();
+        }
+        
+        assert_eq!(5291, board.lit_count());
+    }
+
+    #[test]
+    fn test_part_2() {
+        let mut board = parse_input(&puzzle(2021, 20).unwrap()).unwrap();
+        for _i in 0..50 {
+            board.enhance();
+        }
+        
+        assert_eq!(16665, board.lit_count());
+    }
+}
\ No newline at end of file
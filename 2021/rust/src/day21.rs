@@ -0,0 +1,168 @@
+use std::convert::TryFrom;
+
+use aoc_core::{parsed, Answer, Error, Solution};
+
+const BOARD_SIZE: u32 = 10;
+const WINNING_SCORE: u32 = 1000;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+}
+
+/// Starting positions of both players
+fn parse_input(input: &str) -> Result<[u32; 2], Error> {
+    let positions = input
+        .lines()
+        .map(|line| -> Result<u32, Error> {
+            let (_player, position) = line.split_once(": ").ok_or("Could not find starting position")?;
+            Ok(position.parse()?)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(<[u32; 2]>::try_from(positions).map_err(|_| "Expected exactly two players")?)
+}
+
+fn part_one(input: &str) -> Result<u32, Error> {
+    let [one, two] = parse_input(input)?;
+    let mut die = DiracDie::new(100);
+    let mut players = [Player::new(BOARD_SIZE, WINNING_SCORE, one),
+        Player::new(BOARD_SIZE, WINNING_SCORE, two)];
+
+    let mut winner_index: Option<usize> = None;
+    loop {
+        for (player_index, player) in players.iter_mut().enumerate() {
+            player.turn(&mut die);
+            if player.has_won() {
+                winner_index = Some(player_index);
+                break
+            }
+        }
+
+        if winner_index.is_some() {
+            break
+        }
+    }
+
+    let loser_index = if winner_index.unwrap() == 1 {0} else {1};
+    Ok(die.total_rolls * players[loser_index].score)
+}
+
+struct DiracDie {
+    sides: u32,
+    state: u32,
+    total_rolls: u32
+}
+
+impl DiracDie {
+    fn new(sides: u32) -> Self {
+       Self { sides, state: 0, total_rolls: 0 }
+    }
+
+    fn roll(&mut self) -> u32 {
+        self.total_rolls += 1;
+        let state = self.state + 1;
+        self.state = (self.state + 1) % self.sides;
+        state
+    }
+}
+
+struct Player {
+    score: u32,
+    winning_score: u32,
+    position: u32,
+    board_size: u32,
+}
+
+impl Player {
+    fn new(board_size: u32, winning_score: u32, starting_position: u32) -> Self {
+        Self {
+            score: 0,
+            winning_score,
+            position: starting_position,
+            board_size,
+        }
+    }
+
+    fn turn(&mut self, die: &mut DiracDie) {
+        let result = (0..3).map(|_| { die.roll() }).sum();
+        // println!("Result: {}", result);
+        self.make_move(result);
+    }
+
+    fn make_move(&mut self, move_size: u32) {
+        self.position = ((self.position + move_size -1) % self.board_size) +1;
+        self.score += self.position
+    }
+
+    fn has_won(&self) -> bool {
+        self.score >= self.winning_score
+    }
+}
+
+const QUANTUM_BOARD_SIZE: usize = 10;
+const MAX_SCORE: usize = 21;
+const DIE_CASES: [(usize, u128); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+fn part_two(input: &str) -> Result<u64, Error> {
+    let [one, two] = parse_input(input)?;
+    let mut state  = [[[[0u128; QUANTUM_BOARD_SIZE]; QUANTUM_BOARD_SIZE]; MAX_SCORE]; MAX_SCORE];
+    let mut current_player = 0;
+    state[0][0][one as usize - 1][two as usize - 1] = 1;
+    let mut player_wins = [0u128; 2];
+    loop {
+        let mut new_state  = [[[[0u128; QUANTUM_BOARD_SIZE]; QUANTUM_BOARD_SIZE]; MAX_SCORE]; MAX_SCORE];
+        let mut active_universes = 0u128;
+
+        for one_score in 0..MAX_SCORE {
+            for two_score in 0..MAX_SCORE {
+                for one_position in 0..QUANTUM_BOARD_SIZE {
+                    for two_position in 0..QUANTUM_BOARD_SIZE {
+                        let universe_count = state[one_score][two_score][one_position][two_position];
+
+                        let old_score = if current_player == 0 { one_score } else { two_score };
+                        let old_position = if current_player == 0 { one_position } else { two_position };
+
+                        for (move_increase, count) in DIE_CASES.iter() {
+                            // This can't be the most convenient way
+                            let new_position = (old_position + move_increase) % QUANTUM_BOARD_SIZE;
+                            let new_score = old_score + new_position+1;
+                            if new_score >= MAX_SCORE {
+                                player_wins[current_player] += count*universe_count;
+                            } else {
+                                active_universes += universe_count*count;
+                                if current_player == 0 {
+                                    new_state[new_score][two_score][new_position][two_position] += count*universe_count;
+                                } else {
+                                    new_state[one_score][new_score][one_position][new_position] += count*universe_count;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        state = new_state;
+
+        if active_universes == 0 {
+            break
+        }
+
+        current_player = (current_player + 1) % 2;
+    }
+
+    let most_wins = player_wins.iter().max().cloned().unwrap_or_default();
+    Ok(u64::try_from(most_wins).expect("Win count does not fit in 64 bits"))
+}
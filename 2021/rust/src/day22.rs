@@ -1,29 +1,26 @@
-use std::error::Error;
+use aoc_core::{parsed, Answer, Error, Solution};
 
-fn main() {
-    let mut data = parse_file("22-input.txt").unwrap();
-    data.reverse();
+pub struct Day;
 
-    let mut x_coords = data.iter()
-        .map(|x| {x.1.0})
-        .map(|x| { vec![x.0, x.1+1].into_iter() })
-        .flatten()
-        .collect::<Vec<_>>();
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
 
-    let mut y_coords = data.iter()
-        .map(|x| {x.1.1})
-        .map(|x| { vec![x.0, x.1+1].into_iter() })
-        .flatten()
-        .collect::<Vec<_>>();
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
 
-    let mut z_coords = data.iter()
-        .map(|x| {x.1.2})
-        .map(|x| { vec![x.0, x.1+1].into_iter() })
-        .flatten()
-        .collect::<Vec<_>>();
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+}
+
+fn part_one(input: &str) -> Result<usize, Error> {
+    let mut data = parse_input(input)?;
+    data.reverse();
 
-    let total_cuboids = reboot_reactor(&data, &mut x_coords, &mut y_coords, &mut z_coords);
-    println!("Part 2: Enabled cuboids: {}", total_cuboids);
+    let (mut x_coords, mut y_coords, mut z_coords) = boundaries(&data);
 
     x_coords.append(&mut vec![-50isize, 51]);
     y_coords.append(&mut vec![-50isize, 51]);
@@ -32,11 +29,40 @@ fn main() {
     y_coords.retain(|coord|{*coord >= -50 && *coord <= 51});
     z_coords.retain(|coord|{*coord >= -50 && *coord <= 51});
 
-    let total_cuboids = reboot_reactor(&data, &mut x_coords, &mut y_coords, &mut z_coords);
-    println!("Part 1: Enabled cuboids: {}", total_cuboids);
+    Ok(reboot_reactor(&data, &mut x_coords, &mut y_coords, &mut z_coords))
 }
 
-fn reboot_reactor(commands: &Vec<(bool, (Range, Range, Range))>, x_coords: &mut Vec<isize>, y_coords: &mut Vec<isize>, z_coords: &mut Vec<isize>) -> usize {
+fn part_two(input: &str) -> Result<usize, Error> {
+    let mut data = parse_input(input)?;
+    data.reverse();
+
+    let (mut x_coords, mut y_coords, mut z_coords) = boundaries(&data);
+
+    Ok(reboot_reactor(&data, &mut x_coords, &mut y_coords, &mut z_coords))
+}
+
+/// The coordinates at which any of the cuboids starts or stops along each of the axes
+fn boundaries(data: &[(bool, (Range, Range, Range))]) -> (Vec<isize>, Vec<isize>, Vec<isize>) {
+
+    let x_coords = data.iter()
+        .map(|x| {x.1.0})
+        .flat_map(|x| { vec![x.0, x.1+1].into_iter() })
+        .collect::<Vec<_>>();
+
+    let y_coords = data.iter()
+        .map(|x| {x.1.1})
+        .flat_map(|x| { vec![x.0, x.1+1].into_iter() })
+        .collect::<Vec<_>>();
+
+    let z_coords = data.iter()
+        .map(|x| {x.1.2})
+        .flat_map(|x| { vec![x.0, x.1+1].into_iter() })
+        .collect::<Vec<_>>();
+
+    (x_coords, y_coords, z_coords)
+}
+
+fn reboot_reactor(commands: &[(bool, (Range, Range, Range))], x_coords: &mut Vec<isize>, y_coords: &mut Vec<isize>, z_coords: &mut Vec<isize>) -> usize {
     x_coords.sort();
     y_coords.sort();
     z_coords.sort();
@@ -46,18 +72,18 @@ fn reboot_reactor(commands: &Vec<(bool, (Range, Range, Range))>, x_coords: &mut
 
     let mut total_size = 0;
 
-    for x in x_coords.windows(2).into_iter() {
+    for x in x_coords.windows(2) {
         let commands = commands.iter().filter(|&entry| {
             let x_range = entry.1.0;
             x[0] >= x_range.0 && x[0] <= x_range.1
         }).collect::<Vec<_>>();
-        for y in y_coords.windows(2).into_iter() {
+        for y in y_coords.windows(2) {
             let commands = commands.iter().filter(|&entry| {
                 let y_range = entry.1.1;
                 y[0] >= y_range.0 && y[0] <= y_range.1
             }).collect::<Vec<_>>();
-            for z in z_coords.windows(2).into_iter() {
-                for (command, (x_range, y_range, z_range)) in commands.iter() {
+            for z in z_coords.windows(2) {
+                for (command, (_x_range, _y_range, z_range)) in commands.iter() {
                     if z[0] >= z_range.0 && z[0] <= z_range.1 {
                         if *command {
                             total_size += (x[1] - x[0]) * (y[1] - y[0]) * (z[1] - z[0]);
@@ -73,11 +99,10 @@ fn reboot_reactor(commands: &Vec<(bool, (Range, Range, Range))>, x_coords: &mut
 }
 
 type Range = (isize, isize);
+type Cuboid = (Range, Range, Range);
 
-fn parse_file(filename: &str) -> Result<Vec<(bool, (Range, Range, Range))>, Box<dyn Error>> {
-    let file = std::fs::read_to_string(filename)?;
-
-    let output = file.lines().map(|line| {
+fn parse_input(input: &str) -> Result<Vec<(bool, Cuboid)>, Error> {
+    let output = input.lines().map(|line| {
         let mut parts = line.split(' ');
 
         let command = parts.next().expect("No command founnd") == "on";
@@ -1,13 +1,30 @@
-use std::{collections::BinaryHeap, fmt::Display};
+use std::{convert::TryFrom, fmt::Display};
 
+use aoc_core::{parsed, Answer, Error, Explanation, Solution};
+use aoc_util::search::{astar, Graph, Path};
 use itertools::{chain, iproduct};
 
-fn main() {
-    part_one();
-    part_two();
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(parse_input(input).map(parsed))
+    }
+
+    fn explain(&self, part: u8, input: &str) -> Option<Result<Explanation, Error>> {
+        Some(explain(part, input))
+    }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 enum Pod {
     A,
     B,
@@ -15,6 +32,20 @@ enum Pod {
     D,
 }
 
+impl TryFrom<char> for Pod {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'A' => Ok(Pod::A),
+            'B' => Ok(Pod::B),
+            'C' => Ok(Pod::C),
+            'D' => Ok(Pod::D),
+            _ => Err(format!("'{}' is not an amphipod", value)),
+        }
+    }
+}
+
 impl Pod {
     fn to_char(&self) -> char {
         match self {
@@ -35,50 +66,72 @@ impl Pod {
     }
 }
 
-fn part_one() {
-    let mut burrow: Burrow = Default::default();
-    burrow.set_pod(Coordinate::Room(0, 0), Pod::D);
-    burrow.set_pod(Coordinate::Room(0, 1), Pod::B);
-    burrow.set_pod(Coordinate::Room(1, 0), Pod::A);
-    burrow.set_pod(Coordinate::Room(1, 1), Pod::C);
-    burrow.set_pod(Coordinate::Room(2, 0), Pod::D);
-    burrow.set_pod(Coordinate::Room(2, 1), Pod::B);
-    burrow.set_pod(Coordinate::Room(3, 0), Pod::C);
-    burrow.set_pod(Coordinate::Room(3, 1), Pod::A);
-
-    let mut solver = Solver::new(burrow);
-    let cost = solver.solve().expect("Could not find solution").cost;
-    println!("Part 1: {:?}", cost);
+/// Rows of pods in the rooms, from the hallway down
+fn parse_input(input: &str) -> Result<Vec<[Pod; 4]>, Error> {
+    input
+        .lines()
+        .filter(|line| line.contains(|chr: char| chr.is_ascii_alphabetic()))
+        .map(|line| {
+            let pods = line
+                .chars()
+                .filter(|chr| chr.is_ascii_alphabetic())
+                .map(Pod::try_from)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(<[Pod; 4]>::try_from(pods).map_err(|_| "Expected four rooms")?)
+        })
+        .collect()
 }
 
-fn part_two() {
-    let mut burrow = Burrow::new(4);
-    burrow.set_pod(Coordinate::Room(0, 0), Pod::D);
-    burrow.set_pod(Coordinate::Room(1, 0), Pod::A);
-    burrow.set_pod(Coordinate::Room(2, 0), Pod::D);
-    burrow.set_pod(Coordinate::Room(3, 0), Pod::C);
-
-    burrow.set_pod(Coordinate::Room(0, 1), Pod::D);
-    burrow.set_pod(Coordinate::Room(1, 1), Pod::C);
-    burrow.set_pod(Coordinate::Room(2, 1), Pod::B);
-    burrow.set_pod(Coordinate::Room(3, 1), Pod::A);
-
-    burrow.set_pod(Coordinate::Room(0, 2), Pod::D);
-    burrow.set_pod(Coordinate::Room(1, 2), Pod::B);
-    burrow.set_pod(Coordinate::Room(2, 2), Pod::A);
-    burrow.set_pod(Coordinate::Room(3, 2), Pod::C);
-
-    burrow.set_pod(Coordinate::Room(0, 3), Pod::B);
-    burrow.set_pod(Coordinate::Room(1, 3), Pod::C);
-    burrow.set_pod(Coordinate::Room(2, 3), Pod::B);
-    burrow.set_pod(Coordinate::Room(3, 3), Pod::A);
-
-    let mut solver = Solver::new(burrow);
-    let cost = solver.solve().expect("Could not find solution").cost;
-    println!("Part 2: {:?}", cost);
+/// The rows go from the hallway down, just like the depth of a room
+fn fill_burrow(rows: &[[Pod; 4]]) -> Burrow {
+    let mut burrow = Burrow::new(rows.len());
+    for (depth, pods) in rows.iter().enumerate() {
+        for (room, pod) in pods.iter().enumerate() {
+            burrow.set_pod(Coordinate::Room(room, depth), pod.clone());
+        }
+    }
+    burrow
 }
 
-#[derive(Clone, Debug, PartialEq)]
+fn part_one(input: &str) -> Result<usize, Error> {
+    let burrow = fill_burrow(&parse_input(input)?);
+
+    Ok(solve(burrow).ok_or("Could not find solution")?.cost)
+}
+
+fn part_two(input: &str) -> Result<usize, Error> {
+    let burrow = unfold_burrow(input)?;
+
+    Ok(solve(burrow).ok_or("Could not find solution")?.cost)
+}
+
+fn unfold_burrow(input: &str) -> Result<Burrow, Error> {
+    let mut rows = parse_input(input)?;
+    if rows.is_empty() {
+        return Err("No rooms in input".into());
+    }
+    // The folded part of the diagram
+    rows.splice(1..1, [[Pod::D, Pod::C, Pod::B, Pod::A], [Pod::D, Pod::B, Pod::A, Pod::C]]);
+    Ok(fill_burrow(&rows))
+}
+
+fn explain(part: u8, input: &str) -> Result<Explanation, Error> {
+    let burrow = match part {
+        1 => fill_burrow(&parse_input(input)?),
+        _ => unfold_burrow(input)?,
+    };
+    let path = solve(burrow).ok_or("Could not find solution")?;
+
+    Ok(path.explain(|from, to, energy| {
+        let (start, end) = from
+            .get_possible_moves()
+            .find(|&(start, end)| from.clone().make_move(start, end).ok().as_ref() == Some(to))?;
+        let pod = from.get_pod(start)?.to_char();
+        Some(format!("move {pod} from {start:?} to {end:?} using {energy} energy"))
+    }))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 struct Burrow {
     hallway: [Option<Pod>; 7],
     rooms: [Vec<Option<Pod>>; 4],
@@ -86,6 +139,7 @@ struct Burrow {
 
 type Index = usize;
 type Depth = usize;
+type RoomCoordinate = (Index, Depth);
 type Position = usize;
 type Distance = usize;
 
@@ -131,7 +185,7 @@ impl Display for Burrow {
                 let coord = Self::get_coordinate(position);
                 let display_char = match coord {
                     Coordinate::Hallway(index) => {
-                        DisplayChar::from_option(&self.hallway[index as usize])
+                        DisplayChar::from_option(&self.hallway[index])
                     }
                     Coordinate::Room(_, _) => DisplayChar::Empty,
                 };
@@ -146,7 +200,7 @@ impl Display for Burrow {
                     let display_char = match coord {
                         Coordinate::Hallway(_) => DisplayChar::Border,
                         Coordinate::Room(room_num, _) => {
-                            DisplayChar::from_option(&self.rooms[room_num as usize][depth])
+                            DisplayChar::from_option(&self.rooms[room_num ][depth])
                         }
                     };
                     display_char.to_char()
@@ -294,7 +348,7 @@ impl Burrow {
         let room_coordinates = iproduct!((0..4), (0..height));
         let hallway_coordinates = 0..7;
 
-        let (filled_room_coords, empty_room_coords): (Vec<(Index, Depth)>, Vec<(Index, Depth)>) =
+        let (filled_room_coords, empty_room_coords): (Vec<RoomCoordinate>, Vec<RoomCoordinate>) =
             room_coordinates.partition(|coord| self.rooms[coord.0][coord.1].is_some());
 
         let filled_room_coords = filled_room_coords.into_iter().map(move |coord| {
@@ -353,7 +407,7 @@ impl Burrow {
         mut self,
         start: Coordinate,
         end: Coordinate,
-    ) -> Result<Self, Box<dyn std::error::Error>> {
+    ) -> Result<Self, Error> {
         let pod = self.get_pod(start).cloned().ok_or("Invalid move")?;
         self.del_pod(start);
         self.set_pod(end, pod);
@@ -438,67 +492,39 @@ impl Burrow {
     }
 }
 
-#[derive(Default)]
-struct Solver {
-    priority_queue: BinaryHeap<SolverState>,
-}
+/// Moves the pods until every pod is home, a move costs the energy the pod spends on it
+struct Solver;
 
-impl Solver {
-    fn new(begin_state: Burrow) -> Self {
-        let mut solver = Self::default();
-        solver.priority_queue.push(SolverState {
-            heuristic: Self::compute_heuristic(&begin_state),
-            burrow: begin_state,
-            cost: 0,
-        });
-        solver
-    }
+fn solve(burrow: Burrow) -> Option<Path<Burrow, usize>> {
+    astar(&Solver, [burrow], Burrow::is_complete)
+}
 
-    fn solve(&mut self) -> Option<SolverState> {
-        while let Some(next_state) = self.priority_queue.pop() {
-            let solved_state = self.step(next_state);
-            if solved_state.is_some() {
-                return solved_state;
-            }
-        }
-        None
-    }
+impl Graph for Solver {
+    type State = Burrow;
+    type Cost = usize;
 
-    fn step(&mut self, next_state: SolverState) -> Option<SolverState> {
-        let new_states = next_state
-            .burrow
+    fn successors(&self, burrow: &Burrow) -> impl Iterator<Item = (Burrow, usize)> {
+        burrow
             .get_possible_moves()
-            .filter_map(|(start, end)| {
-                let pod = next_state
-                    .burrow
+            .filter_map(move |(start, end)| {
+                let pod = burrow
                     .get_pod(start)
                     .expect("No pod found at start of move");
                 let cost = Burrow::get_distance(start, end) * pod.to_weight();
-                next_state
-                    .burrow
+                burrow
                     .clone()
                     .make_move(start, end)
                     .ok()
                     .map(|burrow| (burrow, cost))
             })
-            .map(|(new_burrow, cost)| {
-                let new_cost = next_state.cost + cost;
-                SolverState {
-                    heuristic: Solver::compute_heuristic(&new_burrow) + new_cost,
-                    burrow: new_burrow,
-                    cost: new_cost,
-                }
-            });
+    }
 
-        for new_state in new_states {
-            if new_state.burrow.is_complete() {
-                return Some(new_state);
-            }
-            self.priority_queue.push(new_state);
-        }
-        None
+    fn heuristic(&self, burrow: &Burrow) -> usize {
+        Solver::compute_heuristic(burrow)
     }
+}
 
+impl Solver {
     fn compute_heuristic(burrow: &Burrow) -> usize {
         let hallway_heuristic = burrow
             .hallway
@@ -537,37 +563,18 @@ impl Solver {
     }
 }
 
-#[derive(Debug)]
-struct SolverState {
-    heuristic: usize,
-    burrow: Burrow,
-    cost: usize,
-}
-
-impl Eq for SolverState {}
-
-impl PartialEq for SolverState {
-    fn eq(&self, other: &Self) -> bool {
-        self.heuristic.eq(&other.heuristic)
-    }
-}
-
-impl Ord for SolverState {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.heuristic.partial_cmp(&other.heuristic).unwrap()
-    }
-}
-
-impl PartialOrd for SolverState {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        other.heuristic.partial_cmp(&self.heuristic)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// The next burrow A* would explore after the given one, with the cost of the move
+    fn most_promising_move(burrow: &Burrow) -> (Burrow, usize) {
+        Solver
+            .successors(burrow)
+            .min_by_key(|(next_burrow, cost)| cost + Solver.heuristic(next_burrow))
+            .expect("No moves left")
+    }
+
     #[test]
     fn test_get_distance_hall_room() {
         let room = Coordinate::Room(0, 1);
@@ -807,7 +814,7 @@ mod tests {
     fn test_heuristic() {
         let mut burrow = Burrow::default();
         burrow.set_pod(Coordinate::Hallway(1), Pod::A);
-        assert_eq!(Solver::compute_heuristic(&burrow), 4);
+        assert_eq!(Solver::compute_heuristic(&burrow), 3);
 
         let mut burrow = Burrow::default();
         burrow.set_pod(Coordinate::Room(2, 1), Pod::C);
@@ -819,8 +826,7 @@ mod tests {
         let mut burrow = Burrow::default();
         burrow.set_pod(Coordinate::Hallway(1), Pod::A);
 
-        let mut solver = Solver::new(burrow);
-        let solved_state = solver.solve();
+        let solved_state = solve(burrow);
         let cost = solved_state.expect("Could not solve burrow").cost;
         assert_eq!(cost, 3);
     }
@@ -831,19 +837,8 @@ mod tests {
         burrow.set_pod(Coordinate::Hallway(2), Pod::A);
         burrow.set_pod(Coordinate::Hallway(4), Pod::C);
 
-        let mut solver = Solver::new(burrow);
-        let curr_state = solver
-            .priority_queue
-            .pop()
-            .expect("Tried to pop from empty queue");
-        let _ = solver.step(curr_state);
-
-        let next_state = solver
-            .priority_queue
-            .pop()
-            .expect("Tried to pop from empty queue");
-        let cost = next_state.cost;
-        println!("{}", next_state.burrow);
+        let (next_burrow, cost) = most_promising_move(&burrow);
+        println!("{}", next_burrow);
         assert_eq!(cost, 5);
     }
 
@@ -866,8 +861,7 @@ mod tests {
         burrow.set_pod(Coordinate::Hallway(2), Pod::A);
         burrow.set_pod(Coordinate::Room(0, 0), Pod::B);
 
-        let mut solver = Solver::new(burrow);
-        let solved_state = solver.solve();
+        let solved_state = solve(burrow);
         let cost = solved_state.expect("Could not solve burrow").cost;
         assert_eq!(cost, 73);
     }
@@ -877,38 +871,20 @@ mod tests {
         let mut burrow = Burrow::default();
         burrow.set_pod(Coordinate::Hallway(2), Pod::A);
         burrow.set_pod(Coordinate::Room(0, 0), Pod::B);
-        let mut solver = Solver::new(burrow);
-
-        let curr_state = solver
-            .priority_queue
-            .pop()
-            .expect("Tried to pop from empty queue");
-        assert_eq!(solver.step(curr_state), None);
-        assert_eq!(solver.priority_queue.len(), 2);
-
-        let curr_state = solver
-            .priority_queue
-            .pop()
-            .expect("Tried to pop from empty queue");
-        assert_eq!(curr_state.cost, 20);
-        assert_eq!(curr_state.heuristic, 64 + 20);
-
-        assert_eq!(solver.step(curr_state), None);
-        assert_eq!(solver.priority_queue.len(), 2);
-
-        let curr_state = solver
-            .priority_queue
-            .pop()
-            .expect("Tried to pop from empty queue");
-        assert_eq!(curr_state.cost, 23);
-        assert_eq!(curr_state.heuristic, 60 + 23);
-
-        let solved_state = solver.step(curr_state);
-        assert!(solved_state.is_some());
-
-        let solved_state = solved_state.expect("Solved state was not solved");
-        assert_eq!(solved_state.cost, 73);
-        assert_eq!(solved_state.heuristic, 0 + 73);
+        assert_eq!(Solver.successors(&burrow).count(), 2);
+        let (burrow, cost) = most_promising_move(&burrow);
+        assert_eq!(cost, 20);
+        assert_eq!(Solver.heuristic(&burrow), 53);
+
+        assert_eq!(Solver.successors(&burrow).count(), 1);
+        let (burrow, cost) = most_promising_move(&burrow);
+        assert_eq!(cost, 3);
+        assert_eq!(Solver.heuristic(&burrow), 50);
+
+        let (burrow, cost) = most_promising_move(&burrow);
+        assert!(burrow.is_complete());
+        assert_eq!(cost, 50);
+        assert_eq!(Solver.heuristic(&burrow), 0);
     }
 
     #[test]
@@ -917,8 +893,7 @@ mod tests {
         burrow.set_pod(Coordinate::Room(1, 0), Pod::A);
         burrow.set_pod(Coordinate::Room(0, 0), Pod::B);
 
-        let mut solver = Solver::new(burrow);
-        let solved_state = solver.solve();
+        let solved_state = solve(burrow);
         let cost = solved_state.expect("Could not solve burrow").cost;
         assert_eq!(cost, 57);
     }
@@ -949,12 +924,6 @@ mod tests {
         ];
         let mut alter_burrow = burrow.clone();
 
-        let mut solver = Solver::new(burrow);
-        let mut next_state = solver
-            .priority_queue
-            .pop()
-            .expect("Queue was empty after init");
-
         for next_move in fastests_moves {
             println!("{alter_burrow}");
             println!("{next_move:?}");
@@ -962,17 +931,15 @@ mod tests {
                 .make_move(next_move.0, next_move.1)
                 .expect("Could not make a legal move");
 
-            let solved = solver.step(next_state);
-            if solved.is_some() {
-                break;
-            }
-            next_state = solver
-                .priority_queue
-                .into_iter()
-                .find(|state| state.burrow == alter_burrow)
-                .expect("Expected move was not found");
-            solver.priority_queue = BinaryHeap::new();
+            assert!(
+                Solver
+                    .successors(&burrow)
+                    .any(|(next_burrow, _)| next_burrow == alter_burrow),
+                "Expected move was not found"
+            );
+            burrow = alter_burrow.clone();
         }
+        assert!(burrow.is_complete());
     }
 
     #[test]
@@ -1025,8 +992,7 @@ mod tests {
         burrow.set_pod(Coordinate::Room(2, 1), Pod::C);
         burrow.set_pod(Coordinate::Room(3, 1), Pod::A);
 
-        let mut solver = Solver::new(burrow);
-        let solved_state = solver.solve();
+        let solved_state = solve(burrow);
         let cost = solved_state.expect("Could not solve burrow").cost;
         assert_eq!(cost, 12521);
     }
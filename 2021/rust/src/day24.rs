@@ -1,31 +1,45 @@
 use std::collections::HashMap;
-use std::error::Error;
 
-fn main() {
-    let file = std::fs::read_to_string("./24-input.txt").expect("Could not parse file");
-    let instructions = parse_instructions(&file).unwrap();
+use aoc_core::{Answer, Error, Solution, Unsolved};
 
-    // println!("Instructions: {:?}", instructions);
-    // run(&instructions, get_input_from_stdin).unwrap();
+/// Day 24 has not been solved yet, the ALU can only check a given model number
+pub struct Day;
 
-    let inputs: Vec<i64> = vec![9, 1, 9, 7, 2, 3, 9, 5, 9, 1, 9, 9, 9, 3];
-    let inputs: Vec<i64> = vec![1, 3, 5, 7, 9, 2, 4, 6, 8, 9, 9, 9, 9, 9];
+impl Solution for Day {
+    fn part_one(&self, _input: &str) -> Result<Answer, Error> {
+        Err(Unsolved.into())
+    }
 
-    if let Ok(res) = run(&instructions, &inputs) {
-        if *res.get(&'z').unwrap() == 0 {
-            println!("Found number");
-        } else {
-            println!("Nope");
-        }
+    fn variants(&self) -> &'static [&'static str] {
+        &["check_model_number"]
+    }
+
+    /// Runs the ALU on [`MODEL_NUMBER`], like the program used to
+    fn variant(&self, name: &str, input: &str) -> Option<Result<Answer, Error>> {
+        (name == "check_model_number").then(|| {
+            let valid = is_valid_model_number(input, &MODEL_NUMBER);
+            Ok(if valid { "valid" } else { "invalid" }.into())
+        })
+    }
+}
+
+/// The model number the ALU was tried on
+const MODEL_NUMBER: [i64; 14] = [1, 3, 5, 7, 9, 2, 4, 6, 8, 9, 9, 9, 9, 9];
+
+fn is_valid_model_number(program: &str, inputs: &[i64]) -> bool {
+    let instructions = parse_instructions(program).unwrap();
+
+    if let Ok(res) = run(&instructions, inputs) {
+        *res.get(&'z').unwrap() == 0
     } else {
-        eprintln!("Something went wrong")
+        false
     }
 }
 
 fn run(
-    instructions: &Vec<Expression>,
-    input: &Vec<i64>,
-) -> Result<HashMap<char, i64>, Box<dyn Error>> {
+    instructions: &[Expression],
+    input: &[i64],
+) -> Result<HashMap<char, i64>, Error> {
     let mut variables: HashMap<char, i64> = HashMap::new();
     let mut read_index: usize = 0;
 
@@ -104,7 +118,8 @@ fn run(
     Ok(variables)
 }
 
-fn get_input_from_stdin() -> Result<i64, Box<dyn Error>> {
+#[allow(dead_code)]
+fn get_input_from_stdin() -> Result<i64, Error> {
     let mut input = String::new();
     println!("Please provide input: ");
     std::io::stdin().read_line(&mut input)?;
@@ -169,7 +184,7 @@ impl Value {
     fn get_value(&self, collection: &HashMap<char, i64>) -> i64 {
         match self {
             Value::Variable(var) => {
-                if let Some(val) = collection.get(&var) {
+                if let Some(val) = collection.get(var) {
                     *val
                 } else {
                     0
@@ -181,6 +196,7 @@ impl Value {
 }
 
 #[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)]
 enum Instruction {
     INP,
     ADD,
@@ -203,3 +219,17 @@ impl Instruction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{is_valid_model_number, MODEL_NUMBER};
+    use aoc_core::input::example;
+
+    #[test]
+    fn test_example_alu() {
+        // The example writes the bits of the first digit to w, x, y and z
+        let program = example(2021, 24, "example").unwrap();
+        assert!(!is_valid_model_number(&program, &MODEL_NUMBER));
+        assert!(is_valid_model_number(&program, &[2]));
+    }
+}
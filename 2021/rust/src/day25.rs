@@ -0,0 +1,92 @@
+use std::fmt::Display;
+
+// TOOD: Check if using an option with a reference would be faster, but I'm pretty sure it won't be
+#[derive(Clone, PartialEq)]
+enum Cucumber {
+    Empty,
+    Right,
+    Down,
+}
+
+struct Sea {
+    data: Grid<Cucumber>,
+}
+
+impl Sea {
+    fn new(data: Grid<Cucumber>) -> Self {
+        Self {
+            data: data.wrapping(),
+        }
+    }
+
+    fn next(&mut self) -> bool {
+        let moved_right = self.move_herd(Cucumber::Right, 1, 0);
+        let moved_down = self.move_herd(Cucumber::Down, 0, 1);
+
+        moved_right || moved_down
+    }
+
+    /// Moves every cucumber of the herd that is facing an empty spot at the same time.
+    fn move_herd(&mut self, herd: Cucumber, dx: isize, dy: isize) -> bool {
+        let moving = self
+            .data
+            .enumerate()
+            .filter(|&(_, cucumber)| *cucumber == herd)
+            .filter_map(|((x, y), _)| {
+                let next = self.data.offset(x, y, dx, dy)?;
+                (self.data[next] == Cucumber::Empty).then_some(((x, y), next))
+            })
+            .collect::<Vec<_>>();
+
+        for &(from, to) in &moving {
+            self.data[from] = Cucumber::Empty;
+            self.data[to] = herd.clone();
+        }
+
+        !moving.is_empty()
+    }
+}
+
+impl Display for Sea {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let data = self.data.render(|cucumber| match cucumber {
+            Cucumber::Empty => '.',
+            Cucumber::Right => '>',
+            Cucumber::Down => 'v',
+        });
+
+        writeln!(f, "{}", data)
+    }
+}
+
+use aoc_core::{Answer, Error, Solution};
+use aoc_util::grid::Grid;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+}
+
+fn part_one(input: &str) -> Result<usize, Error> {
+    let data = Grid::parse(input, |chr| match chr {
+        '>' => Some(Cucumber::Right),
+        'v' => Some(Cucumber::Down),
+        '.' => Some(Cucumber::Empty),
+        _ => None,
+    })?;
+
+    let mut sea = Sea::new(data);
+    let mut i = 0;
+    
+    loop {
+	// println!("After {i} steps:");
+	// println!("{}", sea);
+	i += 1;
+	if !sea.next() {
+	    break Ok(i);
+	}
+    }
+}
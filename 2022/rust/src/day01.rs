@@ -1,18 +1,24 @@
-use std::{fs::read_to_string, collections::BinaryHeap};
+use aoc_core::{Answer, Error, Solution};
+use std::collections::BinaryHeap;
 
-fn main() {
-    part_one();
-    part_two();
-}
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
 
-fn part_one() {
-    let data = read_to_string("./data/01.txt").expect("Could not read data file");
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+}
 
+fn part_one(data: &str) -> usize {
     let mut line_iter = data.lines();
 
     let mut max = 0;
     let max = loop {
-        let group = line_iter.by_ref().take_while(|line| !(&line).is_empty());
+        let group = line_iter.by_ref().take_while(|line| !line.is_empty());
 
 
         let sum = group
@@ -26,18 +32,16 @@ fn part_one() {
         }
     };
 
-    println!("{max}");
+    max
 }
 
-fn part_two() {
-    let data = read_to_string("./data/01.txt").expect("Could not read data file");
-
+fn part_two(data: &str) -> usize {
     let mut line_iter = data.lines();
 
     let mut calories_per_elve = BinaryHeap::new();
     
     loop {
-        let group = line_iter.by_ref().take_while(|line| !(&line).is_empty());
+        let group = line_iter.by_ref().take_while(|line| !line.is_empty());
 
 
         let sum = group
@@ -51,9 +55,7 @@ fn part_two() {
 	calories_per_elve.push(sum);
     };
 
-    let answer = (0..3usize).map(|i| {
+    (0..3usize).map(|_i| {
 	calories_per_elve.pop().expect("Not enough data")
-    }).sum::<usize>();
-
-    println!("{answer}");
+    }).sum::<usize>()
 }
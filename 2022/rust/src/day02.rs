@@ -1,15 +1,19 @@
-use std::{error::Error, fs::read_to_string};
+use aoc_core::{Answer, Error, Solution};
 
-fn main() {
-    part_one();
-    part_two();
-}
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
 
-fn part_one() {
-    let data = read_to_string("./data/02.txt").expect("Could not load datafile");
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+}
 
-    let score = data
-        .lines()
+fn part_one(data: &str) -> usize {
+    data.lines()
         .map(|line| {
             let (opponent, me) = line.split_once(' ').unwrap();
             let opponent: Shape = opponent.try_into().unwrap();
@@ -23,16 +27,11 @@ fn part_one() {
 
             brave_score as usize + win_score
         })
-        .sum::<usize>();
-
-    println!("{score}")
+        .sum::<usize>()
 }
 
-fn part_two() {
-    let data = read_to_string("./data/02.txt").expect("Could not load datafile");
-
-    let shapes = data
-        .lines()
+fn part_two(data: &str) -> usize {
+    data.lines()
         .map(|line| {
 	    let (opponent, me) = line.split_once(' ').unwrap();
             let opponent: Shape = opponent.try_into().unwrap();
@@ -47,12 +46,10 @@ fn part_two() {
             };
             brave_score as usize + win_score
 	})
-        .sum::<usize>();
-
-    println!("{shapes}")
+        .sum::<usize>()
 }
 
-fn part_one_map(chr: char) -> Result<Shape, Box<dyn Error>> {
+fn part_one_map(chr: char) -> Result<Shape, Error> {
     Ok(match chr {
         'X' => 'A',
         'Y' => 'B',
@@ -62,7 +59,7 @@ fn part_one_map(chr: char) -> Result<Shape, Box<dyn Error>> {
     .try_into()?)
 }
 
-fn part_two_map(strat: char, opponent: &Shape) -> Result<Shape, Box<dyn Error>> {
+fn part_two_map(strat: char, opponent: &Shape) -> Result<Shape, Error> {
     let diff = match strat {
         'X' => 2,
         'Y' => 0,
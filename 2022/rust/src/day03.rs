@@ -1,15 +1,21 @@
+use aoc_core::{Answer, Error, Solution};
 use itertools::Itertools;
-use std::{collections::HashSet, fs::read_to_string};
+use std::collections::HashSet;
 
-fn main() {
-    part_one();
-    part_two();
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
 }
 
-fn part_one() {
-    let sum = read_to_string("./data/03.txt")
-        .expect("Could not read data file")
-        .lines()
+fn part_one(data: &str) -> u32 {
+    data.lines()
         .map(|line| {
             let mut iter = line.chars();
             let part1 = iter.by_ref().take(line.len() / 2).collect::<HashSet<_>>();
@@ -26,15 +32,11 @@ fn part_one() {
                 (byte - b'A' + 27) as u32
             }
         })
-        .sum::<u32>();
-
-    println!("{sum}")
+        .sum::<u32>()
 }
 
-fn part_two() {
-    let sum = read_to_string("./data/03.txt")
-        .expect("Could not read data file")
-        .lines()
+fn part_two(data: &str) -> u32 {
+    data.lines()
         .chunks(3)
         .into_iter()
         .map(|chunk| -> char {
@@ -54,7 +56,5 @@ fn part_two() {
                 byte - b'A' + 27
             }) as u32
         })
-        .sum::<u32>();
-
-    println!("{sum}")
+        .sum::<u32>()
 }
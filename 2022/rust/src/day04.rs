@@ -0,0 +1,54 @@
+use aoc_core::{parsed, Answer, Error, Solution};
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        parsed(load_ranges(input));
+        Some(Ok(()))
+    }
+}
+
+fn load_ranges(data: &str) -> Vec<(u128, u128)> {
+    data.lines()
+        .filter_map(|line| line.split_once(','))
+        .filter_map(|(range_str1, range_str2)| {
+            Some((
+                range_to_mask(range_str1).ok()?,
+                range_to_mask(range_str2).ok()?,
+            ))
+        }).collect()
+}
+
+fn part_one(data: &str) -> usize {
+    load_ranges(data)
+        .iter()
+        .filter(|(range1, range2)| {
+            let union = range1 | range2;
+            union == *range1 || union == *range2
+        })
+        .count()
+}
+
+fn part_two(data: &str) -> usize {
+    load_ranges(data)
+        .iter()
+        .filter(|(range1, range2)| *range1 & *range2 != 0)
+        .count()
+}
+
+fn range_to_mask(range: &str) -> Result<u128, Box<dyn std::error::Error>> {
+    let (lower_bound, upper_bound) = range.split_once('-').ok_or("Could not find range")?;
+    let (lower_bound, upper_bound) = (lower_bound.parse::<usize>()?, upper_bound.parse::<usize>()?);
+
+    let mask = 0u128;
+
+    Ok((lower_bound..=upper_bound).fold(mask, |mask, order| mask | 1 << order))
+}
@@ -1,4 +1,5 @@
-use std::{collections::VecDeque, fs::read_to_string};
+use aoc_core::{Answer, Error, Solution};
+use std::collections::VecDeque;
 
 use itertools::Itertools;
 
@@ -35,14 +36,20 @@ impl TryFrom<&str> for Instruction {
     }
 }
 
-fn main() {
-    part_one();
-    part_two();
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
 }
 
-fn part_one() {
-    let inp = read_to_string("./data/05.txt").expect("Could not load data file");
-    let mut lines = inp.lines();
+fn part_one(data: &str) -> String {
+    let mut lines = data.lines();
 
     let mut piles: [VecDeque<char>; PILE_COUNT] = Default::default();
 
@@ -65,16 +72,11 @@ fn part_one() {
 	piles[instruction.to].extend(&mut grabbed.into_iter().rev());
     }
 
-    print!("Part one: ");
-    for pile in &piles {
-	print!("{}", pile.iter().last().unwrap())
-    }
-    print!("\n");
+    piles.iter().map(|pile| pile.iter().last().unwrap()).collect()
 }
 
-fn part_two() {
-    let inp = read_to_string("./data/05.txt").expect("Could not load data file");
-    let mut lines = inp.lines();
+fn part_two(data: &str) -> String {
+    let mut lines = data.lines();
 
     let mut piles: [VecDeque<char>; PILE_COUNT] = Default::default();
 
@@ -97,9 +99,5 @@ fn part_two() {
 	piles[instruction.to].append(&mut grabbed);
     }
 
-    print!("Part two: ");
-    for pile in &piles {
-	print!("{}", pile.iter().last().unwrap())
-    }
-    print!("\n");
+    piles.iter().map(|pile| pile.iter().last().unwrap()).collect()
 }
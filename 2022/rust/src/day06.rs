@@ -1,11 +1,18 @@
-use std::fs::read_to_string;
+use aoc_core::{Answer, Error, Solution};
 use std::hash::Hash;
 
 use itertools::Itertools;
 
-fn main() {
-    part_one();
-    part_two();
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
 }
 
 fn get_unique_sequence_index<T>(sequence: &[T], win_len: usize) -> Option<usize>
@@ -20,21 +27,16 @@ where
     )
 }
 
-fn part_one() {
-    let data = read_to_string("./data/06.txt").expect("Could not read datafile");
+fn part_one(data: &str) -> usize {
     let mut lines = data.lines();
     let line = lines.next().unwrap().chars().collect::<Vec<_>>();
 
-    println!("Part one: {}", get_unique_sequence_index(&line, 4).unwrap())
+    get_unique_sequence_index(&line, 4).unwrap()
 }
 
-fn part_two() {
-    let data = read_to_string("./data/06.txt").expect("Could not read datafile");
+fn part_two(data: &str) -> usize {
     let mut lines = data.lines();
     let line = lines.next().unwrap().chars().collect::<Vec<_>>();
 
-    println!(
-        "Part two: {}",
-        get_unique_sequence_index(&line, 14).unwrap()
-    )
+    get_unique_sequence_index(&line, 14).unwrap()
 }
@@ -1,8 +1,24 @@
-use std::{fs::read_to_string, path::{PathBuf, Path}, collections::HashMap};
+use aoc_core::{parsed, Answer, Error, Solution};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
 
-fn main() {
-    part_one();
-    part_two();
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        parsed(parse_files(input));
+        Some(Ok(()))
+    }
 }
 
 struct FileEntry {
@@ -44,10 +60,8 @@ fn parse_files(input: &str) -> Vec<FileEntry> {
     entries
 }
 
-fn part_one() {
-    let data = read_to_string("data/07.txt").expect("No datafile");
-
-    let entries = parse_files(&data);
+fn part_one(data: &str) -> usize {
+    let entries = parse_files(data);
 
     let mut directories: HashMap<&Path, usize> = HashMap::new();
     for file in &entries {
@@ -56,20 +70,14 @@ fn part_one() {
 	}
     }
 
-    let answer = directories.iter().filter_map(|(_dir, size)| {
-	(*size <= 100_000).then_some(size)
-    }).sum::<usize>();
-
-    println!("{answer}")
+    directories.values().filter(|size| **size <= 100_000).sum::<usize>()
 }
 
-fn part_two() {
-    let data = read_to_string("data/07.txt").expect("No datafile");
-
+fn part_two(data: &str) -> usize {
     let fs_size = 70_000_000;
     let needed_space = 30_000_000;
 
-    let entries = parse_files(&data);
+    let entries = parse_files(data);
 
     let mut directories: HashMap<&Path, usize> = HashMap::new();
     for file in &entries {
@@ -81,9 +89,6 @@ fn part_two() {
     let used_space = directories.get(&Path::new("/")).expect("Could not find size of root directory");
     let free_space = fs_size - used_space;
 
-    let answer = directories.iter().filter_map(|(_dir, size)| {
-	(*size >= needed_space - free_space).then_some(size)
-    }).min().expect("Could not find optimal directory to delete");
-
-    println!("{answer}")
+    *directories.values().filter(|size| **size >= needed_space - free_space)
+	.min().expect("Could not find optimal directory to delete")
 }
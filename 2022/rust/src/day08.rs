@@ -0,0 +1,154 @@
+use aoc_core::{parsed, Answer, Error, Solution};
+use aoc_util::grid::Grid;
+use std::collections::HashSet;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(Forest::parse(input).map(parsed))
+    }
+}
+
+struct Forest {
+    data: Grid<u8>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Direction {
+    Down,
+    Left,
+    Up,
+    Right,
+}
+
+impl Direction {
+    fn to_offset(self) -> (isize, isize) {
+        match self {
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+        }
+    }
+
+    fn first_row(&self, width: usize, height: usize) -> Vec<usize> {
+        match self {
+            Direction::Down => (0..width).collect(),
+            Direction::Left => (0..height).map(|x| x * width + width - 1).collect(),
+            Direction::Up => (0..width).map(|x| x + height * (width - 1)).collect(),
+            Direction::Right => (0..height).map(|x| x * width).collect(),
+        }
+    }
+
+    fn iter() -> impl Iterator<Item = Direction> {
+        [
+            Direction::Down,
+            Direction::Left,
+            Direction::Up,
+            Direction::Right,
+        ]
+        .iter()
+        .copied()
+    }
+}
+
+impl Forest {
+    fn parse(data: &str) -> Result<Self, Error> {
+        let forest = Grid::parse(data, |chr| chr.to_digit(10).map(|height| height as u8))
+            .map_err(|err| format!("Tree heights should be digits: {err}"))?;
+
+        Ok(Self { data: forest })
+    }
+
+    fn highest_trees_from_direction(&self, direction: Direction) -> HashSet<(usize, usize)> {
+        let (dx, dy) = direction.to_offset();
+        let first_row = direction.first_row(self.data.width(), self.data.height());
+
+        let mut highest_trees = HashSet::new();
+
+        for start_index in first_row {
+            let (x, y) = self.data.coord(start_index);
+            let mut highest = self.data[(x, y)];
+            highest_trees.insert((x, y));
+            for position in self.data.ray(x, y, dx, dy) {
+                if self.data[position] > highest {
+                    highest_trees.insert(position);
+                    highest = self.data[position];
+                }
+            }
+        }
+
+        highest_trees
+    }
+}
+
+fn part_one(data: &str) -> Result<usize, Error> {
+    let forest = Forest::parse(data)?;
+    let mut total = HashSet::new();
+    for direction in Direction::iter() {
+        total.extend(forest.highest_trees_from_direction(direction).drain());
+    }
+
+    Ok(total.len())
+}
+
+fn part_two(data: &str) -> Result<usize, Error> {
+    let forest = Forest::parse(data)?;
+    Ok(forest
+        .data
+        .enumerate()
+        .map(|((x, y), height)| {
+            let mut scenic_score = 1;
+
+            for direction in Direction::iter() {
+                let (dx, dy) = direction.to_offset();
+                let mut visible_trees = 0;
+                for position in forest.data.ray(x, y, dx, dy) {
+                    visible_trees += 1;
+                    if forest.data[position] >= *height {
+                        break;
+                    }
+                }
+                scenic_score *= visible_trees;
+            }
+
+            scenic_score
+        })
+        .max()
+        .ok_or("Could not find best scenic score")?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_row_up() {
+        let first_row = Direction::Up.first_row(10, 10);
+        assert_eq!(first_row, [90, 91, 92, 93, 94, 95, 96, 97, 98, 99])
+    }
+    #[test]
+    fn test_first_row_down() {
+        let first_row = Direction::Down.first_row(10, 10);
+        assert_eq!(first_row, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9])
+    }
+    #[test]
+    fn test_first_row_right() {
+        let first_row = Direction::Right.first_row(10, 10);
+        assert_eq!(first_row, [0, 10, 20, 30, 40, 50, 60, 70, 80, 90])
+    }
+    #[test]
+    fn test_first_row_left() {
+        let first_row = Direction::Left.first_row(10, 10);
+        assert_eq!(first_row, [9, 19, 29, 39, 49, 59, 69, 79, 89, 99])
+    }
+}
@@ -1,7 +1,7 @@
-use std::{
-    collections::{BTreeSet, HashSet},
-    fs::read_to_string,
-};
+use aoc_core::{Answer, Error, Solution};
+use aoc_util::coord::Coord;
+
+use std::collections::{BTreeSet, HashSet};
 
 use nom::{
     branch::alt, bytes::complete::tag, character::complete, sequence::separated_pair, IResult,
@@ -9,60 +9,18 @@ use nom::{
 
 use nom_supreme::ParserExt;
 
-#[derive(Clone, Copy, Ord, PartialOrd, PartialEq, Eq, Debug, Hash)]
-struct Coord {
-    x: i64,
-    y: i64,
-}
-
-impl std::ops::Add for Coord {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
-}
-
-impl std::ops::AddAssign for Coord {
-    fn add_assign(&mut self, rhs: Self) {
-        *self = *self + rhs
-    }
-}
-
-impl std::ops::Sub for Coord {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
-    }
-}
+pub struct Day;
 
-impl From<(i64, i64)> for Coord {
-    fn from(val: (i64, i64)) -> Self {
-        Self { x: val.0, y: val.1 }
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
     }
-}
 
-impl Default for Coord {
-    fn default() -> Self {
-        Self { x: 0, y: 0 }
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
     }
 }
 
-fn main() {
-    let data = read_to_string("./data/09.txt").expect("Could not read data file");
-    let answer = part_one(&data);
-    println!("Part one: {answer}");
-    let answer = part_two(&data);
-    println!("Part two: {answer}");
-}
-
 fn part_one(data: &str) -> usize {
     let instructions: Vec<Instruction> = data
         .lines()
@@ -89,7 +47,7 @@ fn part_one(data: &str) -> usize {
                 break;
             }
 
-            let mut new_tail = tail.clone();
+            let mut new_tail = tail;
 
             if rel_x >= 1 {
                 new_tail.x += rel.x / rel_x;
@@ -125,7 +83,7 @@ fn part_two(data: &str) -> usize {
 
         knots[0] += rel;
 
-        'outer: loop {
+        loop {
 	    let mut moved: [bool; ROPE_LEN] = [false; ROPE_LEN];
             for i in 1..ROPE_LEN {
                 let lead_knot = knots[i - 1];
@@ -134,7 +92,7 @@ fn part_two(data: &str) -> usize {
                 let rel_x = rel.x.abs();
                 let rel_y = rel.y.abs();
 
-                let mut new_knot = knot.clone();
+                let mut new_knot = knot;
 		if rel_x <= 1 && rel_y <= 1 {
 		    continue;
 		}
@@ -195,8 +153,7 @@ impl TryFrom<&str> for Instruction {
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let (direction, distance) =
-            separated_pair(parse_direction, complete::char(' '), complete::i64)(value)
-                .or_else(|_| Err("Could not parse instruction"))?
+            separated_pair(parse_direction, complete::char(' '), complete::i64)(value).map_err(|_| "Could not parse instruction")?
                 .1;
 
         Ok(Instruction {
@@ -218,6 +175,7 @@ fn parse_direction(input: &str) -> IResult<&str, Direction> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::input::example;
 
     #[test]
     fn test_parse_instruction() {
@@ -233,19 +191,19 @@ mod tests {
     #[test]
     fn test_part_one() {
         let data =
-            read_to_string("./data/09-example.txt").expect("Could not read example data file");
+            example(2022, 9, "example").expect("Could not read example data file");
         assert_eq!(part_one(&data), 13);
     }
     #[test]
     fn test_part_two() {
         let data =
-            read_to_string("./data/09-example.txt").expect("Could not read example data file");
+            example(2022, 9, "example").expect("Could not read example data file");
         assert_eq!(part_two(&data), 1);
     }
     #[test]
     fn test_part_two_larger() {
         let data =
-            read_to_string("./data/09-example2.txt").expect("Could not read example data file");
+            example(2022, 9, "example2").expect("Could not read example data file");
         assert_eq!(part_two(&data), 36);
     }
 }
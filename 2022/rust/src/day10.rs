@@ -1,7 +1,7 @@
 // extern crate nom;
 
+use aoc_core::{Answer, Error, Solution};
 use std::fmt::Display;
-use std::fs::read_to_string;
 
 use nom::branch::alt;
 use nom::bytes::complete::tag;
@@ -14,18 +14,23 @@ use nom::error::ErrorKind;
 use nom::sequence::tuple;
 use nom::IResult;
 
-fn main() {
-    part_one();
-    part_two();
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
 }
 
-fn part_one() {
+fn part_one(data: &str) -> i32 {
     let mut cycle = 0;
     let mut x = 1;
     let mut acc = 0;
 
-    let data = read_to_string("./data/10.txt").expect("Could not read datafile");
-
     let instructions = data
         .lines()
         .filter_map(|line| parse_instruction(line).ok())
@@ -51,16 +56,16 @@ fn part_one() {
         }
     }
 
-    println!("{acc}");
+    acc
 }
 
-struct CRT {
+struct Crt {
     data: Vec<bool>,
     height: usize,
     width: usize,
 }
 
-impl CRT {
+impl Crt {
     fn new(width: usize, height: usize) -> Self {
         Self {
             data: vec![false; width * height],
@@ -69,6 +74,7 @@ impl CRT {
         }
     }
 
+    #[allow(dead_code)]
     fn set_pixel(&mut self, x: usize, y: usize) {
         self.data[y * self.width + x] = true;
     }
@@ -78,13 +84,13 @@ impl CRT {
     }
 }
 
-impl Display for CRT {
+impl Display for Crt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for y in 0..self.height {
             for x in 0..self.width {
                 write!(f, "{}", if self.get_pixel(x, y) { '#' } else { ' ' })?;
             }
-            writeln!(f, "")?;
+            writeln!(f)?;
         }
 
         write!(f, "")
@@ -123,17 +129,15 @@ impl<T> RingBuf<T> {
     }
 }
 
-fn part_two() {
+fn part_two(data: &str) -> String {
     let mut cycle = 0;
 
-    let data = read_to_string("./data/10.txt").expect("Could not read datafile");
-
     let mut sprite = RingBuf::new(40, false);
     sprite.set(0, true);
     sprite.set(1, true);
     sprite.set(2, true);
 
-    let mut crt = CRT::new(40, 6);
+    let mut crt = Crt::new(40, 6);
 
     let instructions = data
         .lines()
@@ -160,7 +164,7 @@ fn part_two() {
         }
     }
 
-    println!("{crt}");
+    crt.to_string()
 }
 
 #[derive(Debug, PartialEq)]
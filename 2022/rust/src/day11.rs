@@ -1,18 +1,24 @@
-use std::{collections::VecDeque, fs::read_to_string};
+use aoc_core::{Answer, Error, Solution};
+use std::collections::VecDeque;
 
 use nom::{
     branch::alt, bytes::complete::tag, character::complete::digit1, combinator::map,
     sequence::tuple, IResult,
 };
 
-fn main() {
-    part_one();
-    part_two();
-}
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
 
-fn part_one() {
-    let data = read_to_string("./data/11.txt").expect("Could not read datafile");
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+}
 
+fn part_one(data: &str) -> usize {
     let segments = data.split("\n\n");
 
     let mut monkeys = segments
@@ -42,13 +48,10 @@ fn part_one() {
         }
     }
     inspection_count.sort();
-    let shenanigans = inspection_count.iter().rev().take(2).product::<usize>();
-    println!("{shenanigans}")
+    inspection_count.iter().rev().take(2).product::<usize>()
 }
 
-fn part_two() {
-    let data = read_to_string("./data/11.txt").expect("Could not read datafile");
-
+fn part_two(data: &str) -> usize {
     let segments = data.split("\n\n");
 
     let mut monkeys = segments
@@ -80,8 +83,7 @@ fn part_two() {
         }
     }
     inspection_count.sort();
-    let shenanigans = inspection_count.iter().rev().take(2).product::<usize>();
-    println!("{shenanigans}")
+    inspection_count.iter().rev().take(2).product::<usize>()
 }
 
 struct Monkey<'a> {
@@ -158,7 +160,7 @@ impl<'a> TryFrom<&'a str> for Monkey<'a> {
             .ok_or("No operation found")?;
 
         let (_, operation) =
-            parse_expression(rhs).or_else(|_| Err("Could not parse expression"))?;
+            parse_expression(rhs).map_err(|_| "Could not parse expression")?;
 
         let divisible_by = iter
             .next()
@@ -0,0 +1,129 @@
+use aoc_core::{parsed, Answer, Error, Explanation, Solution};
+use aoc_util::{
+    grid::Grid,
+    search::{bfs, Graph, Path},
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        let hill = Hill::try_from(input)?;
+        let shortest_path =
+            find_shortest_path_from_start(&hill).ok_or("Could not find shortest path")?;
+        Ok(shortest_path.cost.into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        let hill = Hill::try_from(input)?;
+        let shortest_path =
+            find_shortest_path_from_height(&hill, 0).ok_or("Could not find shortest path")?;
+        Ok(shortest_path.cost.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), Error>> {
+        Some(Hill::try_from(input).map(parsed))
+    }
+
+    fn explain(&self, part: u8, input: &str) -> Option<Result<Explanation, Error>> {
+        Some(explain(part, input))
+    }
+}
+
+fn explain(part: u8, input: &str) -> Result<Explanation, Error> {
+    let hill = Hill::try_from(input)?;
+    let shortest_path = match part {
+        1 => find_shortest_path_from_start(&hill),
+        _ => find_shortest_path_from_height(&hill, 0),
+    }
+    .ok_or("Could not find shortest path")?;
+
+    Ok(shortest_path.explain(|_, &index, _| {
+        let (x, y) = hill.heights.coord(index);
+        let height = char::from(b'a' + hill.heights.cells()[index] as u8);
+        Some(format!("step to ({x}, {y}) at height {height}"))
+    }))
+}
+
+fn find_shortest_path_from_start(hill: &Hill) -> Option<Path<usize, usize>> {
+    find_shortest_path(hill, [hill.start_index])
+}
+
+fn find_shortest_path_from_height(hill: &Hill, start_height: u32) -> Option<Path<usize, usize>> {
+    find_shortest_path(hill, hill.get_indices_at_height(start_height))
+}
+
+fn find_shortest_path(
+    hill: &Hill,
+    starts: impl IntoIterator<Item = usize>,
+) -> Option<Path<usize, usize>> {
+    bfs(hill, starts, |&index| index == hill.end_index)
+}
+
+struct Hill {
+    heights: Grid<u32>,
+    start_index: usize,
+    end_index: usize,
+}
+
+impl Hill {
+    fn get_neighbours(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        let (x, y) = self.heights.coord(index);
+        self.heights
+            .neighbours4(x, y)
+            .map(|(x, y)| self.heights.index(x, y))
+    }
+
+    fn get_valid_neighbours(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        let height = self.heights.cells()[index];
+        self.get_neighbours(index)
+            .filter(move |&neighbour| self.heights.cells()[neighbour] <= height + 1)
+    }
+
+    fn get_indices_at_height(&self, height: u32) -> impl Iterator<Item = usize> + '_ {
+        self.heights
+            .cells()
+            .iter()
+            .enumerate()
+            .filter_map(move |(i, height2)| (*height2 == height).then_some(i))
+    }
+}
+
+impl Graph for Hill {
+    type State = usize;
+    type Cost = usize;
+
+    fn successors(&self, &index: &usize) -> impl Iterator<Item = (usize, usize)> {
+        self.get_valid_neighbours(index)
+            .map(|neighbour| (neighbour, 1))
+    }
+}
+
+impl TryFrom<&str> for Hill {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let squares = Grid::parse(value, Some)?;
+
+        let find = |square| {
+            squares
+                .cells()
+                .iter()
+                .position(|&chr| chr == square)
+        };
+        let start_index = find('S').ok_or("Could not find start index")?;
+        let end_index = find('E').ok_or("Could not find end index")?;
+
+        let heights = squares.map(|&chr| match chr {
+            'S' => 0,
+            'E' => 25,
+            chr => chr as u32 - 'a' as u32,
+        });
+
+        Ok(Hill {
+            heights,
+            start_index,
+            end_index,
+        })
+    }
+}
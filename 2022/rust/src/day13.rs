@@ -1,19 +1,23 @@
-use std::fs::read_to_string;
-
+use aoc_core::{Answer, Error, Solution};
 use nom::{
     branch::alt, character::complete, combinator::map, multi::separated_list0, sequence::delimited,
     IResult,
 };
 
-fn main() {
-    part_one();
-    part_two();
-}
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
 
-fn part_one() {
-    let data = read_to_string("./data/13.txt").expect("Could not load data file");
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
+}
 
-    let mut pairs = data.split("\n\n");
+fn part_one(data: &str) -> usize {
+    let pairs = data.split("\n\n");
 
     let mut counter = 0;
 
@@ -34,12 +38,10 @@ fn part_one() {
         }
     }
 
-    println!("Part one: {counter}");
+    counter
 }
 
-fn part_two() {
-    let data = read_to_string("./data/13-example.txt").expect("Could not load data file");
-
+fn part_two(data: &str) -> usize {
     let mut items = data
         .lines()
         .filter_map(|line| parse_list(line).ok().map(|x| x.1))
@@ -50,15 +52,13 @@ fn part_two() {
     items.push(divider1.clone());
     items.push(divider2.clone());
     items.sort();
-    dbg!(&items);
 
     let mut item_iter = items.iter();
 
     let first_index = item_iter.by_ref().position(|x| *x == divider1).unwrap() + 1;
     let second_index = item_iter.by_ref().position(|x| *x == divider2).unwrap() + first_index + 1;
 
-    let decoder_key = first_index * second_index;
-    println!("Part two: {decoder_key}");
+    first_index * second_index
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -69,18 +69,18 @@ enum Item {
 
 impl Ord for Item {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap()
+        match (self, other) {
+            (Item::Number(s), Item::Number(o)) => s.cmp(o),
+            (Item::Number(_s), Item::List(_o)) => Item::List(vec![self.clone()]).cmp(other),
+            (Item::List(_), Item::Number(_)) => self.cmp(&Item::List(vec![other.clone()])),
+            (Item::List(s), Item::List(o)) => s.cmp(o),
+        }
     }
 }
 
 impl PartialOrd for Item {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        match (self, other) {
-            (Item::Number(s), Item::Number(o)) => s.partial_cmp(o),
-            (Item::Number(s), Item::List(o)) => Item::List(vec![self.clone()]).partial_cmp(other),
-            (Item::List(_), Item::Number(_)) => self.partial_cmp(&Item::List(vec![other.clone()])),
-            (Item::List(s), Item::List(o)) => s.partial_cmp(o),
-        }
+        Some(self.cmp(other))
     }
 }
 
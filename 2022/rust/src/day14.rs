@@ -1,19 +1,26 @@
+use aoc_core::{Answer, Error, Solution};
+use aoc_util::coord;
+
 use itertools::Itertools;
-use std::{collections::HashMap, fs::read_to_string};
+use std::collections::HashMap;
 
 use nom::{
-    bytes::complete::tag, character::complete, multi::separated_list1, sequence::separated_pair,
-    IResult,
+    bytes::complete::tag, character::complete, combinator::map, multi::separated_list1,
+    sequence::separated_pair, IResult,
 };
 
-type Coord = (u32, u32);
+type Coord = coord::Coord<u32>;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
 
-fn main() {
-    let data = read_to_string("./data/14.txt").expect("Could not read data file");
-    let answer = part_one(&data);
-    println!("Part one: {answer}");
-    let answer = part_two(&data);
-    println!("Part two: {answer}");
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
 }
 
 #[derive(Debug)]
@@ -31,9 +38,9 @@ fn part_one(data: &str) -> usize {
 
 
     let mut max_y = 0;
-    for coord in paths.iter().flat_map(|path| coordinates_from_path(&path)) {
+    for coord in paths.iter().flat_map(|path| coordinates_from_path(path)) {
 	filled.insert(coord, Material::Rock);
-	max_y = u32::max(max_y, coord.1);
+	max_y = u32::max(max_y, coord.y);
     }
 
     let mut sand_grain_count = 0;
@@ -46,22 +53,22 @@ fn part_one(data: &str) -> usize {
 	    if cur_y > max_y {
 		break 'outer;
 	    }
-	    if !filled.contains_key(&(cur_x, cur_y+1)) {
+	    if !filled.contains_key(&Coord::new(cur_x, cur_y+1)) {
 		cur_y += 1;
 		continue;
 	    }
-	    if !filled.contains_key(&(cur_x-1, cur_y+1)) {
+	    if !filled.contains_key(&Coord::new(cur_x-1, cur_y+1)) {
 		cur_x -= 1;
 		cur_y += 1;
 		continue;
 	    }
-	    if !filled.contains_key(&(cur_x+1, cur_y+1)) {
+	    if !filled.contains_key(&Coord::new(cur_x+1, cur_y+1)) {
 		cur_x += 1;
 		cur_y += 1;
 		continue;
 	    }
 
-	    filled.insert((cur_x, cur_y), Material::Sand);
+	    filled.insert(Coord::new(cur_x, cur_y), Material::Sand);
 	    sand_grain_count += 1;
 	    break;
 	}
@@ -79,46 +86,46 @@ fn part_two(data: &str) -> usize {
 
 
     let mut max_y = 0;
-    for coord in paths.iter().flat_map(|path| coordinates_from_path(&path)) {
+    for coord in paths.iter().flat_map(|path| coordinates_from_path(path)) {
 	filled.insert(coord, Material::Rock);
-	max_y = u32::max(max_y, coord.1);
+	max_y = u32::max(max_y, coord.y);
     }
 
     let floor = max_y + 2;
 
     let mut sand_grain_count = 0;
-    'outer: loop {
+    loop {
 
 	let mut cur_x = 500;
 	let mut cur_y = 0;
 
-	if filled.contains_key(&(cur_x, cur_y)) {
+	if filled.contains_key(&Coord::new(cur_x, cur_y)) {
 	    break;
 	}
 
 	loop {
 	    if cur_y+1 == floor {
-		filled.insert((cur_x, cur_y), Material::Sand);
+		filled.insert(Coord::new(cur_x, cur_y), Material::Sand);
 		sand_grain_count += 1;
 		break;
 	    }
 
-	    if !filled.contains_key(&(cur_x, cur_y+1)) {
+	    if !filled.contains_key(&Coord::new(cur_x, cur_y+1)) {
 		cur_y += 1;
 		continue;
 	    }
-	    if !filled.contains_key(&(cur_x-1, cur_y+1)) {
+	    if !filled.contains_key(&Coord::new(cur_x-1, cur_y+1)) {
 		cur_x -= 1;
 		cur_y += 1;
 		continue;
 	    }
-	    if !filled.contains_key(&(cur_x+1, cur_y+1)) {
+	    if !filled.contains_key(&Coord::new(cur_x+1, cur_y+1)) {
 		cur_x += 1;
 		cur_y += 1;
 		continue;
 	    }
 
-	    filled.insert((cur_x, cur_y), Material::Sand);
+	    filled.insert(Coord::new(cur_x, cur_y), Material::Sand);
 	    sand_grain_count += 1;
 	    break;
 	}
@@ -130,7 +137,10 @@ fn part_two(data: &str) -> usize {
 fn parse_path(data: &str) -> IResult<&str, Vec<Coord>> {
     separated_list1(
         tag(" -> "),
-        separated_pair(complete::u32, complete::char(','), complete::u32),
+        map(
+            separated_pair(complete::u32, complete::char(','), complete::u32),
+            Coord::from,
+        ),
     )(data)
 }
 
@@ -139,23 +149,26 @@ fn coordinates_from_path<'a>(path: &'a [Coord]) -> impl Iterator<Item = Coord> +
         let from = coords[0];
         let to = coords[1];
 
-        let min_x = u32::min(from.0, to.0);
-        let max_x = u32::max(from.0, to.0);
-        let min_y = u32::min(from.1, to.1);
-        let max_y = u32::max(from.1, to.1);
+        let min_x = u32::min(from.x, to.x);
+        let max_x = u32::max(from.x, to.x);
+        let min_y = u32::min(from.y, to.y);
+        let max_y = u32::max(from.y, to.y);
 
-        (min_x..=max_x).cartesian_product(min_y..=max_y)
+        (min_x..=max_x)
+            .cartesian_product(min_y..=max_y)
+            .map(Coord::from)
     })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::input::example;
 
     #[test]
     fn test_part_one_example() {
         let data =
-            read_to_string("./data/14-example.txt").expect("Could not load example datafile");
+            example(2022, 14, "example").expect("Could not load example datafile");
         let answer = part_one(&data);
         assert_eq!(answer, 24);
     }
@@ -163,7 +176,7 @@ mod tests {
     #[test]
     fn test_part_two_example() {
         let data =
-            read_to_string("./data/14-example.txt").expect("Could not load example datafile");
+            example(2022, 14, "example").expect("Could not load example datafile");
         let answer = part_two(&data);
         assert_eq!(answer, 93);
     }
@@ -171,6 +184,9 @@ mod tests {
     #[test]
     fn test_parse_path() {
         let path = parse_path("498,4 -> 498,6 -> 496,6").unwrap().1;
-        assert_eq!(path, vec![(498, 4), (498, 6), (496, 6)]);
+        assert_eq!(
+            path,
+            vec![Coord::new(498, 4), Coord::new(498, 6), Coord::new(496, 6)]
+        );
     }
 }
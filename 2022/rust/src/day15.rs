@@ -1,16 +1,16 @@
-use std::fs::read_to_string;
+use aoc_core::{Answer, Error, Solution};
+use aoc_util::coord::Coord;
 
 use itertools::Itertools;
 
 use nom::{
     bytes::complete::{tag, take_until},
     character::complete,
+    combinator::map,
     sequence::{preceded, separated_pair},
     IResult,
 };
 
-type Coord = (i64, i64);
-
 struct Sensor {
     position: Coord,
     range: i64,
@@ -18,36 +18,38 @@ struct Sensor {
 
 impl Sensor {
     fn in_range(&self, coord: Coord) -> bool {
-	manhattan_distance(self.position, coord) <= self.range
+	self.position.manhattan(coord) <= self.range
     }
 }
 
-fn main() {
-    let data = read_to_string("./data/15.txt").expect("Could not load data file");
-    println!("Part one: {}", part_one(&data, 2_000_000));
-    println!("Part two: {}", part_two(&data, (0, 4_000_000), (0, 4_000_000)));
-}
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input, 2_000_000).into())
+    }
 
-fn manhattan_distance(coord1: Coord, coord2: Coord) -> i64 {
-    i64::abs(coord1.0 - coord2.0) + i64::abs(coord1.1 - coord2.1)
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input, (0, 4_000_000), (0, 4_000_000)).into())
+    }
 }
 
 fn part_one(data: &str, row: i64) -> i64 {
     let sensors = data.lines().filter_map(|line| {
         let (sensor, beacon) = parse_line(line).ok()?.1;
 
-        let distance = manhattan_distance(sensor, beacon);
+        let distance = sensor.manhattan(beacon);
 
         Some((sensor, distance))
     });
 
     let mut excluded_ranges = sensors
         .filter_map(|(sensor, excluded_distance)| {
-            let row_distance = i64::abs(sensor.1 - row);
+            let row_distance = i64::abs(sensor.y - row);
             let excluded_radius = excluded_distance - row_distance;
 
             (excluded_radius >= 0)
-                .then_some((sensor.0 - excluded_radius, sensor.0 + excluded_radius))
+                .then_some((sensor.x - excluded_radius, sensor.x + excluded_radius))
         })
         .collect::<Vec<_>>();
 
@@ -64,7 +66,7 @@ fn part_two(data: &str, x_range: (i64, i64), y_range: (i64, i64)) -> i64 {
         .lines()
         .filter_map(|line| {
             let (sensor, beacon) = parse_line(line).ok()?.1;
-            let distance = manhattan_distance(sensor, beacon);
+            let distance = sensor.manhattan(beacon);
 
             Some(Sensor {
                 position: sensor,
@@ -77,29 +79,26 @@ fn part_two(data: &str, x_range: (i64, i64), y_range: (i64, i64)) -> i64 {
 
     let valid_poi = pois.iter().filter(|&poi| {
 	is_valid_coord(*poi, x_range, y_range)
-    }).filter(|&poi| {
+    }).find(|&poi| {
 	!sensors.iter().any(|sensor| sensor.in_range(*poi))
-    }).next().expect("Could not find valid POI");
+    }).expect("Could not find valid POI");
 
-    valid_poi.0 * 4_000_000 + valid_poi.1
+    valid_poi.x * 4_000_000 + valid_poi.y
 }
 
 fn is_valid_coord(coord: Coord, x_range: (i64, i64), y_range: (i64, i64)) -> bool {
-    x_range.0 <= coord.0 && coord.0 <= x_range.1 && y_range.0 <= coord.1 && coord.1 <= y_range.1
+    x_range.0 <= coord.x && coord.x <= x_range.1 && y_range.0 <= coord.y && coord.y <= y_range.1
 }
 
-fn get_points_of_interest(sensor1: &Sensor, sensor2: &Sensor) -> Option<[(i64, i64); 2]> {
-    let distance = manhattan_distance(sensor1.position, sensor2.position);
+fn get_points_of_interest(sensor1: &Sensor, sensor2: &Sensor) -> Option<[Coord; 2]> {
+    let distance = sensor1.position.manhattan(sensor2.position);
     let range_sum = sensor1.range + sensor2.range;
 
     if distance > range_sum {
         return None;
     }
 
-    let distance_vec = (
-        sensor2.position.0 - sensor1.position.0,
-        sensor2.position.1 - sensor1.position.1,
-    );
+    let distance_vec = sensor2.position - sensor1.position;
     let offset = range_sum - distance;
 
     // Does not lock in a single coordinate, could theoretically lead to missed cases
@@ -109,21 +108,21 @@ fn get_points_of_interest(sensor1: &Sensor, sensor2: &Sensor) -> Option<[(i64, i
 
     let offset = offset / 2;
 
-    // let horizontal_direction = distance_vec.0 / i64::abs(distance_vec.0);
-    let horizontal_direction = match distance_vec.0.cmp(&0) {
+    // let horizontal_direction = distance_vec.x / i64::abs(distance_vec.x);
+    let horizontal_direction = match distance_vec.x.cmp(&0) {
         std::cmp::Ordering::Less => -1,
         std::cmp::Ordering::Equal => 0,
         std::cmp::Ordering::Greater => 1,
     };
-    // let vertical_direction = distance_vec.1 / i64::abs(distance_vec.1);
-    let vertical_direction = match distance_vec.1.cmp(&0) {
+    // let vertical_direction = distance_vec.y / i64::abs(distance_vec.y);
+    let vertical_direction = match distance_vec.y.cmp(&0) {
         std::cmp::Ordering::Less => -1,
         std::cmp::Ordering::Equal => 0,
         std::cmp::Ordering::Greater => 1,
     };
 
-    let abs_horizontal_distance = i64::abs(distance_vec.0);
-    let abs_vertical_distance = i64::abs(distance_vec.1);
+    let abs_horizontal_distance = i64::abs(distance_vec.x);
+    let abs_vertical_distance = i64::abs(distance_vec.y);
 
     let pathlen = sensor1.range - offset;
 
@@ -131,8 +130,8 @@ fn get_points_of_interest(sensor1: &Sensor, sensor2: &Sensor) -> Option<[(i64, i
     let horizontal_steps = i64::min(abs_horizontal_distance, pathlen);
     let vertical_steps = i64::max(pathlen - horizontal_steps, 0);
 
-    let closest_x = sensor1.position.0 + horizontal_steps * horizontal_direction;
-    let closest_y = sensor1.position.1 + vertical_steps * vertical_direction;
+    let closest_x = sensor1.position.x + horizontal_steps * horizontal_direction;
+    let closest_y = sensor1.position.y + vertical_steps * vertical_direction;
 
     let poi1 = if vertical_steps > 0 {
         let offset_direction = if horizontal_direction == 0 {
@@ -140,22 +139,22 @@ fn get_points_of_interest(sensor1: &Sensor, sensor2: &Sensor) -> Option<[(i64, i
         } else {
             horizontal_direction
         };
-        (closest_x + ((offset + 1) * offset_direction), closest_y)
+        Coord::new(closest_x + ((offset + 1) * offset_direction), closest_y)
     } else {
         let offset_direction = if vertical_direction == 0 {
             -1
         } else {
             vertical_direction
         };
-        (closest_x, closest_y - ((offset + 1) * offset_direction))
+        Coord::new(closest_x, closest_y - ((offset + 1) * offset_direction))
     };
 
     // First vertical then horizontal
     let vertical_steps = i64::min(abs_vertical_distance, pathlen);
     let horizontal_steps = i64::max(pathlen - vertical_steps, 0);
 
-    let closest_x = sensor1.position.0 + horizontal_steps * horizontal_direction;
-    let closest_y = sensor1.position.1 + vertical_steps * vertical_direction;
+    let closest_x = sensor1.position.x + horizontal_steps * horizontal_direction;
+    let closest_y = sensor1.position.y + vertical_steps * vertical_direction;
 
     let poi2 = if horizontal_steps > 0 {
         let offset_direction = if vertical_direction == 0 {
@@ -163,14 +162,14 @@ fn get_points_of_interest(sensor1: &Sensor, sensor2: &Sensor) -> Option<[(i64, i
         } else {
             vertical_direction
         };
-        (closest_x, closest_y + ((offset + 1) * offset_direction))
+        Coord::new(closest_x, closest_y + ((offset + 1) * offset_direction))
     } else {
         let offset_direction = if horizontal_direction == 0 {
             1
         } else {
             horizontal_direction
         };
-        (closest_x - ((offset + 1) * offset_direction), closest_y)
+        Coord::new(closest_x - ((offset + 1) * offset_direction), closest_y)
     };
 
     Some([poi1, poi2])
@@ -197,10 +196,13 @@ fn parse_line(line: &str) -> IResult<&str, (Coord, Coord)> {
 }
 
 fn parse_coordinate(input: &str) -> IResult<&str, Coord> {
-    separated_pair(
-        preceded(tag("x="), complete::i64),
-        tag(", "),
-        preceded(tag("y="), complete::i64),
+    map(
+        separated_pair(
+            preceded(tag("x="), complete::i64),
+            tag(", "),
+            preceded(tag("y="), complete::i64),
+        ),
+        Coord::from,
     )(input)
 }
 
@@ -230,18 +232,19 @@ fn get_non_overlapping_ranges(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::input::example;
 
     #[test]
     fn test_part_one() {
         let data =
-            read_to_string("./data/15-example.txt").expect("Could not load example data filkke");
+            example(2022, 15, "example").expect("Could not load example data filkke");
         assert_eq!(part_one(&data, 10), 26)
     }
 
     #[test]
     fn test_part_two() {
         let data =
-            read_to_string("./data/15-example.txt").expect("Could not load example data filkke");
+            example(2022, 15, "example").expect("Could not load example data filkke");
         assert_eq!(part_two(&data, (0, 20), (0, 20)), 56_000_011);
     }
 
@@ -250,7 +253,7 @@ mod tests {
         let res = parse_line("Sensor at x=2, y=18: closest beacon is at x=-2, y=15")
             .unwrap()
             .1;
-        assert_eq!(res, ((2, 18), (-2, 15)))
+        assert_eq!(res, (Coord::new(2, 18), Coord::new(-2, 15)))
     }
 
     #[test]
@@ -262,17 +265,17 @@ mod tests {
     #[test]
     fn test_get_poi() {
         let sensor1 = Sensor {
-            position: (6, 8),
+            position: Coord::new(6, 8),
             range: 4,
         };
         let sensor2 = Sensor {
-            position: (7, 4),
+            position: Coord::new(7, 4),
             range: 3,
         };
 
         let poi = get_points_of_interest(&sensor1, &sensor2).unwrap();
         assert_eq!(poi.len(), 2);
-        assert!(poi.contains(&(4, 5)));
-        assert!(poi.contains(&(9, 6)));
+        assert!(poi.contains(&Coord::new(4, 5)));
+        assert!(poi.contains(&Coord::new(9, 6)));
     }
 }
@@ -1,4 +1,6 @@
-use std::{collections::BTreeSet, fs::read_to_string};
+use aoc_core::{Answer, Error, Solution};
+
+use std::collections::BTreeSet;
 
 use nom::{
     branch::alt,
@@ -11,20 +13,32 @@ use nom::{
 
 use itertools::Itertools;
 
-fn main() {
-    let data = read_to_string("./data/16.txt").expect("Could not read data file");
-    let answer = part_one(&data);
-    println!("{answer}");
-    let answer = part_two_optimized(&data);
-    println!("{answer}");
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two_optimized(input).into())
+    }
+
+    fn variants(&self) -> &'static [&'static str] {
+        &["part_two_unoptimized"]
+    }
+
+    fn variant(&self, name: &str, input: &str) -> Option<Result<Answer, Error>> {
+        (name == "part_two_unoptimized").then(|| Ok(part_two(input).into()))
+    }
 }
 
 fn get_shortest_path(valves: &[(&str, usize, Vec<&str>)]) -> Vec<Vec<usize>> {
     let mut shortest_path = vec![vec![usize::MAX / valves.len(); valves.len()]; valves.len()];
 
     // Self distance is zero
-    for i in 0..valves.len() {
-        shortest_path[i][i] = 0;
+    for (i, distances) in shortest_path.iter_mut().enumerate() {
+        distances[i] = 0;
     }
 
     // Fill shortest path with neigbours
@@ -170,24 +184,8 @@ fn part_two_optimized(data: &str) -> usize {
     )
     .0;
 
-    let starting_costs = flowing_valves
-        .iter()
-        .map(|i| shortest_path_all_valves[starting_index][*i]);
-    let queue = get_start_queue(30, &flow_rates, &flowing_valves, starting_costs);
-
-    dbg!(run(
-        30,
-        &flow_rates,
-        &flowing_valves,
-        &shortest_path_all_valves,
-        queue,
-    )
-    .0
-    .into_iter()
-    .max());
-
     let flowing_valves_set = flowing_valves.iter().cloned().collect::<BTreeSet<_>>();
-    let partitioned_valves = (1..(flowing_valves.len() / 2))
+    let partitioned_valves = (1..=(flowing_valves.len() / 2))
         .flat_map(|len| flowing_valves.iter().cloned().combinations(len))
         .map(|chosen| {
             let set = chosen.iter().cloned().collect::<BTreeSet<_>>();
@@ -403,27 +401,17 @@ fn run(
         }
     }
 
-    dbg!(solutions
-        .iter()
-	.cloned()
-	.enumerate()
-        .filter_map(|(i, x)| (x == 0).then_some(i))
-        .map(|valves| ValveCollection {
-            open_valves: valves
-        }
-        .get_open_valves(64)
-        .collect::<Vec<_>>())
-        .collect::<Vec<_>>());
-
     (solutions, optimal_pressure)
 }
 
+#[allow(dead_code)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 struct MemoKey {
     position: BTreeSet<Position>,
     valves: ValveCollection,
 }
 
+#[allow(dead_code)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 struct Position {
     position: usize,
@@ -439,17 +427,14 @@ struct State {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Default)]
 struct ValveCollection {
     open_valves: usize,
 }
 
-impl Default for ValveCollection {
-    fn default() -> Self {
-        Self { open_valves: 0 }
-    }
-}
 
 impl ValveCollection {
+    #[allow(dead_code)]
     fn get_open_valves(&self, valve_count: usize) -> impl Iterator<Item = usize> + '_ {
         (0..valve_count).filter(|i| self.has_open_valve(*i))
     }
@@ -494,11 +479,12 @@ fn parse_line(line: &str) -> IResult<&str, (&str, u64, Vec<&str>)> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::input::example;
 
     #[test]
     fn test_part_one() {
         let data =
-            read_to_string("./data/16-example.txt").expect("Could not read example data file");
+            example(2022, 16, "example").expect("Could not read example data file");
 
         assert_eq!(part_one(&data), 1651);
     }
@@ -506,11 +492,19 @@ mod tests {
     #[test]
     fn test_part_two() {
         let data =
-            read_to_string("./data/16-example.txt").expect("Could not read example data file");
+            example(2022, 16, "example").expect("Could not read example data file");
 
         assert_eq!(part_two_optimized(&data), 1707);
     }
 
+    #[test]
+    fn test_part_two_unoptimized() {
+        let data =
+            example(2022, 16, "example").expect("Could not read example data file");
+
+        assert_eq!(part_two(&data), 1707);
+    }
+
     #[test]
     fn test_parse_line() {
         assert_eq!(
@@ -22,7 +22,7 @@ impl TryFrom<char> for Direction {
 }
 
 impl Direction {
-    fn to_stride(&self, width: isize) -> isize {
+    fn to_stride(self, width: isize) -> isize {
         match self {
             Direction::Left => -1,
             Direction::Right => 1,
@@ -31,7 +31,11 @@ impl Direction {
     }
 }
 
-use std::{collections::{BTreeSet, HashMap}, fmt::Display, fs::read_to_string};
+use aoc_core::{Answer, Error, Solution};
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+};
 
 #[derive(Hash, Debug, PartialEq, Eq, PartialOrd, Ord)]
 struct Key {
@@ -137,12 +141,16 @@ impl Display for Chamber {
     }
 }
 
-fn main() {
-    let data = read_to_string("./data/17.txt").expect("Could not read data file");
-    let answer = part_one(&data);
-    println!("{answer}");
-    let answer = part_two(&data);
-    println!("{answer}");
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
 }
 
 fn part_one(data: &str) -> usize {
@@ -173,9 +181,6 @@ fn part_one(data: &str) -> usize {
         }
     }
 
-    println!("{:?}", chamber.bottom);
-    println!("{chamber}");
-
     chamber.top_row as usize
 }
 
@@ -236,9 +241,6 @@ fn part_two(data: &str) -> usize {
 	rock_index += 1;
     }
 
-    println!("{:?}", chamber.bottom);
-    println!("{chamber}");
-
     chamber.top_row as usize + skipped_height
 }
 
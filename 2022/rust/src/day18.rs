@@ -1,18 +1,23 @@
+use aoc_core::{Answer, Error, Solution};
+use aoc_util::coord::axis_neighbours;
+
 use nom::{bytes::complete::tag, character::complete, multi::separated_list1};
-use std::{collections::BTreeSet, fs::read_to_string};
+use std::collections::BTreeSet;
 
 use itertools::Itertools;
 
 const MAX_DIM: usize = 3;
 
-fn main() {
-    let data = read_to_string("./data/18.txt").expect("Could not read data file");
+pub struct Day;
 
-    let answer = part_one(&data);
-    println!("Part one: {answer}");
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
 
-    let answer = part_two(&data);
-    println!("Part two: {answer}");
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
 }
 
 fn part_one(data: &str) -> usize {
@@ -72,7 +77,7 @@ fn part_two(data: &str) -> usize {
     let mut visited = BTreeSet::new();
     let mut sides = 0;
     while let Some(coord) = stack.pop() {
-	let neighbours = get_neighbours(&coord, &min_max);
+	let neighbours = axis_neighbours(&coord, &min_max);
 
 	for neighbour in neighbours {
 	    if coords.contains(&neighbour) {
@@ -87,24 +92,6 @@ fn part_two(data: &str) -> usize {
     sides
 }
 
-fn get_neighbours(coord: &[i16], min_max: &[(i16, i16)]) -> Vec<Vec<i16>> {
-    let mut neighbours = Vec::with_capacity(2 * MAX_DIM);
-    for dimension in 0..MAX_DIM {
-	if coord[dimension] > min_max[dimension].0 {
-	    let mut neighbour = coord.to_owned();
-	    neighbour[dimension] -= 1;
-	    neighbours.push(neighbour);
-	}
-	if coord[dimension] < min_max[dimension].1 {
-	    let mut neighbour = coord.to_owned();
-	    neighbour[dimension] += 1;
-	    neighbours.push(neighbour);
-	}
-    }
-
-    neighbours
-}
-
 fn make_sort_key(coord: &[i16], order: impl IntoIterator<Item = usize>) -> usize {
     let mut it = order.into_iter();
     let mut total = coord[it.next().expect("Need at least one sort key")] as usize;
@@ -122,18 +109,19 @@ fn parse_line(line: &str) -> nom::IResult<&str, Vec<i16>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::input::example;
 
     #[test]
     fn test_part_one() {
         let data =
-            read_to_string("./data/18-example.txt").expect("Could not load example data file");
+            example(2022, 18, "example").expect("Could not load example data file");
 
         assert_eq!(part_one(&data), 64);
     }
     #[test]
     fn test_part_two() {
         let data =
-            read_to_string("./data/18-example.txt").expect("Could not load example data file");
+            example(2022, 18, "example").expect("Could not load example data file");
 
         assert_eq!(part_two(&data), 58);
     }
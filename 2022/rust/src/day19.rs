@@ -1,19 +1,19 @@
-use std::{
-    collections::{BTreeMap, HashMap, VecDeque},
-    fs::read_to_string,
-};
+use aoc_core::{Answer, Error, Solution};
+
+use std::collections::{BTreeMap, VecDeque};
 
 use nom::{
     branch::alt,
     bytes::complete::{tag, take, take_until},
     character::complete,
     multi::separated_list1,
-    sequence::{preceded, separated_pair, tuple},
+    sequence::separated_pair,
     IResult,
 };
 use nom_supreme::ParserExt;
 
 type Cost = (u32, Material);
+#[allow(dead_code)]
 type Blueprint = Vec<Robot>;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -37,10 +37,16 @@ struct State {
     ore: BTreeMap<Material, u32>,
 }
 
-fn main() {
-    let data = read_to_string("./data/19.txt").expect("Could not load data file");
-    println!("Part one: {}", part_one(&data));
-    println!("Part two: {}", part_two(&data));
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
 }
 
 fn part_one(data: &str) -> usize {
@@ -63,7 +69,6 @@ fn part_two(data: &str) -> usize {
     blueprints
         .take(3)
         .map(|blueprint| find_optimum::<32>(&blueprint))
-        .inspect(|x| println!("{x}"))
         .product::<u32>() as usize
 }
 
@@ -77,12 +82,12 @@ fn time_till_new_bot(
         .iter()
         .map(|(cost, material)| {
             if let Some(flow) = producing.get(material) {
-                let current = current_materials.get(&material).unwrap_or(&0);
+                let current = current_materials.get(material).unwrap_or(&0);
                 if current >= cost {
                     return 0;
                 }
                 let needed = cost - current;
-                (needed / flow) + if needed % flow == 0 { 0 } else { 1 }
+                (needed / flow) + if needed.is_multiple_of(*flow) { 0 } else { 1 }
             } else {
                 u32::MAX / 8
             }
@@ -192,6 +197,7 @@ fn parse_material(input: &str) -> IResult<&str, Material> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::input::example;
 
     #[test]
     fn test_parse_line() {
@@ -223,7 +229,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let data =
-            read_to_string("./data/19-example.txt").expect("Could not load example data file");
+            example(2022, 19, "example").expect("Could not load example data file");
         let answer = part_one(&data);
         assert_eq!(answer, 33);
     }
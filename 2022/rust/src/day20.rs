@@ -1,14 +1,17 @@
-use std::fs::read_to_string;
+use aoc_core::{Answer, Error, Solution};
 
 const DECRYPTION_KEY: isize = 811589153;
 
-fn main() {
-    let data = read_to_string("./data/20.txt").expect("Could not read data file");
-    let answer = part_one(&data);
-    println!("{answer}");
+pub struct Day;
 
-    let answer = part_two(&data);
-    println!("{answer}");
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
 }
 
 fn part_one(data: &str) -> isize {
@@ -19,9 +22,8 @@ fn part_one(data: &str) -> isize {
 
     let mut indices = (0..data.len()).collect::<Vec<_>>();
     // swap_all(&mut data, &mut indices);
-    for i in 0..data.len() {
+    for (i, &shift) in data.iter().enumerate() {
         let index = indices.iter().position(|&x| x == i).unwrap();
-        let shift = data[i];
 
         swappy::<1>(shift, &mut indices, index);
     }
@@ -39,7 +41,7 @@ fn part_one(data: &str) -> isize {
         .sum::<isize>()
 }
 
-fn swappy<const DECRYPTION_KEY: isize>(shift: isize, indices: &mut Vec<usize>, index: usize) {
+fn swappy<const DECRYPTION_KEY: isize>(shift: isize, indices: &mut [usize], index: usize) {
     let new_index = (((index as isize) + shift * DECRYPTION_KEY).rem_euclid(indices.len() as isize - 1)) as usize;
 
     match new_index.cmp(&index) {
@@ -53,10 +55,9 @@ fn swappy<const DECRYPTION_KEY: isize>(shift: isize, indices: &mut Vec<usize>, i
     }
 }
 
-fn swap_all<const DECRYPTION_KEY: isize>(data: &[isize], indices: &mut Vec<usize>) {
-    for i in 0..data.len() {
+fn swap_all<const DECRYPTION_KEY: isize>(data: &[isize], indices: &mut [usize]) {
+    for (i, &shift) in data.iter().enumerate() {
         let index = indices.iter().position(|&x| x == i).unwrap();
-        let shift = data[i];
 
         swappy::<DECRYPTION_KEY>(shift, indices, index);
     }
@@ -103,6 +104,7 @@ mod tests {
 	list.rotate_left(offset);
     }
     use super::*;
+    use aoc_core::input::example;
 
     #[test]
     fn test_swap_all() {
@@ -188,14 +190,14 @@ mod tests {
     #[test]
     fn test_part_one() {
         let data =
-            read_to_string("./data/20-example.txt").expect("Could not read example data file");
+            example(2022, 20, "example").expect("Could not read example data file");
         assert_eq!(part_one(&data), 3);
     }
 
     #[test]
     fn test_part_two() {
         let data =
-            read_to_string("./data/20-example.txt").expect("Could not read example data file");
+            example(2022, 20, "example").expect("Could not read example data file");
         assert_eq!(part_two(&data), 1623178306);
     }
 
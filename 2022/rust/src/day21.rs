@@ -1,19 +1,28 @@
-use std::{collections::HashMap, fs::read_to_string};
+use aoc_core::{Answer, Error, Solution};
+
+use std::collections::HashMap;
 
 use nom::{
     branch::alt,
     bytes::complete::{tag, take, take_while},
     character::{complete, is_alphabetic},
-    sequence::{delimited, preceded, separated_pair, terminated, tuple},
+    sequence::{delimited, separated_pair, tuple},
     IResult,
 };
 
 type Value = i64;
+type Monkey<'a> = (&'a str, Operation<'a>);
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_one(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_one(input).into())
+    }
 
-fn main() {
-    let data = read_to_string("./data/21.txt").expect("Could not load data file");
-    println!("Part one: {}", part_one(&data));
-    println!("Part two: {}", part_two(&data));
+    fn part_two(&self, input: &str) -> Result<Answer, Error> {
+        Ok(part_two(input).into())
+    }
 }
 
 fn part_one(data: &str) -> Value {
@@ -38,7 +47,6 @@ fn part_one(data: &str) -> Value {
                 deps.entry(lhs).or_default().push(op.clone());
                 deps.entry(rhs).or_default().push(op);
             }
-            _ => unreachable!(),
         };
     }
 
@@ -65,7 +73,7 @@ fn part_one(data: &str) -> Value {
 }
 
 fn part_two(data: &str) -> Value {
-    let (root, operations): (Vec<(&str, Operation)>, Vec<(&str, Operation)>) = data
+    let (root, operations): (Vec<Monkey>, Vec<Monkey>) = data
         .lines()
         .map(|line| parse_line(line).expect("Could not parse line").1)
         .partition(|k| k.0 == "root");
@@ -111,7 +119,6 @@ fn part_two(data: &str) -> Value {
             }
         }
     }
-    dbg!(known_data.len());
 
     match root[0].1 {
         Operation::Add(lhs, rhs)
@@ -172,8 +179,6 @@ fn part_two(data: &str) -> Value {
             }
         }
     }
-    dbg!(known_data.len());
-
     panic!("Could not find a valid value for humn");
 }
 
@@ -197,7 +202,7 @@ impl<'a> Operation<'a> {
         })
     }
 
-    fn invert(self, dest: &'a str) -> [(&str, Self); 2] {
+    fn invert(self, dest: &'a str) -> [(&'a str, Self); 2] {
         match self {
             Operation::Add(lhs, rhs) => [
                 (rhs, Operation::Subtract(dest, lhs)),
@@ -220,15 +225,15 @@ impl<'a> Operation<'a> {
     }
 }
 
-fn parse_line<'a>(data: &'a str) -> IResult<&str, (&str, Operation<'a>)> {
+fn parse_line<'a>(data: &'a str) -> IResult<&'a str, (&'a str, Operation<'a>)> {
     separated_pair(take(4usize), tag(": "), parse_operation)(data)
 }
 
-fn parse_operation(input: &str) -> IResult<&str, Operation> {
+fn parse_operation(input: &str) -> IResult<&str, Operation<'_>> {
     alt((parse_operator, parse_assignment))(input)
 }
 
-fn parse_operator(data: &str) -> IResult<&str, Operation> {
+fn parse_operator(data: &str) -> IResult<&str, Operation<'_>> {
     let (res, tup) = tuple((
         take_while(|x| is_alphabetic(x as u8)),
         delimited(
@@ -255,7 +260,7 @@ fn parse_operator(data: &str) -> IResult<&str, Operation> {
     IResult::Ok((res, out))
 }
 
-fn parse_assignment(data: &str) -> IResult<&str, Operation> {
+fn parse_assignment(data: &str) -> IResult<&str, Operation<'_>> {
     let (res, number) = complete::i64(data)?;
     IResult::Ok((res, Operation::Assign(number)))
 }
@@ -263,6 +268,7 @@ fn parse_assignment(data: &str) -> IResult<&str, Operation> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::input::example;
 
     #[test]
     fn test_parse_assignment() {
@@ -280,7 +286,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let data = read_to_string("./data/21-example.txt").expect("Could not read example data");
+        let data = example(2022, 21, "example").expect("Could not read example data");
         let answer = part_one(&data);
 
         assert_eq!(answer, 152);
@@ -288,7 +294,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let data = read_to_string("./data/21-example.txt").expect("Could not read example data");
+        let data = example(2022, 21, "example").expect("Could not read example data");
         let answer = part_two(&data);
 
         assert_eq!(answer, 301);
@@ -0,0 +1,55 @@
+use aoc_core::Year;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+
+pub static YEAR: Year = Year {
+    year: 2022,
+    days: &[
+        (1, &day01::Day),
+        (2, &day02::Day),
+        (3, &day03::Day),
+        (4, &day04::Day),
+        (5, &day05::Day),
+        (6, &day06::Day),
+        (7, &day07::Day),
+        (8, &day08::Day),
+        (9, &day09::Day),
+        (10, &day10::Day),
+        (11, &day11::Day),
+        (12, &day12::Day),
+        (13, &day13::Day),
+        (14, &day14::Day),
+        (15, &day15::Day),
+        (16, &day16::Day),
+        (17, &day17::Day),
+        (18, &day18::Day),
+        (19, &day19::Day),
+        (20, &day20::Day),
+        (21, &day21::Day),
+    ],
+};
+
+#[cfg(test)]
+mod examples {
+    include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+}
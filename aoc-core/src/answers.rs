@@ -0,0 +1,142 @@
+//! Known answers, to check that solutions keep giving the same results.
+//!
+//! Every year keeps them in an `answers.toml` next to its inputs, with a table per day:
+//!
+//! ```toml
+//! [02]
+//! part_one = 1451208
+//! part_two = 1620141160
+//! ```
+//!
+//! Answers that are not a number (like the letters drawn on a screen) are written as strings.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use crate::{Answer, Error};
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+enum Value {
+    Number(i64),
+    Text(String),
+}
+
+impl Value {
+    fn to_text(&self) -> String {
+        match self {
+            Value::Number(value) => value.to_string(),
+            Value::Text(value) => value.clone(),
+        }
+    }
+}
+
+/// The answers of both parts of a single day.
+#[derive(Debug, Default, Deserialize)]
+pub struct DayAnswers {
+    part_one: Option<Value>,
+    part_two: Option<Value>,
+}
+
+impl DayAnswers {
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        Ok(toml::from_str(text)?)
+    }
+
+    /// The expected answer of the given part (1 or 2), as it would be displayed.
+    pub fn get(&self, part: u8) -> Option<String> {
+        match part {
+            1 => self.part_one.as_ref(),
+            2 => self.part_two.as_ref(),
+            _ => None,
+        }
+        .map(Value::to_text)
+    }
+
+    /// Whether the answer matches the expected one, `None` when there is no expected answer.
+    ///
+    /// Trailing whitespace is ignored on every line, so pictures can be written as multi-line
+    /// strings.
+    pub fn check(&self, part: u8, answer: &Answer) -> Option<bool> {
+        let expected = self.get(part)?;
+        let answer = answer.to_string();
+
+        let lines = |text: &str| {
+            text.trim_end()
+                .lines()
+                .map(|line| line.trim_end().to_owned())
+                .collect::<Vec<_>>()
+        };
+        Some(lines(&expected) == lines(&answer))
+    }
+}
+
+/// The answers of all days of a single year.
+#[derive(Debug, Default)]
+pub struct Answers {
+    days: BTreeMap<u8, DayAnswers>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let tables: BTreeMap<String, DayAnswers> = toml::from_str(text)?;
+
+        let days = tables
+            .into_iter()
+            .map(|(day, answers)| -> Result<(u8, DayAnswers), Error> {
+                let number = day
+                    .parse()
+                    .map_err(|_| format!("'{day}' is not a day number"))?;
+                Ok((number, answers))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { days })
+    }
+
+    pub fn day(&self, day: u8) -> Option<&DayAnswers> {
+        self.days.get(&day)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<String> {
+        self.day(day)?.get(part)
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Option<bool> {
+        self.day(day)?.check(part, answer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "[02]\npart_one = 1451208\npart_two = 1620141160\n\n[13]\npart_one = 17\npart_two = \"\"\"\n#..#\n#..#\n\"\"\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(2, 1), Some(String::from("1451208")));
+        assert_eq!(answers.get(2, 2), Some(String::from("1620141160")));
+        assert_eq!(answers.get(13, 2), Some(String::from("#..#\n#..#\n")));
+        assert_eq!(answers.get(3, 1), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("[1]\npart_one = 7\npart_two = \"#.\\n.#\"\n").unwrap();
+
+        assert_eq!(answers.check(1, 1, &Answer::from(7u32)), Some(true));
+        assert_eq!(answers.check(1, 1, &Answer::from(8u32)), Some(false));
+        assert_eq!(answers.check(1, 2, &Answer::from("#. \n.#\n")), Some(true));
+        assert_eq!(answers.check(2, 1, &Answer::from(7u32)), None);
+    }
+
+    #[test]
+    fn test_invalid_day() {
+        assert!(Answers::parse("[first]\npart_one = 1\n").is_err());
+    }
+}
@@ -0,0 +1,83 @@
+//! Tests generated from the examples next to the inputs.
+//!
+//! Every `<day>-<name>.txt` in the input directory of a year is an example of that day. Its
+//! expected answers go in a sidecar `<day>-<name>.toml`, written like a single day of the answers
+//! file:
+//!
+//! ```toml
+//! part_one = 13
+//! part_two = 1
+//! ```
+//!
+//! The build script of a year crate calls [`generate`], which writes a test for every example and
+//! part. Parts without an expected answer get an ignored test, so `cargo test` lists them.
+
+use std::{fmt::Write, fs, path::Path};
+
+use crate::{input::Inputs, Error, Year};
+
+/// Writes the tests for all examples of a year to `out`, to be included in the crate of that year.
+pub fn generate(year: u16, out: &Path) -> Result<(), Error> {
+    let inputs = Inputs::default();
+    println!("cargo:rerun-if-env-changed={}", crate::input::ROOT_VAR);
+    println!(
+        "cargo:rerun-if-changed={}",
+        inputs.root().join(year.to_string()).display()
+    );
+
+    let mut tests = String::new();
+    for (day, name) in inputs.examples(year)? {
+        let answers = inputs.read_example_answers(year, day, &name)?;
+        let sidecar = inputs.example_answers_path(year, day, &name);
+        let sidecar = sidecar.file_name().unwrap_or_default().to_string_lossy();
+
+        for (part, part_name) in [(1, "one"), (2, "two")] {
+            writeln!(tests, "#[test]")?;
+            if answers.get(part).is_none() {
+                writeln!(tests, "#[ignore = \"no expected answer in {sidecar}\"]")?;
+            }
+            writeln!(
+                tests,
+                "fn day{day:02}_{}_part_{part_name}() {{",
+                name.replace(|chr: char| !chr.is_ascii_alphanumeric(), "_")
+            )?;
+            writeln!(
+                tests,
+                "    aoc_core::examples::check(&crate::YEAR, {day}, {name:?}, {part});"
+            )?;
+            writeln!(tests, "}}")?;
+            writeln!(tests)?;
+        }
+    }
+
+    fs::write(out, tests)?;
+    Ok(())
+}
+
+/// Runs a part on an example and panics when the answer is not the expected one.
+pub fn check(year: &Year, day: u8, name: &str, part: u8) {
+    let inputs = Inputs::default();
+    let input = inputs
+        .read_example(year.year, day, name)
+        .unwrap_or_else(|err| panic!("{err}"));
+    let answers = inputs
+        .read_example_answers(year.year, day, name)
+        .unwrap_or_else(|err| panic!("{err}"));
+    let expected = answers
+        .get(part)
+        .unwrap_or_else(|| panic!("No expected answer for part {part} of {day:02}-{name}"));
+
+    let solution = year
+        .day(day)
+        .unwrap_or_else(|| panic!("No solution for day {day} of {}", year.year));
+    let answer = match part {
+        1 => solution.part_one(&input),
+        _ => solution.part_two(&input),
+    }
+    .unwrap_or_else(|err| panic!("Part {part} failed on {day:02}-{name}: {err}"));
+
+    assert!(
+        answers.check(part, &answer) == Some(true),
+        "Part {part} of {day:02}-{name}: expected {expected}, got {answer}"
+    );
+}
@@ -0,0 +1,200 @@
+//! Finding the puzzle inputs.
+//!
+//! Inputs are stored as `<root>/<year>/<day>.txt`, with the examples of a day next to it as
+//! `<day>-<name>.txt` (like `12-first-example.txt`). The root is the `input` directory of the
+//! repository, unless `AOC_INPUT_DIR` points somewhere else.
+
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::{Answers, DayAnswers},
+    Error,
+};
+
+/// Environment variable overriding the input root.
+pub const ROOT_VAR: &str = "AOC_INPUT_DIR";
+
+/// Path that reads from stdin instead of a file.
+pub const STDIN: &str = "-";
+
+/// Returned when an input could not be read, names the path that was tried.
+#[derive(Debug)]
+pub struct InputError {
+    path: PathBuf,
+    source: io::Error,
+}
+
+impl InputError {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.path == Path::new(STDIN) {
+            true => write!(f, "Could not read stdin: {}", self.source),
+            false => write!(f, "Could not read {}: {}", self.path.display(), self.source),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Resolves inputs relative to a root directory.
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    root: PathBuf,
+}
+
+impl Default for Inputs {
+    /// Uses `AOC_INPUT_DIR` when it is set and the `input` directory of the repository otherwise.
+    fn default() -> Self {
+        match env::var_os(ROOT_VAR) {
+            Some(root) => Self::new(root),
+            None => Self::new(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .parent()
+                    .expect("Crate is not part of the workspace")
+                    .join("input"),
+            ),
+        }
+    }
+}
+
+impl Inputs {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("{day:02}.txt"))
+    }
+
+    pub fn example_path(&self, year: u16, day: u8, name: &str) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("{day:02}-{name}.txt"))
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<String, InputError> {
+        read(self.path(year, day))
+    }
+
+    pub fn read_example(&self, year: u16, day: u8, name: &str) -> Result<String, InputError> {
+        read(self.example_path(year, day, name))
+    }
+
+    /// Sidecar of an example with its expected answers.
+    pub fn example_answers_path(&self, year: u16, day: u8, name: &str) -> PathBuf {
+        self.example_path(year, day, name).with_extension("toml")
+    }
+
+    pub fn read_example_answers(
+        &self,
+        year: u16,
+        day: u8,
+        name: &str,
+    ) -> Result<DayAnswers, Error> {
+        let path = self.example_answers_path(year, day, name);
+        match fs::read_to_string(&path) {
+            Ok(text) => DayAnswers::parse(&text)
+                .map_err(|err| format!("Could not parse {}: {err}", path.display()).into()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(DayAnswers::default()),
+            Err(source) => Err(InputError { path, source }.into()),
+        }
+    }
+
+    /// Day and name of all examples of a year, sorted by day.
+    pub fn examples(&self, year: u16) -> Result<Vec<(u8, String)>, InputError> {
+        let directory = self.root.join(year.to_string());
+        let entries = match fs::read_dir(&directory) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(source) => {
+                return Err(InputError {
+                    path: directory,
+                    source,
+                })
+            }
+        };
+
+        let mut examples = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|source| InputError {
+                path: directory.clone(),
+                source,
+            })?;
+            let file_name = entry.file_name();
+            let Some(stem) = file_name
+                .to_str()
+                .and_then(|name| name.strip_suffix(".txt"))
+            else {
+                continue;
+            };
+
+            if let Some((day, name)) = stem.split_once('-') {
+                if let Ok(day) = day.parse() {
+                    examples.push((day, name.to_owned()));
+                }
+            }
+        }
+        examples.sort();
+
+        Ok(examples)
+    }
+
+    pub fn answers_path(&self, year: u16) -> PathBuf {
+        self.root.join(year.to_string()).join("answers.toml")
+    }
+
+    /// The known answers of a year, a year without an answers file has no known answers.
+    pub fn read_answers(&self, year: u16) -> Result<Answers, Error> {
+        let path = self.answers_path(year);
+        match fs::read_to_string(&path) {
+            Ok(text) => Answers::parse(&text)
+                .map_err(|err| format!("Could not parse {}: {err}", path.display()).into()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(source) => Err(InputError { path, source }.into()),
+        }
+    }
+}
+
+/// Reads an input from the given path, or from stdin when the path is `-`.
+pub fn read(path: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = path.as_ref();
+    let result = match path == Path::new(STDIN) {
+        true => io::read_to_string(io::stdin()),
+        false => fs::read_to_string(path),
+    };
+
+    result.map_err(|source| InputError {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+/// The puzzle input of a day, from the default root.
+pub fn puzzle(year: u16, day: u8) -> Result<String, InputError> {
+    Inputs::default().read(year, day)
+}
+
+/// A named example of a day, from the default root.
+pub fn example(year: u16, day: u8, name: &str) -> Result<String, InputError> {
+    Inputs::default().read_example(year, day, name)
+}
@@ -0,0 +1,221 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    io::{BufRead, Write},
+};
+
+pub mod answers;
+pub mod examples;
+pub mod input;
+
+/// The answer to a single part of a puzzle.
+///
+/// Most puzzles have a numeric answer, some (like the ones that draw letters on a screen) have a
+/// textual one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Signed(value as i64)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Unsigned(value as u64)
+            }
+        })*
+    };
+}
+
+impl_from_signed!(i8, i16, i32, i64, isize);
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+/// Error returned by a part, most of them just pass on whatever went wrong while parsing the input.
+pub type Error = Box<dyn std::error::Error>;
+
+/// Returned by parts that have not been solved (yet).
+#[derive(Debug)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Not solved yet")
+    }
+}
+
+impl std::error::Error for Unsolved {}
+
+/// A single day of a year, with its two parts.
+///
+/// Both parts get the puzzle input as text, so they can be run on the real input just as easily
+/// as on an example.
+pub trait Solution: Sync {
+    fn part_one(&self, input: &str) -> Result<Answer, Error>;
+
+    fn part_two(&self, _input: &str) -> Result<Answer, Error> {
+        Err(Unsolved.into())
+    }
+
+    /// Only parses the input, so parsing can be benchmarked apart from the parts.
+    ///
+    /// Days that parse while solving have no separate parse step and return `None`.
+    fn parse(&self, _input: &str) -> Option<Result<(), Error>> {
+        None
+    }
+
+    /// How the given part found its answer, for days that search for it.
+    ///
+    /// Days that do not search return `None`.
+    fn explain(&self, _part: u8, _input: &str) -> Option<Result<Explanation, Error>> {
+        None
+    }
+
+    /// Renders the puzzle as a file in the given format (like `dot`), showing the state after the
+    /// given part or just the input when there is no part.
+    ///
+    /// Days return `None` for the formats they cannot render.
+    fn render(
+        &self,
+        _input: &str,
+        _part: Option<u8>,
+        _format: &str,
+    ) -> Option<Result<Vec<u8>, Error>> {
+        None
+    }
+
+    /// Renders every step of the puzzle as a numbered file in the given format (like `png`),
+    /// from the input up to the end of the given part or just the input when there is no part.
+    ///
+    /// Days return `None` for the formats they cannot render step by step.
+    fn frames(
+        &self,
+        _input: &str,
+        _part: Option<u8>,
+        _format: &str,
+    ) -> Option<Result<Vec<Vec<u8>>, Error>> {
+        None
+    }
+
+    /// Answers a variation of the puzzle, described by options like `--exclude-value red`.
+    ///
+    /// Days without variations return `None`.
+    fn query(&self, _input: &str, _options: &[QueryOption]) -> Option<Result<Answer, Error>> {
+        None
+    }
+
+    /// Runs an interactive debugger on the input, reading its commands line by line and writing
+    /// what happens.
+    ///
+    /// Days without a debugger return `None`.
+    fn debug(
+        &self,
+        _input: &str,
+        _commands: &mut dyn BufRead,
+        _output: &mut dyn Write,
+    ) -> Option<Result<(), Error>> {
+        None
+    }
+
+    /// Names of other ways to solve the day that are worth measuring next to the parts, like the
+    /// naive version of a part that was sped up.
+    ///
+    /// Days without variants return no names.
+    fn variants(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Runs one of the [`Solution::variants`] on the input.
+    ///
+    /// Returns `None` for names that are not a variant of the day.
+    fn variant(&self, _name: &str, _input: &str) -> Option<Result<Answer, Error>> {
+        None
+    }
+}
+
+/// A single option of a query, without its leading dashes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryOption {
+    pub name: String,
+    /// Flags like `--only-arrays` have no value.
+    pub value: Option<String>,
+}
+
+/// The winning sequence found by a search, with the amount of work it took to find it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Explanation {
+    pub steps: Vec<String>,
+    /// States of which the successors were generated.
+    pub expanded: usize,
+    /// States that were queued to be expanded.
+    pub pushed: usize,
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self.steps.len().to_string().len();
+        for (number, step) in self.steps.iter().enumerate() {
+            writeln!(f, "{:>width$}. {step}", number + 1)?;
+        }
+        write!(
+            f,
+            "{} steps, {} states expanded, {} states pushed",
+            self.steps.len(),
+            self.expanded,
+            self.pushed
+        )
+    }
+}
+
+/// Throws away a parsed input in [`Solution::parse`] without letting the parsing be optimized away.
+pub fn parsed<T>(value: T) {
+    drop(black_box(value));
+}
+
+/// All days of a single year, as exposed by the crate of that year.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [(u8, &'static dyn Solution)],
+}
+
+impl Year {
+    pub fn day(&self, day: u8) -> Option<&'static dyn Solution> {
+        self.days
+            .iter()
+            .find(|(number, _)| *number == day)
+            .map(|(_, solution)| *solution)
+    }
+}
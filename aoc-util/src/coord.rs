@@ -0,0 +1,133 @@
+//! Coordinates on a plane or in a space with any number of dimensions.
+
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// A point (or the offset between two points) on a plane.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Coord<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Coord<i64> {
+    /// Distance when only moving horizontally and vertically.
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The offset of at most one step in either direction towards the sign of every axis.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl<T: Add<Output = T>> Add for Coord<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Add<Output = T> + Copy> AddAssign for Coord<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Coord<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T> + Copy> SubAssign for Coord<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<T> From<(T, T)> for Coord<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Coord<T>> for (T, T) {
+    fn from(coord: Coord<T>) -> Self {
+        (coord.x, coord.y)
+    }
+}
+
+/// The points one step away along a single axis, that stay within the inclusive bounds of every
+/// axis.
+pub fn axis_neighbours<T>(coord: &[T], bounds: &[(T, T)]) -> Vec<Vec<T>>
+where
+    T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    let one = T::from(1);
+
+    let mut neighbours = Vec::with_capacity(2 * coord.len());
+    for (axis, &(min, max)) in bounds.iter().enumerate() {
+        if coord[axis] > min {
+            let mut neighbour = coord.to_owned();
+            neighbour[axis] = coord[axis] - one;
+            neighbours.push(neighbour);
+        }
+        if coord[axis] < max {
+            let mut neighbour = coord.to_owned();
+            neighbour[axis] = coord[axis] + one;
+            neighbours.push(neighbour);
+        }
+    }
+
+    neighbours
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut coord = Coord::new(1, 2);
+        coord += Coord::new(3, -4);
+        assert_eq!(coord, Coord::new(4, -2));
+        assert_eq!(coord - Coord::new(4, 4), Coord::new(0, -6));
+        assert_eq!(Coord::new(-3, 0).signum(), Coord::new(-1, 0));
+    }
+
+    #[test]
+    fn test_manhattan() {
+        assert_eq!(Coord::new(2, 18).manhattan(Coord::new(-2, 15)), 7);
+    }
+
+    #[test]
+    fn test_axis_neighbours() {
+        let bounds = [(0i16, 2), (0, 2), (0, 2)];
+
+        assert_eq!(
+            axis_neighbours(&[1, 1, 1], &bounds),
+            vec![
+                vec![0, 1, 1],
+                vec![2, 1, 1],
+                vec![1, 0, 1],
+                vec![1, 2, 1],
+                vec![1, 1, 0],
+                vec![1, 1, 2],
+            ]
+        );
+        assert_eq!(
+            axis_neighbours(&[0, 2, 0], &bounds),
+            vec![vec![1, 2, 0], vec![0, 1, 0], vec![0, 2, 1]]
+        );
+    }
+}
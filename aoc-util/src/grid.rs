@@ -0,0 +1,530 @@
+//! Grids stored row by row in a flat `Vec`.
+//!
+//! [`Grid`] keeps the cells together with the dimensions, the free functions are for days that
+//! keep their own storage.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+/// Offsets of the horizontal and vertical neighbours, in the order left, right, up, down.
+const OFFSETS4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Offsets of all neighbours, column by column like [`neighbours8`].
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Index of `(x, y)` in a grid with the given number of columns.
+pub fn index(x: usize, y: usize, width: usize) -> usize {
+    y * width + x
+}
+
+/// The `(x, y)` of an index in a grid with the given number of columns.
+pub fn coord(index: usize, width: usize) -> (usize, usize) {
+    (index % width, index / width)
+}
+
+/// The horizontal and vertical neighbours of `(x, y)` that lie within the grid.
+///
+/// They come in the order left, right, up, down.
+pub fn neighbours4(
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    [
+        (x > 0).then(|| (x - 1, y)),
+        (x + 1 < width).then_some((x + 1, y)),
+        (y > 0).then(|| (x, y - 1)),
+        (y + 1 < height).then_some((x, y + 1)),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// The horizontal, vertical and diagonal neighbours of `(x, y)` that lie within the grid.
+///
+/// They come column by column, from the top of the left column to the bottom of the right one.
+pub fn neighbours8(
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    (x.saturating_sub(1)..=usize::min(width - 1, x + 1))
+        .flat_map(move |n_x| {
+            (y.saturating_sub(1)..=usize::min(height - 1, y + 1)).map(move |n_y| (n_x, n_y))
+        })
+        .filter(move |&neighbour| neighbour != (x, y))
+}
+
+/// Returned when text could not be parsed into a [`Grid`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError {
+    Empty,
+    Ragged { y: usize, width: usize },
+    InvalidChar { chr: char, x: usize, y: usize },
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "Grid has no cells"),
+            ParseGridError::Ragged { y, width } => {
+                write!(f, "Row {y} of the grid is not {width} cells wide")
+            }
+            ParseGridError::InvalidChar { chr, x, y } => {
+                write!(f, "Invalid character '{chr}' at ({x}, {y}) in the grid")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+/// A rectangular grid of cells, addressed by `(x, y)` with the origin in the top left.
+///
+/// Indexing with `grid[(x, y)]` panics outside of the grid, [`Grid::get`] does not. A wrapping grid
+/// is a torus: stepping off one edge comes back in on the opposite one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    wrapping: bool,
+}
+
+impl<T> Grid<T> {
+    /// A grid of the given width with the cells row by row, panics when they do not fill the rows.
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of {width}",
+            cells.len()
+        );
+
+        Self {
+            height: cells.len() / width,
+            cells,
+            width,
+            wrapping: false,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+
+        Self::new(width, cells)
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, vec![value; width * height])
+    }
+
+    /// Parses a grid with a line per row, mapping every character to a cell.
+    pub fn parse(
+        input: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseGridError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+
+        for (y, line) in input.lines().enumerate() {
+            let start = cells.len();
+            for (x, chr) in line.chars().enumerate() {
+                cells.push(f(chr).ok_or(ParseGridError::InvalidChar { chr, x, y })?);
+            }
+
+            let row_width = cells.len() - start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseGridError::Ragged { y, width });
+                }
+                Some(_) => {}
+            }
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self::new(width, cells)),
+            _ => Err(ParseGridError::Empty),
+        }
+    }
+
+    /// Turns the grid into a torus for [`Grid::offset`] and everything built on it.
+    pub fn wrapping(mut self) -> Self {
+        self.wrapping = true;
+        self
+    }
+
+    pub fn is_wrapping(&self) -> bool {
+        self.wrapping
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cells row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn index(&self, x: usize, y: usize) -> usize {
+        index(x, y, self.width)
+    }
+
+    pub fn coord(&self, index: usize) -> (usize, usize) {
+        coord(index, self.width)
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[self.index(x, y)])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        match self.contains(x, y) {
+            true => {
+                let index = self.index(x, y);
+                Some(&mut self.cells[index])
+            }
+            false => None,
+        }
+    }
+
+    /// The position `dx` columns and `dy` rows away, `None` when it falls off a grid that does
+    /// not wrap.
+    pub fn offset(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        let x = x as isize + dx;
+        let y = y as isize + dy;
+        let (width, height) = (self.width as isize, self.height as isize);
+
+        match self.wrapping {
+            true => Some((x.rem_euclid(width) as usize, y.rem_euclid(height) as usize)),
+            false => ((0..width).contains(&x) && (0..height).contains(&y))
+                .then_some((x as usize, y as usize)),
+        }
+    }
+
+    /// The horizontal and vertical neighbours, in the order left, right, up, down.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS4
+            .iter()
+            .filter_map(move |&(dx, dy)| self.offset(x, y, dx, dy))
+    }
+
+    /// The horizontal, vertical and diagonal neighbours, column by column.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS8
+            .iter()
+            .filter_map(move |&(dx, dy)| self.offset(x, y, dx, dy))
+    }
+
+    /// The positions from `(x, y)` (not included) in steps of `(dx, dy)`, up to the edge of the
+    /// grid. Works for rows, columns and diagonals alike, a wrapping grid stops before returning to
+    /// `(x, y)`.
+    pub fn ray(
+        &self,
+        x: usize,
+        y: usize,
+        dx: isize,
+        dy: isize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        std::iter::successors(self.offset(x, y, dx, dy), move |&(n_x, n_y)| {
+            self.offset(n_x, n_y, dx, dy)
+        })
+        .take_while(move |&position| position != (x, y))
+        .take(self.width.max(self.height))
+    }
+
+    /// All positions row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// All cells row by row, with their positions.
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// A grid of the same shape with every cell mapped.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+            wrapping: self.wrapping,
+        }
+    }
+
+    /// Draws the grid with a character per cell and a line per row.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            output.extend(row.iter().map(&mut f));
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(self.contains(x, y), "({x}, {y}) is outside of the grid");
+        &self.cells[index(x, y, self.width)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(self.contains(x, y), "({x}, {y}) is outside of the grid");
+        &mut self.cells[index(x, y, self.width)]
+    }
+}
+
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = ParseGridError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input, |chr| T::try_from(chr).ok())
+    }
+}
+
+/// Writes every cell next to each other, with a line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbours4() {
+        assert_eq!(
+            neighbours4(50, 50, 100, 100).collect::<Vec<_>>(),
+            vec![(49, 50), (51, 50), (50, 49), (50, 51)]
+        );
+        assert_eq!(
+            neighbours4(0, 0, 100, 100).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            neighbours4(99, 99, 100, 100).collect::<Vec<_>>(),
+            vec![(98, 99), (99, 98)]
+        );
+    }
+
+    #[test]
+    fn test_neighbours8() {
+        let mut neighbours = neighbours8(50, 50, 100, 100);
+
+        assert_eq!(neighbours.next(), Some((49, 49)));
+        assert_eq!(neighbours.next(), Some((49, 50)));
+        assert_eq!(neighbours.next(), Some((49, 51)));
+        assert_eq!(neighbours.next(), Some((50, 49)));
+        assert_eq!(neighbours.next(), Some((50, 51)));
+        assert_eq!(neighbours.next(), Some((51, 49)));
+        assert_eq!(neighbours.next(), Some((51, 50)));
+        assert_eq!(neighbours.next(), Some((51, 51)));
+        assert_eq!(neighbours.next(), None);
+    }
+
+    #[test]
+    fn test_neighbours8_top_left() {
+        let mut neighbours = neighbours8(0, 0, 100, 100);
+
+        assert_eq!(neighbours.next(), Some((0, 1)));
+        assert_eq!(neighbours.next(), Some((1, 0)));
+        assert_eq!(neighbours.next(), Some((1, 1)));
+        assert_eq!(neighbours.next(), None);
+    }
+
+    #[test]
+    fn test_neighbours8_bottom_right() {
+        let mut neighbours = neighbours8(99, 99, 100, 100);
+
+        assert_eq!(neighbours.next(), Some((98, 98)));
+        assert_eq!(neighbours.next(), Some((98, 99)));
+        assert_eq!(neighbours.next(), Some((99, 98)));
+        assert_eq!(neighbours.next(), None);
+    }
+
+    #[test]
+    fn test_index() {
+        assert_eq!(index(0, 0, 100), 0);
+        assert_eq!(index(1, 0, 100), 1);
+        assert_eq!(index(0, 1, 100), 100);
+        assert_eq!(index(1, 1, 100), 101);
+    }
+
+    #[test]
+    fn test_coord() {
+        assert_eq!(coord(0, 100), (0, 0));
+        assert_eq!(coord(1, 100), (1, 0));
+        assert_eq!(coord(100, 100), (0, 1));
+        assert_eq!(coord(101, 100), (1, 1));
+    }
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |chr| chr.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get(1, 0), Some(&2));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse = |input| Grid::parse(input, |chr| chr.to_digit(10));
+
+        assert_eq!(parse(""), Err(ParseGridError::Empty));
+        assert_eq!(
+            parse("12\n3\n"),
+            Err(ParseGridError::Ragged { y: 1, width: 2 })
+        );
+        assert_eq!(
+            parse("12\n3x\n"),
+            Err(ParseGridError::InvalidChar {
+                chr: 'x',
+                x: 1,
+                y: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_grid_neighbours() {
+        let grid = digits("123\n456\n789\n");
+
+        assert_eq!(
+            grid.neighbours4(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8(2, 2).collect::<Vec<_>>(),
+            neighbours8(2, 2, 3, 3).collect::<Vec<_>>()
+        );
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid = digits("123\n456\n789\n").wrapping();
+
+        assert_eq!(grid.offset(2, 0, 1, -1), Some((0, 2)));
+        assert_eq!(
+            grid.neighbours4(0, 0).collect::<Vec<_>>(),
+            vec![(2, 0), (1, 0), (0, 2), (0, 1)]
+        );
+        assert_eq!(
+            grid.ray(1, 1, 1, 0).collect::<Vec<_>>(),
+            vec![(2, 1), (0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = digits("123\n456\n789\n");
+
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5, 8]);
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(
+            grid.ray(0, 0, 1, 1)
+                .map(|position| grid[position])
+                .collect::<Vec<_>>(),
+            vec![5, 9]
+        );
+        assert_eq!(
+            grid.ray(1, 2, 0, -1).collect::<Vec<_>>(),
+            vec![(1, 1), (1, 0)]
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = Grid::from_fn(3, 2, |x, y| x == y);
+
+        assert_eq!(
+            grid.render(|&lit| if lit { '#' } else { '.' }),
+            "#..\n.#.\n"
+        );
+    }
+}
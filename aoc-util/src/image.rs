@@ -0,0 +1,244 @@
+//! Grayscale images, written as PBM, PGM or PNG without any dependencies.
+//!
+//! The PNG is not compressed, which keeps the writer small and is fine for the sizes of the
+//! puzzles.
+
+/// The formats an [`Image`] can be written in, by their file extensions.
+pub const FORMATS: [&str; 3] = ["pbm", "pgm", "png"];
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Most bytes in a stored deflate block.
+const STORED_BLOCK: usize = u16::MAX as usize;
+
+const CRC_TABLE: [u32; 256] = crc_table();
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut crc = n as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                0xedb8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[n] = crc;
+        n += 1;
+    }
+    table
+}
+
+/// The CRC-32 that ends every PNG chunk.
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, &byte| {
+        CRC_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+/// The Adler-32 that ends a zlib stream.
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+/// A grayscale image, row by row with a byte per pixel that goes from black at 0 to white at 255.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Panics when the number of pixels does not match the dimensions.
+    pub fn new(width: usize, height: usize, pixels: Vec<u8>) -> Self {
+        assert_eq!(
+            pixels.len(),
+            width * height,
+            "Image is not {width}x{height}"
+        );
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// An image with white pixels for the cells that are on.
+    pub fn from_bools(width: usize, height: usize, cells: impl IntoIterator<Item = bool>) -> Self {
+        let pixels = cells.into_iter().map(|on| if on { 255 } else { 0 });
+        Self::new(width, height, pixels.collect())
+    }
+
+    /// An image with the levels scaled so the highest one is white.
+    pub fn from_levels(width: usize, height: usize, levels: &[u64]) -> Self {
+        let max = levels.iter().copied().max().unwrap_or_default().max(1) as u128;
+        let pixels = levels
+            .iter()
+            .map(|&level| (level as u128 * 255 / max) as u8)
+            .collect();
+        Self::new(width, height, pixels)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Writes the image in one of the [`FORMATS`], `None` for any other format.
+    pub fn encode(&self, format: &str) -> Option<Vec<u8>> {
+        match format {
+            "pbm" => Some(self.to_pbm()),
+            "pgm" => Some(self.to_pgm()),
+            "png" => Some(self.to_png()),
+            _ => None,
+        }
+    }
+
+    /// A binary PBM, where the pixels from 128 on are white and the others black.
+    pub fn to_pbm(&self) -> Vec<u8> {
+        let mut bytes = format!("P4\n{} {}\n", self.width, self.height).into_bytes();
+        if self.width == 0 {
+            return bytes;
+        }
+        for row in self.pixels.chunks(self.width) {
+            // Every row starts at a new byte, and a set bit is black
+            bytes.extend(row.chunks(8).map(|pixels| {
+                pixels
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &pixel)| pixel < 128)
+                    .fold(0u8, |byte, (bit, _)| byte | 0x80 >> bit)
+            }));
+        }
+        bytes
+    }
+
+    /// A binary PGM.
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let mut bytes = format!("P5\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.extend_from_slice(&self.pixels);
+        bytes
+    }
+
+    /// An 8-bit grayscale PNG.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&(self.width as u32).to_be_bytes());
+        header.extend_from_slice(&(self.height as u32).to_be_bytes());
+        // Bit depth 8, grayscale, deflate, no filters and no interlacing
+        header.extend_from_slice(&[8, 0, 0, 0, 0]);
+
+        // Every row starts with the filter it uses, which is none
+        let mut scanlines = Vec::with_capacity((self.width + 1) * self.height);
+        if self.width > 0 {
+            for row in self.pixels.chunks(self.width) {
+                scanlines.push(0);
+                scanlines.extend_from_slice(row);
+            }
+        }
+
+        let mut bytes = PNG_SIGNATURE.to_vec();
+        write_chunk(&mut bytes, b"IHDR", &header);
+        write_chunk(&mut bytes, b"IDAT", &zlib_stored(&scanlines));
+        write_chunk(&mut bytes, b"IEND", &[]);
+        bytes
+    }
+}
+
+fn write_chunk(bytes: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    bytes.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = bytes.len();
+    bytes.extend_from_slice(kind);
+    bytes.extend_from_slice(data);
+    let crc = crc32(&bytes[start..]);
+    bytes.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// A zlib stream of stored deflate blocks, so without any compression.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let blocks = data.len().div_ceil(STORED_BLOCK).max(1);
+    let mut bytes = Vec::with_capacity(data.len() + 5 * blocks + 6);
+    // Deflate with a 32K window and the check bits that make the header a multiple of 31
+    bytes.extend_from_slice(&[0x78, 0x01]);
+    for block in 0..blocks {
+        let chunk = &data[block * STORED_BLOCK..data.len().min((block + 1) * STORED_BLOCK)];
+        bytes.push((block + 1 == blocks) as u8);
+        bytes.extend_from_slice(&(chunk.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&(!(chunk.len() as u16)).to_le_bytes());
+        bytes.extend_from_slice(chunk);
+    }
+    bytes.extend_from_slice(&adler32(data).to_be_bytes());
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_pbm() {
+        let image = Image::from_bools(10, 2, (0..20).map(|cell| cell % 3 == 0));
+        assert_eq!(image.to_pbm(), b"P4\n10 2\n\x6d\x80\xdb\x40".to_vec());
+    }
+
+    #[test]
+    fn test_levels() {
+        let image = Image::from_levels(3, 1, &[0, 5, 10]);
+        assert_eq!(image.pixels(), &[0, 127, 255]);
+        assert_eq!(image.to_pgm(), b"P5\n3 1\n255\n\x00\x7f\xff".to_vec());
+        assert_eq!(Image::from_levels(1, 1, &[0]).pixels(), &[0]);
+    }
+
+    #[test]
+    fn test_png() {
+        let image = Image::new(2, 1, vec![0, 255]);
+        let png = image.to_png();
+        assert_eq!(png[..8], PNG_SIGNATURE);
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[37..41], b"IDAT");
+        // Filter, pixel, pixel inside a single final stored block
+        assert_eq!(
+            &png[41..52],
+            &[0x78, 0x01, 1, 3, 0, 0xfc, 0xff, 0, 0, 255, 0x01]
+        );
+        assert_eq!(
+            &png[png.len() - 8..],
+            &[b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]
+        );
+    }
+
+    #[test]
+    fn test_stored_blocks() {
+        let data = vec![7; STORED_BLOCK + 10];
+        let stream = zlib_stored(&data);
+        assert_eq!(stream.len(), 2 + 5 + STORED_BLOCK + 5 + 10 + 4);
+        assert_eq!(&stream[2..7], &[0, 0xff, 0xff, 0, 0]);
+        assert_eq!(
+            &stream[7 + STORED_BLOCK..12 + STORED_BLOCK],
+            &[1, 10, 0, 0xf5, 0xff]
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! Helpers shared by the solutions of all years.
+
+pub mod coord;
+pub mod grid;
+pub mod image;
+pub mod search;
+pub mod words;
@@ -0,0 +1,421 @@
+//! Shortest paths through graphs that are only known by the successors of their states.
+//!
+//! A [`Graph`] can be anything from the positions on a map to the states of a game. [`bfs`] finds
+//! the path with the fewest steps, [`dijkstra`] the cheapest one and [`astar`] the cheapest one
+//! guided by [`Graph::heuristic`]. [`dfs`] finds any path, which is all some puzzles need.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::{Add, Sub},
+};
+
+use aoc_core::Explanation;
+
+pub trait Graph {
+    type State: Clone + Eq + Hash;
+    type Cost: Copy + Ord + Default + Add<Output = Self::Cost>;
+
+    /// The states reachable in a single step, with the cost of that step.
+    fn successors(&self, state: &Self::State) -> impl Iterator<Item = (Self::State, Self::Cost)>;
+
+    /// Lower bound of the cost from the state to the nearest goal, only used by [`astar`].
+    ///
+    /// When it overestimates, the path that is found might not be the cheapest one.
+    fn heuristic(&self, _state: &Self::State) -> Self::Cost {
+        Self::Cost::default()
+    }
+}
+
+/// A path from one of the start states to a goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+    /// Cost of reaching every state of the path from the start.
+    pub costs: Vec<C>,
+    pub stats: Stats,
+}
+
+/// How much work the search did to find a path.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// States of which the successors were generated.
+    pub expanded: usize,
+    /// States that were queued to be expanded, a state is queued again when a cheaper way to it
+    /// is found.
+    pub pushed: usize,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn start(&self) -> &S {
+        self.states.first().expect("Path has no states")
+    }
+
+    pub fn goal(&self) -> &S {
+        self.states.last().expect("Path has no states")
+    }
+
+    /// Number of steps taken, one less than the number of states.
+    pub fn len(&self) -> usize {
+        self.states.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The consecutive states of the path with the cost of the step between them.
+    pub fn steps(&self) -> impl Iterator<Item = (&S, &S, C)> + '_
+    where
+        C: Copy + Sub<Output = C>,
+    {
+        self.states
+            .windows(2)
+            .zip(self.costs.windows(2))
+            .map(|(states, costs)| (&states[0], &states[1], costs[1] - costs[0]))
+    }
+
+    /// Describes the steps of the path, steps that are described as `None` are left out.
+    pub fn explain(&self, mut describe: impl FnMut(&S, &S, C) -> Option<String>) -> Explanation
+    where
+        C: Copy + Sub<Output = C>,
+    {
+        Explanation {
+            steps: self
+                .steps()
+                .filter_map(|(from, to, cost)| describe(from, to, cost))
+                .collect(),
+            expanded: self.stats.expanded,
+            pushed: self.stats.pushed,
+        }
+    }
+}
+
+/// Every state that was reached, with the cheapest known way to reach it.
+struct Explored<S, C> {
+    states: Vec<S>,
+    costs: Vec<C>,
+    parents: Vec<Option<usize>>,
+    nodes: HashMap<S, usize>,
+    stats: Stats,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + Ord> Explored<S, C> {
+    fn new() -> Self {
+        Self {
+            states: Vec::new(),
+            costs: Vec::new(),
+            parents: Vec::new(),
+            nodes: HashMap::new(),
+            stats: Stats::default(),
+        }
+    }
+
+    /// Records reaching the state, returns its node when this is the first or a cheaper way.
+    fn reach(&mut self, state: S, cost: C, parent: Option<usize>) -> Option<usize> {
+        match self.nodes.entry(state) {
+            Entry::Occupied(entry) => {
+                let node = *entry.get();
+                (cost < self.costs[node]).then(|| {
+                    self.costs[node] = cost;
+                    self.parents[node] = parent;
+                    node
+                })
+            }
+            Entry::Vacant(entry) => {
+                let node = self.states.len();
+                self.states.push(entry.key().clone());
+                self.costs.push(cost);
+                self.parents.push(parent);
+                entry.insert(node);
+                Some(node)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<S, C> {
+        let mut nodes = vec![goal];
+        while let Some(parent) = self.parents[nodes[nodes.len() - 1]] {
+            nodes.push(parent);
+        }
+        nodes.reverse();
+
+        Path {
+            cost: self.costs[goal],
+            states: nodes
+                .iter()
+                .map(|&node| self.states[node].clone())
+                .collect(),
+            costs: nodes.iter().map(|&node| self.costs[node]).collect(),
+            stats: self.stats,
+        }
+    }
+}
+
+/// The path with the fewest steps from any of the start states to a goal, ignoring the costs of
+/// the steps. The cost of the path is its number of steps.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::State>,
+    mut is_goal: impl FnMut(&G::State) -> bool,
+) -> Option<Path<G::State, usize>> {
+    let mut explored = Explored::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Some(node) = explored.reach(start, 0, None) {
+            if is_goal(&explored.states[node]) {
+                return Some(explored.path(node));
+            }
+            queue.push_back(node);
+            explored.stats.pushed += 1;
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let state = explored.states[node].clone();
+        let steps = explored.costs[node] + 1;
+        explored.stats.expanded += 1;
+
+        for (next, _) in graph.successors(&state) {
+            if explored.nodes.contains_key(&next) {
+                continue;
+            }
+
+            let next = explored
+                .reach(next, steps, Some(node))
+                .expect("Unexplored state was not reached");
+            if is_goal(&explored.states[next]) {
+                return Some(explored.path(next));
+            }
+            queue.push_back(next);
+            explored.stats.pushed += 1;
+        }
+    }
+
+    None
+}
+
+/// Some path from any of the start states to a goal, following the last successor as deep as
+/// possible before trying the others. The path is the first one found, not necessarily the
+/// shortest. The cost of the path is its number of steps.
+pub fn dfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::State>,
+    mut is_goal: impl FnMut(&G::State) -> bool,
+) -> Option<Path<G::State, usize>> {
+    let mut explored = Explored::new();
+    let mut stack = Vec::new();
+
+    for start in starts {
+        if let Some(node) = explored.reach(start, 0, None) {
+            if is_goal(&explored.states[node]) {
+                return Some(explored.path(node));
+            }
+            stack.push(node);
+            explored.stats.pushed += 1;
+        }
+    }
+
+    while let Some(node) = stack.pop() {
+        let state = explored.states[node].clone();
+        let steps = explored.costs[node] + 1;
+        explored.stats.expanded += 1;
+
+        for (next, _) in graph.successors(&state) {
+            if explored.nodes.contains_key(&next) {
+                continue;
+            }
+
+            let next = explored
+                .reach(next, steps, Some(node))
+                .expect("Unexplored state was not reached");
+            if is_goal(&explored.states[next]) {
+                return Some(explored.path(next));
+            }
+            stack.push(next);
+            explored.stats.pushed += 1;
+        }
+    }
+
+    None
+}
+
+/// The cheapest path from any of the start states to a goal.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::State>,
+    is_goal: impl FnMut(&G::State) -> bool,
+) -> Option<Path<G::State, G::Cost>> {
+    best_first(graph, starts, is_goal, |_| G::Cost::default())
+}
+
+/// The cheapest path from any of the start states to a goal, exploring the states that look
+/// closest to a goal according to [`Graph::heuristic`] first.
+pub fn astar<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::State>,
+    is_goal: impl FnMut(&G::State) -> bool,
+) -> Option<Path<G::State, G::Cost>> {
+    best_first(graph, starts, is_goal, |state| graph.heuristic(state))
+}
+
+fn best_first<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::State>,
+    mut is_goal: impl FnMut(&G::State) -> bool,
+    heuristic: impl Fn(&G::State) -> G::Cost,
+) -> Option<Path<G::State, G::Cost>> {
+    let mut explored = Explored::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        let priority = heuristic(&start);
+        if let Some(node) = explored.reach(start, G::Cost::default(), None) {
+            queue.push(Reverse((priority, G::Cost::default(), node)));
+            explored.stats.pushed += 1;
+        }
+    }
+
+    while let Some(Reverse((_, cost, node))) = queue.pop() {
+        // A cheaper way to this state was found after this one was queued
+        if cost > explored.costs[node] {
+            continue;
+        }
+
+        let state = explored.states[node].clone();
+        if is_goal(&state) {
+            return Some(explored.path(node));
+        }
+        explored.stats.expanded += 1;
+
+        for (next, step) in graph.successors(&state) {
+            let next_cost = cost + step;
+            let priority = next_cost + heuristic(&next);
+            if let Some(next) = explored.reach(next, next_cost, Some(node)) {
+                queue.push(Reverse((priority, next_cost, next)));
+                explored.stats.pushed += 1;
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    /// Walks over the digits of a grid, entering a position costs its digit.
+    struct Walk(Grid<u32>);
+
+    impl Graph for Walk {
+        type State = (usize, usize);
+        type Cost = u32;
+
+        fn successors(&self, &(x, y): &Self::State) -> impl Iterator<Item = (Self::State, u32)> {
+            self.0
+                .neighbours4(x, y)
+                .map(|position| (position, self.0[position]))
+        }
+
+        fn heuristic(&self, &(x, y): &Self::State) -> u32 {
+            ((self.0.width() - 1 - x) + (self.0.height() - 1 - y)) as u32
+        }
+    }
+
+    fn walk() -> Walk {
+        let grid = Grid::parse("1163751\n1381373\n2136511\n3694931\n", |chr| {
+            chr.to_digit(10)
+        });
+        Walk(grid.unwrap())
+    }
+
+    fn is_corner(state: &(usize, usize)) -> bool {
+        *state == (6, 3)
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs(&walk(), [(0, 0)], is_corner).unwrap();
+
+        assert_eq!(path.cost, 9);
+        assert_eq!(path.len(), 9);
+        assert_eq!((path.start(), path.goal()), (&(0, 0), &(6, 3)));
+        assert_eq!(path.costs, (0..=9).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_dfs() {
+        let walk = walk();
+        let path = dfs(&walk, [(0, 0)], is_corner).unwrap();
+
+        assert_eq!(path.cost, path.len());
+        assert_eq!((path.start(), path.goal()), (&(0, 0), &(6, 3)));
+        assert!(path
+            .states
+            .windows(2)
+            .all(|step| walk.successors(&step[0]).any(|(next, _)| next == step[1])));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let walk = walk();
+        let path = dijkstra(&walk, [(0, 0)], is_corner).unwrap();
+
+        assert_eq!(path.cost, 21);
+        assert_eq!(
+            path.states[1..]
+                .iter()
+                .map(|&position| walk.0[position])
+                .sum::<u32>(),
+            21
+        );
+        assert!(path
+            .states
+            .windows(2)
+            .all(|step| walk.successors(&step[0]).any(|(next, _)| next == step[1])));
+        assert!(path
+            .steps()
+            .all(|(_, &position, cost)| cost == walk.0[position]));
+        assert_eq!(path.costs.last(), Some(&21));
+    }
+
+    #[test]
+    fn test_stats() {
+        let walk = walk();
+        let dijkstra = dijkstra(&walk, [(0, 0)], is_corner).unwrap().stats;
+        let astar = astar(&walk, [(0, 0)], is_corner).unwrap().stats;
+
+        assert!(dijkstra.expanded <= dijkstra.pushed);
+        assert!(astar.expanded <= dijkstra.expanded);
+    }
+
+    #[test]
+    fn test_astar() {
+        let walk = walk();
+
+        assert_eq!(
+            astar(&walk, [(0, 0)], is_corner).map(|path| path.cost),
+            dijkstra(&walk, [(0, 0)], is_corner).map(|path| path.cost)
+        );
+    }
+
+    #[test]
+    fn test_multiple_starts() {
+        let path = dijkstra(&walk(), [(0, 0), (5, 3)], is_corner).unwrap();
+
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.states, vec![(5, 3), (6, 3)]);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        assert_eq!(bfs(&walk(), [(0, 0)], |_| false), None);
+        assert_eq!(dfs(&walk(), [(0, 0)], |_| false), None);
+        assert_eq!(dijkstra(&walk(), [(0, 0)], |_| false), None);
+    }
+}
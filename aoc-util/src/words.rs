@@ -0,0 +1,164 @@
+//! Reading instructions word by word, with errors that point at the word that was not expected.
+//!
+//! [`Words`] only splits a line into words, the days read their own operands from it.
+
+use std::fmt::{self, Display};
+
+/// Returned when a line is not a valid instruction, points at the word that was not expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: &'static str,
+    /// The unexpected word, `None` at the end of the line
+    pub found: Option<String>,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let found = match &self.found {
+            Some(found) => format!("`{found}`"),
+            None => String::from("the end of the line"),
+        };
+        write!(
+            f,
+            "{}:{}: Expected {}, found {found}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses every line that is not blank, numbering the lines from 1.
+pub fn parse_lines<T>(
+    data: &str,
+    mut f: impl FnMut(usize, &str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    data.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| f(index + 1, line))
+        .collect()
+}
+
+/// The words of a line, keeping track of the column they start at.
+pub struct Words<'a> {
+    line_number: usize,
+    line: &'a str,
+    offset: usize,
+    /// Characters that are a word by themselves, even without whitespace around them
+    separators: &'static [char],
+}
+
+impl<'a> Words<'a> {
+    pub fn new(line_number: usize, line: &'a str) -> Self {
+        Self {
+            line_number,
+            line,
+            offset: 0,
+            separators: &[],
+        }
+    }
+
+    /// Makes every one of the characters a word by itself, like the comma in `jie a, +4`.
+    pub fn with_separators(mut self, separators: &'static [char]) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// The word that is up next, without moving past it.
+    pub fn peek(&mut self) -> Option<&'a str> {
+        let rest = &self.line[self.offset..];
+        self.offset += rest.len() - rest.trim_start().len();
+        let rest = &self.line[self.offset..];
+        let separators = self.separators;
+        match rest.find(|chr: char| chr.is_whitespace() || separators.contains(&chr)) {
+            _ if rest.is_empty() => None,
+            Some(0) => Some(&rest[..rest.chars().next().map_or(0, char::len_utf8)]),
+            Some(end) => Some(&rest[..end]),
+            None => Some(rest),
+        }
+    }
+
+    /// Moves past the word that is up next.
+    pub fn advance(&mut self) {
+        if let Some(word) = self.peek() {
+            self.offset += word.len();
+        }
+    }
+
+    /// Moves past the word that is up next when it is the given one, an error otherwise.
+    pub fn expect(&mut self, word: &str, expected: &'static str) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(next) if next == word => {
+                self.advance();
+                Ok(())
+            }
+            _ => Err(self.unexpected(expected)),
+        }
+    }
+
+    /// Error for the word that is up next.
+    pub fn unexpected(&mut self, expected: &'static str) -> ParseError {
+        let found = self.peek().map(str::to_owned);
+        ParseError {
+            line: self.line_number,
+            column: self.line[..self.offset].chars().count() + 1,
+            expected,
+            found,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_words() {
+        let mut words = Words::new(1, "  jie a, +4").with_separators(&[',']);
+        let mut read = Vec::new();
+        while let Some(word) = words.peek() {
+            read.push(word);
+            words.advance();
+        }
+        assert_eq!(read, vec!["jie", "a", ",", "+4"]);
+
+        let mut words = Words::new(1, "x AND y");
+        assert_eq!(words.peek(), Some("x"));
+        words.advance();
+        assert_eq!(words.peek(), Some("AND"));
+        assert_eq!(words.peek(), Some("AND"));
+    }
+
+    #[test]
+    fn test_unexpected() {
+        let mut words = Words::new(3, "é -> b");
+        words.advance();
+        assert!(words.expect("->", "`->`").is_ok());
+        assert_eq!(
+            words.expect("->", "`->`").unwrap_err().to_string(),
+            "3:6: Expected `->`, found `b`"
+        );
+        words.advance();
+        assert_eq!(
+            words.unexpected("a wire"),
+            ParseError {
+                line: 3,
+                column: 7,
+                expected: "a wire",
+                found: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let lines = parse_lines("a\n\n  \nb", |line, text| Ok((line, text.to_owned())));
+        assert_eq!(
+            lines.unwrap(),
+            vec![(1, "a".to_owned()), (4, "b".to_owned())]
+        );
+    }
+}
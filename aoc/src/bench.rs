@@ -0,0 +1,265 @@
+//! Benchmarks of the parse step, both parts and the variants of every day, on the stable toolchain.
+
+use std::{
+    fmt::Write,
+    hint::black_box,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use aoc_core::{input::Inputs, Error, Solution, Unsolved, Year};
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+
+/// A single sample should take at least this long, faster steps are run several times per sample.
+const MIN_SAMPLE_TIME: Duration = Duration::from_millis(1);
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+pub struct Config {
+    pub warm_up: Duration,
+    pub measurement: Duration,
+    pub samples: usize,
+}
+
+#[derive(Clone, Copy)]
+pub enum Step {
+    Parse,
+    PartOne,
+    PartTwo,
+    /// One of the [`Solution::variants`] of the day
+    Variant(&'static str),
+}
+
+impl Step {
+    fn name(&self) -> &'static str {
+        match self {
+            Step::Parse => "parse",
+            Step::PartOne => "part_one",
+            Step::PartTwo => "part_two",
+            Step::Variant(name) => name,
+        }
+    }
+
+    /// Runs the step once, `None` when the day does not have it.
+    fn run(&self, solution: &dyn Solution, input: &str) -> Option<Result<(), Error>> {
+        let result = match self {
+            Step::Parse => return solution.parse(input),
+            Step::PartOne => solution.part_one(input),
+            Step::PartTwo => solution.part_two(input),
+            Step::Variant(name) => solution.variant(name, input)?,
+        };
+
+        match result {
+            Err(err) if err.is::<Unsolved>() => None,
+            result => Some(result.map(|answer| drop(black_box(answer)))),
+        }
+    }
+}
+
+impl Serialize for Step {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Summary of the samples of a single step, all times are in nanoseconds per run.
+#[derive(Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub step: Step,
+    pub samples: usize,
+    pub runs_per_sample: u64,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Record {
+    fn new(year: u16, day: u8, step: Step, mut samples: Vec<f64>, runs_per_sample: u64) -> Self {
+        samples.sort_by(f64::total_cmp);
+
+        let count = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / count;
+        let median = match samples.len() % 2 {
+            0 => (samples[samples.len() / 2 - 1] + samples[samples.len() / 2]) / 2.0,
+            _ => samples[samples.len() / 2],
+        };
+        let std_dev = match samples.len() {
+            1 => 0.0,
+            _ => {
+                let variance = samples
+                    .iter()
+                    .map(|sample| (sample - mean).powi(2))
+                    .sum::<f64>()
+                    / (count - 1.0);
+                variance.sqrt()
+            }
+        };
+
+        Self {
+            year,
+            day,
+            step,
+            samples: samples.len(),
+            runs_per_sample,
+            mean,
+            median,
+            std_dev,
+            min: samples[0],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Benchmarks the selected days, steps that could not be measured are reported on stderr.
+pub fn bench(years: &[&Year], day: Option<u8>, config: &Config) -> Vec<Record> {
+    let inputs = Inputs::default();
+
+    let mut records = Vec::new();
+    for year in years {
+        for (number, solution) in year.days {
+            if day.is_some_and(|day| day != *number) {
+                continue;
+            }
+
+            let input = match inputs.read(year.year, *number) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("Skipping {} day {number}: {err}", year.year);
+                    continue;
+                }
+            };
+
+            let variants = solution.variants().iter().map(|&name| Step::Variant(name));
+            for step in [Step::Parse, Step::PartOne, Step::PartTwo]
+                .into_iter()
+                .chain(variants)
+            {
+                match measure(*solution, step, &input, config) {
+                    Ok(Some((samples, runs))) => {
+                        records.push(Record::new(year.year, *number, step, samples, runs))
+                    }
+                    Ok(None) => {}
+                    Err(err) => {
+                        eprintln!("Skipping {} day {number} {}: {err}", year.year, step.name())
+                    }
+                }
+            }
+        }
+    }
+
+    records
+}
+
+/// Samples of the time per run, with the number of runs per sample.
+fn measure(
+    solution: &dyn Solution,
+    step: Step,
+    input: &str,
+    config: &Config,
+) -> Result<Option<(Vec<f64>, u64)>, String> {
+    let run = || match panic::catch_unwind(AssertUnwindSafe(|| step.run(solution, input))) {
+        Ok(Some(Ok(()))) => Ok(Some(())),
+        Ok(Some(Err(err))) => Err(err.to_string()),
+        Ok(None) => Ok(None),
+        Err(_) => Err(String::from("panicked")),
+    };
+
+    // The warm-up also estimates how long a single run takes
+    let start = Instant::now();
+    let mut runs = 0u32;
+    while runs == 0 || start.elapsed() < config.warm_up {
+        if run()?.is_none() {
+            return Ok(None);
+        }
+        runs += 1;
+    }
+    let estimate = start.elapsed() / runs;
+
+    let runs_per_sample = (MIN_SAMPLE_TIME.as_nanos() / estimate.as_nanos().max(1)).max(1) as u64;
+
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while samples.is_empty()
+        || (samples.len() < config.samples && start.elapsed() < config.measurement)
+    {
+        let sample_start = Instant::now();
+        for _ in 0..runs_per_sample {
+            run()?;
+        }
+        samples.push(sample_start.elapsed().as_nanos() as f64 / runs_per_sample as f64);
+    }
+
+    Ok(Some((samples, runs_per_sample)))
+}
+
+pub fn format(records: &[Record], format: Format) -> Result<String, Error> {
+    match format {
+        Format::Table => table(records),
+        Format::Json => Ok(serde_json::to_string_pretty(records)? + "\n"),
+        Format::Csv => csv(records),
+    }
+}
+
+fn table(records: &[Record]) -> Result<String, Error> {
+    let time = |nanos: f64| format!("{:.2?}", Duration::from_secs_f64(nanos / 1e9));
+
+    // The variants can have longer names than the parts
+    let width = records
+        .iter()
+        .map(|record| record.step.name().len())
+        .fold("part_one".len(), usize::max);
+
+    let mut output = String::new();
+    writeln!(output, "Year | Day | {:<width$} | Mean       | Median     | Std dev    | Min        | Max        | Samples", "Step")?;
+    writeln!(output, "-----+-----+-{:-<width$}-+------------+------------+------------+------------+------------+--------", "")?;
+    for record in records {
+        writeln!(
+            output,
+            "{} | {:>3} | {:<width$} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} | {}",
+            record.year,
+            record.day,
+            record.step.name(),
+            time(record.mean),
+            time(record.median),
+            time(record.std_dev),
+            time(record.min),
+            time(record.max),
+            record.samples,
+        )?;
+    }
+
+    Ok(output)
+}
+
+fn csv(records: &[Record]) -> Result<String, Error> {
+    let mut output =
+        String::from("year,day,step,samples,runs_per_sample,mean,median,std_dev,min,max\n");
+    for record in records {
+        writeln!(
+            output,
+            "{},{},{},{},{},{:.1},{:.1},{:.1},{:.1},{:.1}",
+            record.year,
+            record.day,
+            record.step.name(),
+            record.samples,
+            record.runs_per_sample,
+            record.mean,
+            record.median,
+            record.std_dev,
+            record.min,
+            record.max,
+        )?;
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,599 @@
+mod bench;
+
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    fs,
+    io::{self, Write},
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{
+    answers::Answers,
+    input::{self, Inputs},
+    Answer, QueryOption, Solution, Unsolved, Year,
+};
+use clap::{Parser, Subcommand};
+
+static YEARS: [&Year; 3] = [&aoc2015::YEAR, &aoc2021::YEAR, &aoc2022::YEAR];
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run solutions and print their answers with the time they took
+    Run {
+        /// Only run the given year
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only run the given day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Only run the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input of the selected day from this file, or from stdin when it is `-`
+        #[arg(long, requires_all = ["year", "day"])]
+        input: Option<PathBuf>,
+        /// Print the winning sequence of days that search for their answer
+        #[arg(long)]
+        explain: bool,
+    },
+    /// Run solutions on their input and compare the answers with the known ones
+    Verify {
+        /// Only verify the given year
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only verify the given day
+        #[arg(long)]
+        day: Option<u8>,
+    },
+    /// Benchmark parsing, both parts and the variants of every day on its input
+    Bench {
+        /// Only benchmark the given year
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only benchmark the given day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Milliseconds to run every step before measuring it
+        #[arg(long, default_value_t = 500)]
+        warm_up: u64,
+        /// Milliseconds to spend at most on measuring every step
+        #[arg(long, default_value_t = 3000)]
+        time: u64,
+        /// Number of samples to take of every step
+        #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+        samples: u64,
+        /// Format of the results
+        #[arg(long, value_enum, default_value_t = bench::Format::Table)]
+        format: bench::Format,
+    },
+    /// Render the puzzle of a day as a file, like a graph or an image
+    Render {
+        /// Year of the day to render
+        #[arg(long)]
+        year: u16,
+        /// Day to render
+        #[arg(long)]
+        day: u8,
+        /// Show the state after the given part instead of just the input
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Format to render in, like `dot`
+        #[arg(long)]
+        format: String,
+        /// Read the input from this file, or from stdin when it is `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Write to this file instead of stdout
+        #[arg(long, conflicts_with = "frames")]
+        output: Option<PathBuf>,
+        /// Write every step as a numbered file in this directory, like `000.png`
+        #[arg(long)]
+        frames: Option<PathBuf>,
+    },
+    /// Answer a variation of the puzzle of a day, like `--exclude-value red` for 2015 day 12
+    Query {
+        /// Year of the day to query
+        #[arg(long)]
+        year: u16,
+        /// Day to query
+        #[arg(long)]
+        day: u8,
+        /// Read the input from this file, or from stdin when it is `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Options of the day that describe the variation, as `--name value` or `--flag`
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        options: Vec<String>,
+    },
+    /// Step through the program of a day in a debugger that reads its commands from stdin
+    Debug {
+        /// Year of the day to debug
+        #[arg(long)]
+        year: u16,
+        /// Day to debug
+        #[arg(long)]
+        day: u8,
+        /// Read the input from this file
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+enum Outcome {
+    Solved(Answer),
+    Unsolved,
+    Failed(String),
+    Panicked,
+}
+
+enum Check {
+    Pass,
+    Fail(String),
+    Missing(String),
+}
+
+struct Row {
+    year: u16,
+    day: u8,
+    part: u8,
+    outcome: Outcome,
+    elapsed: Duration,
+    explanation: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            explain,
+        } => match run(year, day, part, input, explain) {
+            Ok(rows) => {
+                print_table(&rows);
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Verify { year, day } => match verify(year, day) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Bench {
+            year,
+            day,
+            warm_up,
+            time,
+            samples,
+            format,
+        } => {
+            let config = bench::Config {
+                warm_up: Duration::from_millis(warm_up),
+                measurement: Duration::from_millis(time),
+                samples: samples as usize,
+            };
+            let result = select(year, day).and_then(|years| {
+                bench::format(&bench::bench(&years, day, &config), format)
+                    .map_err(|err| err.to_string())
+            });
+            match result {
+                Ok(output) => {
+                    print!("{output}");
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("{err}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Render {
+            year,
+            day,
+            part,
+            format,
+            input,
+            output,
+            frames,
+        } => match render(year, day, part, &format, input, output, frames) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Query {
+            year,
+            day,
+            input,
+            options,
+        } => match query(year, day, input, &options) {
+            Ok(answer) => {
+                println!("{answer}");
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Debug { year, day, input } => match debug(year, day, input) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+/// The years to work on, fails when the selected year or day has no solution
+fn select(year: Option<u16>, day: Option<u8>) -> Result<Vec<&'static Year>, String> {
+    let years = YEARS
+        .iter()
+        .copied()
+        .filter(|y| year.is_none_or(|year| y.year == year))
+        .collect::<Vec<_>>();
+
+    if years.is_empty() {
+        return Err(format!(
+            "No solutions for year {}",
+            year.unwrap_or_default()
+        ));
+    }
+
+    if let Some(day) = day {
+        if let Some(year) = years.iter().find(|year| year.day(day).is_none()) {
+            return Err(format!("No solution for day {day} of {}", year.year));
+        }
+    }
+
+    Ok(years)
+}
+
+fn run(
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    explain: bool,
+) -> Result<Vec<Row>, String> {
+    let inputs = Inputs::default();
+
+    let mut rows = Vec::new();
+    for year in select(year, day)? {
+        let days = year
+            .days
+            .iter()
+            .filter(|(number, _)| day.is_none_or(|day| *number == day));
+
+        for (number, solution) in days {
+            let input = match &input {
+                Some(path) => input::read(path),
+                None => inputs.read(year.year, *number),
+            }
+            .map_err(|err| err.to_string());
+
+            for current_part in [1, 2] {
+                if part.is_none_or(|part| part == current_part) {
+                    let (outcome, elapsed) = match &input {
+                        Ok(input) => run_part(*solution, current_part, input),
+                        Err(err) => (Outcome::Failed(err.clone()), Duration::ZERO),
+                    };
+                    let explanation = match (&input, &outcome) {
+                        (Ok(input), Outcome::Solved(_)) if explain => {
+                            explain_part(*solution, current_part, input)
+                        }
+                        _ => None,
+                    };
+                    rows.push(Row {
+                        year: year.year,
+                        day: *number,
+                        part: current_part,
+                        outcome,
+                        elapsed,
+                        explanation,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(rows)
+}
+
+fn render(
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    format: &str,
+    input: Option<PathBuf>,
+    output: Option<PathBuf>,
+    frames: Option<PathBuf>,
+) -> Result<(), String> {
+    let solution = select(Some(year), Some(day))?[0]
+        .day(day)
+        .expect("Selected day has no solution");
+
+    let input = match input {
+        Some(path) => input::read(path),
+        None => Inputs::default().read(year, day),
+    }
+    .map_err(|err| err.to_string())?;
+
+    if let Some(directory) = frames {
+        let frames = solution
+            .frames(&input, part, format)
+            .ok_or_else(|| {
+                format!("Day {day} of {year} cannot be rendered step by step as {format}")
+            })?
+            .map_err(|err| err.to_string())?;
+        fs::create_dir_all(&directory)
+            .map_err(|err| format!("Could not create {}: {err}", directory.display()))?;
+        let digits = frames.len().saturating_sub(1).to_string().len().max(3);
+        for (step, frame) in frames.iter().enumerate() {
+            let path = directory.join(format!("{step:0digits$}.{format}"));
+            fs::write(&path, frame)
+                .map_err(|err| format!("Could not write {}: {err}", path.display()))?;
+        }
+        return Ok(());
+    }
+
+    let rendered = solution
+        .render(&input, part, format)
+        .ok_or_else(|| format!("Day {day} of {year} cannot be rendered as {format}"))?
+        .map_err(|err| err.to_string())?;
+
+    match output {
+        Some(path) => fs::write(&path, rendered)
+            .map_err(|err| format!("Could not write {}: {err}", path.display())),
+        None => io::stdout()
+            .write_all(&rendered)
+            .map_err(|err| format!("Could not write to stdout: {err}")),
+    }
+}
+
+fn query(year: u16, day: u8, input: Option<PathBuf>, options: &[String]) -> Result<Answer, String> {
+    let solution = select(Some(year), Some(day))?[0]
+        .day(day)
+        .expect("Selected day has no solution");
+    let options = parse_options(options)?;
+
+    let input = match input {
+        Some(path) => input::read(path),
+        None => Inputs::default().read(year, day),
+    }
+    .map_err(|err| err.to_string())?;
+
+    solution
+        .query(&input, &options)
+        .ok_or_else(|| format!("Day {day} of {year} has no variations to query"))?
+        .map_err(|err| err.to_string())
+}
+
+fn debug(year: u16, day: u8, input: Option<PathBuf>) -> Result<(), String> {
+    let solution = select(Some(year), Some(day))?[0]
+        .day(day)
+        .expect("Selected day has no solution");
+
+    let input = match input {
+        Some(path) if path.as_os_str() == "-" => {
+            return Err(String::from(
+                "The debugger reads its commands from stdin, so the input has to be a file",
+            ))
+        }
+        Some(path) => input::read(path),
+        None => Inputs::default().read(year, day),
+    }
+    .map_err(|err| err.to_string())?;
+
+    solution
+        .debug(&input, &mut io::stdin().lock(), &mut io::stdout().lock())
+        .ok_or_else(|| format!("Day {day} of {year} has no debugger"))?
+        .map_err(|err| err.to_string())
+}
+
+/// Splits `--name value`, `--name=value` and `--flag` into options
+fn parse_options(args: &[String]) -> Result<Vec<QueryOption>, String> {
+    let mut options = Vec::new();
+    let mut args = args.iter().peekable();
+
+    while let Some(arg) = args.next() {
+        let name = arg
+            .strip_prefix("--")
+            .ok_or_else(|| format!("Expected an option like `--name`, found `{arg}`"))?;
+        let option = match name.split_once('=') {
+            Some((name, value)) => QueryOption {
+                name: name.to_owned(),
+                value: Some(value.to_owned()),
+            },
+            None => QueryOption {
+                name: name.to_owned(),
+                value: args.next_if(|value| !value.starts_with("--")).cloned(),
+            },
+        };
+        options.push(option);
+    }
+
+    Ok(options)
+}
+
+/// Prints how every part compares to its known answer, returns whether none of them failed
+fn verify(year: Option<u16>, day: Option<u8>) -> Result<bool, String> {
+    let inputs = Inputs::default();
+    let rows = run(year, day, None, None, false)?;
+
+    let mut answers = BTreeMap::new();
+    for row in &rows {
+        if let Entry::Vacant(entry) = answers.entry(row.year) {
+            let known = inputs
+                .read_answers(row.year)
+                .map_err(|err| err.to_string())?;
+            entry.insert(known);
+        }
+    }
+
+    let checks = rows
+        .iter()
+        .map(|row| check(&answers[&row.year], row))
+        .collect::<Vec<_>>();
+    print_checks(&rows, &checks);
+
+    let count = |f: fn(&Check) -> bool| checks.iter().filter(|check| f(check)).count();
+    let failed = count(|check| matches!(check, Check::Fail(_)));
+    println!();
+    println!(
+        "{} passed, {failed} failed, {} missing",
+        count(|check| matches!(check, Check::Pass)),
+        count(|check| matches!(check, Check::Missing(_))),
+    );
+
+    Ok(failed == 0)
+}
+
+fn check(answers: &Answers, row: &Row) -> Check {
+    let Some(expected) = answers.get(row.day, row.part) else {
+        return Check::Missing(describe(&row.outcome));
+    };
+
+    match &row.outcome {
+        Outcome::Solved(answer) => match answers.check(row.day, row.part, answer) {
+            Some(true) => Check::Pass,
+            _ => Check::Fail(format!("expected {expected}, got {answer}")),
+        },
+        outcome => Check::Fail(format!("expected {expected}, got {}", describe(outcome))),
+    }
+}
+
+fn run_part(solution: &dyn Solution, part: u8, input: &str) -> (Outcome, Duration) {
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| match part {
+        1 => solution.part_one(input),
+        _ => solution.part_two(input),
+    }));
+    let elapsed = start.elapsed();
+
+    let outcome = match result {
+        Ok(Ok(answer)) => Outcome::Solved(answer),
+        Ok(Err(err)) if err.is::<Unsolved>() => Outcome::Unsolved,
+        Ok(Err(err)) => Outcome::Failed(err.to_string()),
+        Err(_) => Outcome::Panicked,
+    };
+
+    (outcome, elapsed)
+}
+
+/// Explains a part that was already solved, so explaining is left out of its time
+fn explain_part(solution: &dyn Solution, part: u8, input: &str) -> Option<String> {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| solution.explain(part, input)));
+
+    match result {
+        Ok(Some(Ok(explanation))) => Some(explanation.to_string()),
+        Ok(Some(Err(err))) => Some(format!("error: {err}")),
+        Ok(None) => None,
+        Err(_) => Some(String::from("panicked")),
+    }
+}
+
+fn describe(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Solved(answer) => answer.to_string(),
+        Outcome::Unsolved => String::from("unsolved"),
+        Outcome::Failed(err) => format!("error: {err}"),
+        Outcome::Panicked => String::from("panicked"),
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    let answers = rows
+        .iter()
+        .map(|row| describe(&row.outcome))
+        .collect::<Vec<_>>();
+
+    // Answers spanning multiple lines (like pictures) are printed below their row
+    let width = answers
+        .iter()
+        .filter(|answer| !answer.contains('\n'))
+        .map(|answer| answer.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!("Year | Day | Part | {:<width$} | Time", "Answer");
+    println!("-----+-----+------+-{}-+-----------", "-".repeat(width));
+
+    for (row, answer) in rows.iter().zip(answers) {
+        let time = match row.outcome {
+            Outcome::Unsolved | Outcome::Failed(_) => String::new(),
+            _ => format!("{:.2?}", row.elapsed),
+        };
+
+        let (inline, below) = match answer.contains('\n') {
+            true => ("", Some(answer.trim_end())),
+            false => (answer.as_str(), None),
+        };
+
+        let line = format!(
+            "{} | {:>3} | {:>4} | {:<width$} | {time}",
+            row.year, row.day, row.part, inline
+        );
+        println!("{}", line.trim_end());
+
+        if let Some(below) = below {
+            println!("{below}");
+        }
+
+        if let Some(explanation) = &row.explanation {
+            println!("{explanation}");
+        }
+    }
+}
+
+fn print_checks(rows: &[Row], checks: &[Check]) {
+    println!("Year | Day | Part | Status  | Details");
+    println!("-----+-----+------+---------+--------");
+
+    for (row, check) in rows.iter().zip(checks) {
+        let (status, details) = match check {
+            Check::Pass => ("pass", ""),
+            Check::Fail(details) => ("FAIL", details.as_str()),
+            Check::Missing(details) => ("missing", details.as_str()),
+        };
+
+        // Pictures would break up the table, so they are printed below their row
+        let (inline, below) = match details.contains('\n') {
+            true => ("", Some(details.trim_end())),
+            false => (details, None),
+        };
+
+        let line = format!(
+            "{} | {:>3} | {:>4} | {status:<7} | {inline}",
+            row.year, row.day, row.part
+        );
+        println!("{}", line.trim_end());
+
+        if let Some(below) = below {
+            println!("{below}");
+        }
+    }
+}